@@ -1 +1,19 @@
+pub mod bitset;
+pub mod disjoint_sparse_table;
 pub mod fenwick;
+pub mod lazy_segtree;
+pub mod li_chao;
+pub mod min_stack;
+pub mod monotone_deque;
+pub mod order_stat_tree;
+pub mod persistent_segtree;
+pub mod queue_aggregate;
+pub mod segtree;
+pub mod skew_heap;
+pub mod sparse_table;
+pub mod treap;
+pub mod trie;
+pub mod union_find;
+pub mod wavelet;
+pub mod window;
+pub mod xor_trie;
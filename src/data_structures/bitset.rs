@@ -0,0 +1,244 @@
+//! Bitset.
+
+const WORD: usize = 64;
+
+/// Fixed-length sequence of bits packed into 64-bit words.
+/// The bits beyond the length are kept 0.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::bitset::Bitset;
+///
+/// // subset sums of {3, 5, 7}
+/// let mut dp = Bitset::new(16);
+/// dp.set(0);
+/// for &w in &[3, 5, 7] {
+///     let shifted = dp.shift_left(w);
+///     dp.or_assign(&shifted);
+/// }
+///
+/// assert!(dp.get(12));
+/// assert!(!dp.get(11));
+/// assert_eq!(8, dp.count_ones());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitset {
+    data: Vec<u64>,
+    len: usize,
+}
+
+impl Bitset {
+    /// Constructs a new bitset of len zeros.
+    pub fn new(len: usize) -> Self {
+        Self {
+            data: vec![0; (len + WORD - 1) / WORD],
+            len,
+        }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sets the i-th bit to 1.
+    pub fn set(&mut self, i: usize) {
+        assert!(i < self.len, "Index out of range");
+        self.data[i / WORD] |= 1 << (i % WORD);
+    }
+
+    /// Sets the i-th bit to 0.
+    pub fn unset(&mut self, i: usize) {
+        assert!(i < self.len, "Index out of range");
+        self.data[i / WORD] &= !(1 << (i % WORD));
+    }
+
+    /// Returns the i-th bit.
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "Index out of range");
+        self.data[i / WORD] >> (i % WORD) & 1 == 1
+    }
+
+    /// Takes the bitwise AND with other.
+    /// Consumes O(n / 64) time.
+    ///
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn and_assign(&mut self, other: &Self) {
+        assert_eq!(self.len, other.len);
+        self.data
+            .iter_mut()
+            .zip(&other.data)
+            .for_each(|(a, b)| *a &= b);
+    }
+
+    /// Takes the bitwise OR with other.
+    /// Consumes O(n / 64) time.
+    ///
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn or_assign(&mut self, other: &Self) {
+        assert_eq!(self.len, other.len);
+        self.data
+            .iter_mut()
+            .zip(&other.data)
+            .for_each(|(a, b)| *a |= b);
+    }
+
+    /// Takes the bitwise XOR with other.
+    /// Consumes O(n / 64) time.
+    ///
+    /// # Panics
+    /// Panics if the lengths differ.
+    pub fn xor_assign(&mut self, other: &Self) {
+        assert_eq!(self.len, other.len);
+        self.data
+            .iter_mut()
+            .zip(&other.data)
+            .for_each(|(a, b)| *a ^= b);
+    }
+
+    /// Flips all the bits.
+    /// Consumes O(n / 64) time.
+    pub fn not_assign(&mut self) {
+        self.data.iter_mut().for_each(|a| *a = !*a);
+        self.clear_tail();
+    }
+
+    /// Returns the number of 1s.
+    /// Consumes O(n / 64) time.
+    pub fn count_ones(&self) -> usize {
+        self.data.iter().map(|a| a.count_ones() as usize).sum()
+    }
+
+    /// Returns the bitset where the i-th bit is moved to the (i + k)-th.
+    /// The bits moved beyond the length are discarded.
+    /// Consumes O(n / 64) time.
+    pub fn shift_left(&self, k: usize) -> Self {
+        let mut res = Self::new(self.len);
+        let (words, bits) = (k / WORD, k % WORD);
+
+        for i in words..self.data.len() {
+            let j = i - words;
+            res.data[i] = self.data[j] << bits;
+            if bits > 0 && j > 0 {
+                res.data[i] |= self.data[j - 1] >> (WORD - bits);
+            }
+        }
+
+        res.clear_tail();
+        res
+    }
+
+    fn clear_tail(&mut self) {
+        if self.len % WORD != 0 {
+            *self.data.last_mut().unwrap() &= (1 << (self.len % WORD)) - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_bits(len: usize, seed: u64) -> Vec<bool> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next() & 1 == 1).collect()
+    }
+
+    fn from_bools(v: &[bool]) -> Bitset {
+        let mut b = Bitset::new(v.len());
+        (0..v.len()).filter(|&i| v[i]).for_each(|i| b.set(i));
+        b
+    }
+
+    fn to_bools(b: &Bitset) -> Vec<bool> {
+        (0..b.len()).map(|i| b.get(i)).collect()
+    }
+
+    #[test]
+    fn set_get() {
+        let mut b = Bitset::new(130);
+        b.set(0);
+        b.set(64);
+        b.set(129);
+        b.set(129);
+        assert_eq!(3, b.count_ones());
+        assert!(b.get(64) && !b.get(63));
+
+        b.unset(64);
+        b.unset(65);
+        assert_eq!(2, b.count_ones());
+        assert!(!b.get(64));
+    }
+
+    #[test]
+    fn bitwise() {
+        for &len in &[0, 1, 63, 64, 65, 200] {
+            let x = random_bits(len, 1);
+            let y = random_bits(len, 2);
+            let zip = |f: fn(bool, bool) -> bool| -> Vec<bool> {
+                x.iter().zip(&y).map(|(&a, &b)| f(a, b)).collect()
+            };
+
+            let mut b = from_bools(&x);
+            b.and_assign(&from_bools(&y));
+            assert_eq!(zip(|a, b| a & b), to_bools(&b));
+
+            let mut b = from_bools(&x);
+            b.or_assign(&from_bools(&y));
+            assert_eq!(zip(|a, b| a | b), to_bools(&b));
+
+            let mut b = from_bools(&x);
+            b.xor_assign(&from_bools(&y));
+            assert_eq!(zip(|a, b| a ^ b), to_bools(&b));
+
+            let mut b = from_bools(&x);
+            b.not_assign();
+            assert_eq!(x.iter().map(|&a| !a).collect::<Vec<_>>(), to_bools(&b));
+            assert_eq!(x.iter().filter(|&&a| !a).count(), b.count_ones());
+        }
+    }
+
+    #[test]
+    fn shift_left() {
+        for &len in &[1, 63, 64, 65, 200] {
+            let x = random_bits(len, len as u64);
+            let b = from_bools(&x);
+            for k in 0..=len + 1 {
+                let naive: Vec<bool> = (0..len).map(|i| i >= k && x[i - k]).collect();
+                assert_eq!(naive, to_bools(&b.shift_left(k)));
+            }
+        }
+    }
+
+    #[test]
+    fn subset_sum() {
+        let weights: Vec<usize> = (1..=40).map(|i| i * i % 97 + 1).collect();
+        let total = weights.iter().sum::<usize>();
+
+        let mut naive = vec![false; total + 1];
+        naive[0] = true;
+        for &w in weights.iter() {
+            for s in (w..=total).rev() {
+                naive[s] |= naive[s - w];
+            }
+        }
+
+        let mut dp = Bitset::new(total + 1);
+        dp.set(0);
+        for &w in weights.iter() {
+            let shifted = dp.shift_left(w);
+            dp.or_assign(&shifted);
+        }
+
+        assert_eq!(naive, to_bools(&dp));
+    }
+}
@@ -0,0 +1,133 @@
+//! Disjoint sparse table.
+
+/// Static range product for any associative operation, which need not be idempotent.
+/// Each level splits the array into blocks and stores the products from the middle of each block,
+/// so that any range is covered by two disjoint stored products.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::disjoint_sparse_table::DisjointSparseTable;
+///
+/// let table = DisjointSparseTable::new(&[3, 1, 4, 1, 5, 9, 2, 6], |a, b| a + b);
+/// assert_eq!(11, table.query(1, 5));
+/// assert_eq!(31, table.query(0, 8));
+/// assert_eq!(9, table.query(5, 6));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSparseTable<T: Clone, F: Fn(T, T) -> T> {
+    data: Vec<T>,
+    // table[k][i] is the product between i and the middle of its block of size 2^(k+1)
+    table: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T: Clone, F: Fn(T, T) -> T> DisjointSparseTable<T, F> {
+    /// Constructs the table with the associative operation `op`.
+    /// Consumes O(n log n) time.
+    pub fn new(data: &[T], op: F) -> Self {
+        let n = data.len();
+        let mut table = vec![];
+        let mut half = 1;
+        while half < n {
+            let mut level = data.to_vec();
+            for mid in (half..n).step_by(2 * half) {
+                for i in (mid - half..mid - 1).rev() {
+                    level[i] = op(data[i].clone(), level[i + 1].clone());
+                }
+                for i in mid + 1..n.min(mid + half) {
+                    level[i] = op(level[i - 1].clone(), data[i].clone());
+                }
+            }
+            table.push(level);
+            half *= 2;
+        }
+        Self {
+            data: data.to_vec(),
+            table,
+            op,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the product of `[l, r)`.
+    /// Consumes O(1) time.
+    ///
+    /// # Panics
+    /// Panics if `l >= r` or `r > n`.
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(
+            l < r && r <= self.len(),
+            "The range is required to be nonempty"
+        );
+        let last = r - 1;
+        if l == last {
+            return self.data[l].clone();
+        }
+        let k = (0usize.leading_zeros() - (l ^ last).leading_zeros()) as usize - 1;
+        (self.op)(self.table[k][l].clone(), self.table[k][last].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn product_mod() {
+        const MOD: u64 = 1_000_000_007;
+        let mut rng = XorShift64::new(407);
+        for n in 1..40 {
+            let data: Vec<u64> = (0..n).map(|_| rng.next_range(0, MOD)).collect();
+            let table = DisjointSparseTable::new(&data, |a, b| a * b % MOD);
+            assert_eq!(n, table.len());
+            for l in 0..n {
+                let mut prod = 1;
+                for r in l + 1..=n {
+                    prod = prod * data[r - 1] % MOD;
+                    assert_eq!(prod, table.query(l, r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn xor() {
+        let mut rng = XorShift64::new(7);
+        let data: Vec<u64> = (0..100).map(|_| rng.next()).collect();
+        let table = DisjointSparseTable::new(&data, |a, b| a ^ b);
+        for l in 0..100 {
+            for r in l + 1..=100 {
+                assert_eq!(data[l..r].iter().fold(0, |x, &y| x ^ y), table.query(l, r));
+            }
+        }
+    }
+
+    #[test]
+    fn non_commutative() {
+        let data: Vec<String> = "disjoint".chars().map(|c| c.to_string()).collect();
+        let table = DisjointSparseTable::new(&data, |a, b| a + &b);
+        for l in 0..data.len() {
+            for r in l + 1..=data.len() {
+                assert_eq!(data[l..r].concat(), table.query(l, r));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_range() {
+        let table = DisjointSparseTable::new(&[1, 2, 3], |a, b| a + b);
+        table.query(2, 2);
+    }
+}
@@ -1,5 +1,8 @@
 //! Fenwick Tree.
 
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign};
+
 /// Fenwick Tree. 1-indexed.
 ///
 /// # Examples
@@ -17,11 +20,15 @@
 /// assert_eq!(16, fw.sum(3));
 /// assert_eq!(13, fw.sum(3) - fw.sum(2));
 /// ```
-pub struct Fenwick {
-    table: Vec<i64>,
+#[derive(Debug, Clone)]
+pub struct Fenwick<T> {
+    table: Vec<T>,
 }
 
-impl Fenwick {
+/// Fenwick Tree over i64.
+pub type FenwickI64 = Fenwick<i64>;
+
+impl<T: Copy + Default + Add<Output = T> + AddAssign> Fenwick<T> {
     /// Constructs a new Fenwick Tree.
     ///
     /// # Examples
@@ -29,11 +36,12 @@ impl Fenwick {
     /// ```
     /// use tklib::data_structures::fenwick::Fenwick;
     ///
-    /// let mut fw = Fenwick::new();
+    /// let mut fw = Fenwick::<i64>::new();
     /// ```
-    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { table: vec![0] }
+        Self {
+            table: vec![T::default()],
+        }
     }
 
     /// Constructs a new Fenwick Tree from a slice.
@@ -45,16 +53,19 @@ impl Fenwick {
     /// let a = [1, 2, 3];
     /// let fw = Fenwick::from_slice(&a);
     /// ```
-    pub fn from_slice(src: &[i64]) -> Self {
+    pub fn from_slice(src: &[T]) -> Self {
         let n = src.len();
 
-        let mut table = vec![0; n + 1];
+        let mut table = vec![T::default(); n + 1];
         table[1..].copy_from_slice(src);
 
         (1..n)
             .map(|i| (i, i + lsb(i)))
             .filter(|&(_, j)| j <= n)
-            .for_each(|(i, j)| table[j] += table[i]);
+            .for_each(|(i, j)| {
+                let x = table[i];
+                table[j] += x;
+            });
 
         Self { table }
     }
@@ -68,7 +79,7 @@ impl Fenwick {
     /// let mut fw = Fenwick::new();
     /// fw.push(1);
     /// ```
-    pub fn push(&mut self, x: i64) {
+    pub fn push(&mut self, x: T) {
         let n = self.table.len();
         let k = lsb(n);
 
@@ -76,7 +87,7 @@ impl Fenwick {
             std::iter::successors(Some(1), |&i| Some(i * 2))
                 .take_while(|&i| i != k)
                 .map(|i| self.table[n - i])
-                .fold(x, std::ops::Add::add),
+                .fold(x, Add::add),
         )
     }
 
@@ -92,11 +103,11 @@ impl Fenwick {
     /// assert_eq!(1, fw.sum(1));
     /// assert_eq!(3, fw.sum(2));
     /// ```
-    pub fn sum(&self, i: usize) -> i64 {
+    pub fn sum(&self, i: usize) -> T {
         std::iter::successors(Some(i), |&i| Some(i - lsb(i)))
             .take_while(|&i| i != 0)
             .map(|i| self.table[i])
-            .sum()
+            .fold(T::default(), Add::add)
     }
 
     /// Adds x onto the i-th element.
@@ -113,12 +124,207 @@ impl Fenwick {
     /// fw.add(2, 3);
     /// assert_eq!(6, fw.sum(2));
     /// ```
-    pub fn add(&mut self, i: usize, x: i64) {
+    pub fn add(&mut self, i: usize, x: T) {
         let n = self.table.len();
         std::iter::successors(Some(i), |&i| Some(i + lsb(i)))
             .take_while(|&i| i < n)
             .for_each(|i| self.table[i] += x);
     }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.table.len() - 1
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the smallest i with `self.sum(i) >= k`, or `self.len() + 1` if there is none.
+    /// The elements are required to be non-negative.
+    /// Descends the tree instead of searching with `sum`.
+    /// Consumes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// use tklib::data_structures::fenwick::Fenwick;
+    ///
+    /// let fw = Fenwick::from_slice(&[1, 0, 2, 3]);
+    /// assert_eq!(1, fw.lower_bound(1));
+    /// assert_eq!(3, fw.lower_bound(2));
+    /// assert_eq!(4, fw.lower_bound(4));
+    /// assert_eq!(5, fw.lower_bound(7));
+    /// ```
+    pub fn lower_bound(&self, k: T) -> usize
+    where
+        T: PartialOrd,
+    {
+        let n = self.len();
+        let mut pos = 0;
+        let mut acc = T::default();
+        let mut step = if n == 0 {
+            0
+        } else {
+            1 << (63 - (n as u64).leading_zeros())
+        };
+        while step > 0 {
+            if pos + step <= n && acc + self.table[pos + step] < k {
+                pos += step;
+                acc += self.table[pos];
+            }
+            step >>= 1;
+        }
+        pos + 1
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + AddAssign> Default for Fenwick<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds the tree in O(n) time as [`from_slice`] does.
+///
+/// [`from_slice`]: struct.Fenwick.html#method.from_slice
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::fenwick::FenwickI64;
+///
+/// let fw: FenwickI64 = (1..=5).collect();
+/// assert_eq!(15, fw.sum(5));
+/// ```
+impl<T: Copy + Default + Add<Output = T> + AddAssign> FromIterator<T> for Fenwick<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Fenwick Tree with range additions and prefix sums over i64. 1-indexed.
+/// Keeps two Fenwick Trees so that the prefix sum of i is `i * b1.sum(i) - b2.sum(i)`.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::fenwick::FenwickRangeUpdate;
+///
+/// let mut fw = FenwickRangeUpdate::new(5);
+/// fw.add_range(2, 4, 5);
+/// fw.add_range(1, 2, 1);
+///
+/// assert_eq!(1, fw.sum(1));
+/// assert_eq!(7, fw.sum(2));
+/// assert_eq!(10, fw.sum(5) - fw.sum(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FenwickRangeUpdate {
+    b1: FenwickI64,
+    b2: FenwickI64,
+}
+
+impl FenwickRangeUpdate {
+    /// Constructs a new Fenwick Tree of n zeros.
+    pub fn new(n: usize) -> Self {
+        Self {
+            b1: Fenwick::from_slice(&vec![0; n]),
+            b2: Fenwick::from_slice(&vec![0; n]),
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.b1.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds x onto each element in [l, r].
+    /// Consumes O(log n) time.
+    pub fn add_range(&mut self, l: usize, r: usize, x: i64) {
+        assert!(1 <= l && l <= r + 1 && r <= self.len());
+        self.b1.add(l, x);
+        self.b1.add(r + 1, -x);
+        self.b2.add(l, x * (l as i64 - 1));
+        self.b2.add(r + 1, -x * r as i64);
+    }
+
+    /// Sums up the elements in [1, i].
+    /// Consumes O(log n) time.
+    pub fn sum(&self, i: usize) -> i64 {
+        self.b1.sum(i) * i as i64 - self.b2.sum(i)
+    }
+}
+
+/// Two-dimensional Fenwick Tree over i64. 1-indexed.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::fenwick::Fenwick2D;
+///
+/// let mut fw = Fenwick2D::new(3, 4);
+/// fw.add(1, 2, 5);
+/// fw.add(3, 3, 7);
+///
+/// assert_eq!(5, fw.sum(2, 2));
+/// assert_eq!(0, fw.sum(3, 1));
+/// assert_eq!(12, fw.sum(3, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fenwick2D {
+    table: Vec<Vec<i64>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Fenwick2D {
+    /// Constructs a new Fenwick Tree of zeros with `rows` rows and `cols` columns.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            table: vec![vec![0; cols + 1]; rows + 1],
+            rows,
+            cols,
+        }
+    }
+
+    /// Adds x onto the element at (r, c).
+    /// Consumes O(log(rows) log(cols)) time.
+    #[allow(clippy::many_single_char_names)]
+    pub fn add(&mut self, r: usize, c: usize, x: i64) {
+        assert!(1 <= r && r <= self.rows && 1 <= c && c <= self.cols);
+        let mut i = r;
+        while i <= self.rows {
+            let mut j = c;
+            while j <= self.cols {
+                self.table[i][j] += x;
+                j += lsb(j);
+            }
+            i += lsb(i);
+        }
+    }
+
+    /// Sums up the elements in [1, r] × [1, c].
+    /// Consumes O(log(rows) log(cols)) time.
+    pub fn sum(&self, r: usize, c: usize) -> i64 {
+        assert!(r <= self.rows && c <= self.cols);
+        let mut acc = 0;
+        let mut i = r;
+        while i > 0 {
+            let mut j = c;
+            while j > 0 {
+                acc += self.table[i][j];
+                j -= lsb(j);
+            }
+            i -= lsb(i);
+        }
+        acc
+    }
 }
 
 fn lsb(i: usize) -> usize {
@@ -132,7 +338,7 @@ mod tests {
     #[test]
     fn it_works() {
         let a = [1, 2, 3];
-        let mut fw = Fenwick::from_slice(&a);
+        let mut fw = FenwickI64::from_slice(&a);
 
         assert_eq!(3, fw.sum(2));
 
@@ -142,4 +348,153 @@ mod tests {
         fw.add(2, 2);
         assert_eq!(8, fw.sum(3));
     }
+
+    #[test]
+    fn from_iter() {
+        use crate::utils::scan::Scanner;
+
+        let input = "6\n3 -1 4 1 -5 9\n";
+        let mut sc = Scanner::new(input.as_bytes());
+        let n: usize = sc.read();
+        let a: Vec<i64> = sc.vec(n);
+
+        let fw: FenwickI64 = a.iter().copied().collect();
+        assert_eq!(FenwickI64::from_slice(&a).table, fw.table);
+        assert_eq!(n, fw.len());
+        for i in 0..=n {
+            assert_eq!(a[..i].iter().sum::<i64>(), fw.sum(i));
+        }
+
+        let empty: FenwickI64 = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn len() {
+        let mut fw = FenwickI64::default();
+        assert_eq!(0, fw.len());
+        assert!(fw.is_empty());
+        assert_eq!(0, fw.sum(0));
+
+        for i in 1..=10 {
+            fw.push(i);
+            assert_eq!(i as usize, fw.len());
+            assert_eq!(i * (i + 1) / 2, fw.sum(fw.len()));
+        }
+        assert!(!fw.is_empty());
+        assert_eq!(3, FenwickI64::from_slice(&[1, 2, 3]).len());
+    }
+
+    #[test]
+    fn modint() {
+        use crate::math::modint::ModInt998244353 as Mint;
+
+        let a: Vec<Mint> = (0..10).map(|i| Mint::new(998_244_350 + i)).collect();
+        let mut fw = Fenwick::from_slice(&a);
+
+        assert_eq!(Mint::new(998_244_350 * 3 + 3), fw.sum(3));
+        assert_eq!(a.iter().sum::<Mint>(), fw.sum(10));
+
+        fw.add(1, Mint::new(3));
+        fw.push(Mint::new(5));
+        assert_eq!(Mint::new(0), fw.sum(1));
+        assert_eq!(a.iter().sum::<Mint>() + Mint::new(8), fw.sum(11));
+    }
+
+    #[test]
+    fn float() {
+        let mut fw = Fenwick::<f64>::from_slice(&[0.5, 0.25, 0.125]);
+        fw.add(2, 1.0);
+        assert!((fw.sum(2) - 1.75).abs() < 1e-12);
+        assert!((fw.sum(3) - 1.875).abs() < 1e-12);
+    }
+
+    #[test]
+    fn two_dimensional() {
+        use crate::utils::random::XorShift64;
+
+        let (rows, cols) = (100, 100);
+        let mut rng = XorShift64::new(511);
+        let mut naive = vec![vec![0i64; cols + 1]; rows + 1];
+        let mut fw = Fenwick2D::new(rows, cols);
+
+        for _ in 0..200 {
+            let r = rng.next_range(1, rows as u64 + 1) as usize;
+            let c = rng.next_range(1, cols as u64 + 1) as usize;
+            let x = rng.next_range(0, 2001) as i64 - 1000;
+            naive[r][c] += x;
+            fw.add(r, c, x);
+
+            let r = rng.next_range(0, rows as u64 + 1) as usize;
+            let c = rng.next_range(0, cols as u64 + 1) as usize;
+            let expected: i64 = naive[..=r]
+                .iter()
+                .map(|row| row[..=c].iter().sum::<i64>())
+                .sum();
+            assert_eq!(expected, fw.sum(r, c));
+        }
+
+        for r in 0..=rows {
+            for c in 0..=cols {
+                let expected: i64 = naive[..=r]
+                    .iter()
+                    .map(|row| row[..=c].iter().sum::<i64>())
+                    .sum();
+                assert_eq!(expected, fw.sum(r, c));
+            }
+        }
+    }
+
+    #[test]
+    fn lower_bound() {
+        use crate::utils::random::XorShift64;
+
+        let mut rng = XorShift64::new(512);
+        for n in 0..40 {
+            let mut weights: Vec<i64> = (0..n).map(|_| rng.next_range(0, 5) as i64).collect();
+            weights.sort();
+            let mut fw = FenwickI64::from_slice(&weights);
+            assert_eq!(n, fw.len());
+
+            let total: i64 = weights.iter().sum();
+            for k in -1..=total + 1 {
+                let expected = (1..=n).find(|&i| fw.sum(i) >= k).unwrap_or(n + 1);
+                assert_eq!(expected, fw.lower_bound(k));
+            }
+
+            fw.push(3);
+            assert_eq!(n + 1, fw.lower_bound(total + 1));
+        }
+    }
+
+    #[test]
+    fn range_update() {
+        let mut fw = FenwickRangeUpdate::new(5);
+        fw.add_range(2, 4, 5);
+        assert_eq!(0, fw.sum(1));
+        assert_eq!(5, fw.sum(2));
+        assert_eq!(15, fw.sum(4));
+        assert_eq!(15, fw.sum(5));
+    }
+
+    #[test]
+    fn range_update_random() {
+        use crate::utils::random::XorShift64;
+
+        let n = 50;
+        let mut rng = XorShift64::new(513);
+        let mut naive = vec![0i64; n + 1];
+        let mut fw = FenwickRangeUpdate::new(n);
+
+        for _ in 0..500 {
+            let l = rng.next_range(1, n as u64 + 1) as usize;
+            let r = rng.next_range(l as u64 - 1, n as u64 + 1) as usize;
+            let x = rng.next_range(0, 201) as i64 - 100;
+            naive[l..=r].iter_mut().for_each(|v| *v += x);
+            fw.add_range(l, r, x);
+
+            let i = rng.next_range(0, n as u64 + 1) as usize;
+            assert_eq!(naive[..=i].iter().sum::<i64>(), fw.sum(i));
+        }
+    }
 }
@@ -0,0 +1,389 @@
+//! Segment tree with lazy propagation.
+
+use super::segtree::Monoid;
+use std::mem::replace;
+
+/// Monoid of maps acting on the values of the monoid M.
+/// `op(f, g)` is the composition applying g first and then f,
+/// and each map is required to be an endomorphism: `apply(f, op(x, y)) = op(apply(f, x), apply(f, y))`.
+pub trait Action<M: Monoid>: Monoid {
+    /// Returns the map f applied to x.
+    fn apply(f: &Self::S, x: &M::S) -> M::S;
+}
+
+/// Segment tree over a monoid M acted on by F, for range updates and range products.
+/// Ranges are half-open.
+///
+/// # Examples
+///
+/// Range affine, range sum:
+///
+/// ```
+/// use tklib::data_structures::lazy_segtree::{Action, LazySegTree};
+/// use tklib::data_structures::segtree::Monoid;
+/// use tklib::math::modint::ModInt998244353 as Mint;
+///
+/// // (sum, length)
+/// struct Sum;
+/// impl Monoid for Sum {
+///     type S = (Mint, Mint);
+///     fn identity() -> (Mint, Mint) {
+///         (Mint::new(0), Mint::new(0))
+///     }
+///     fn op(a: &(Mint, Mint), b: &(Mint, Mint)) -> (Mint, Mint) {
+///         (a.0 + b.0, a.1 + b.1)
+///     }
+/// }
+///
+/// // x -> bx + c
+/// struct Affine;
+/// impl Monoid for Affine {
+///     type S = (Mint, Mint);
+///     fn identity() -> (Mint, Mint) {
+///         (Mint::new(1), Mint::new(0))
+///     }
+///     fn op(f: &(Mint, Mint), g: &(Mint, Mint)) -> (Mint, Mint) {
+///         (f.0 * g.0, f.0 * g.1 + f.1)
+///     }
+/// }
+/// impl Action<Sum> for Affine {
+///     fn apply(f: &(Mint, Mint), x: &(Mint, Mint)) -> (Mint, Mint) {
+///         (f.0 * x.0 + f.1 * x.1, x.1)
+///     }
+/// }
+///
+/// let v = (1..=5).map(|x| (Mint::new(x), Mint::new(1))).collect();
+/// let mut st = LazySegTree::<Sum, Affine>::from_vec(v);
+///
+/// // [1, 2, 3, 4, 5] -> [1, 7, 9, 11, 5]
+/// st.apply_range(1, 4, (Mint::new(2), Mint::new(3)));
+/// assert_eq!(Mint::new(27), st.prod(1, 4).0);
+///
+/// // -> [1, 7, 10, 12, 6]
+/// st.apply_range(2, 5, (Mint::new(1), Mint::new(1)));
+/// assert_eq!(Mint::new(36), st.all_prod().0);
+/// assert_eq!(Mint::new(10), st.get(2).0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazySegTree<M: Monoid, F: Action<M>> {
+    n: usize,
+    size: usize,
+    log: u32,
+    data: Vec<M::S>,
+    lazy: Vec<F::S>,
+}
+
+impl<M: Monoid, F: Action<M>> LazySegTree<M, F> {
+    /// Constructs a new tree of n identity elements.
+    /// Consumes O(n) time.
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![M::identity(); n])
+    }
+
+    /// Constructs a new tree from the values.
+    /// Consumes O(n) time.
+    pub fn from_vec(v: Vec<M::S>) -> Self {
+        let n = v.len();
+        let size = n.next_power_of_two();
+        let mut data = vec![M::identity(); 2 * size];
+        for (i, x) in v.into_iter().enumerate() {
+            data[size + i] = x;
+        }
+        for k in (1..size).rev() {
+            data[k] = M::op(&data[2 * k], &data[2 * k + 1]);
+        }
+
+        Self {
+            n,
+            size,
+            log: size.trailing_zeros(),
+            data,
+            lazy: vec![F::identity(); size],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Sets the i-th element to x.
+    /// Consumes O(log n) time.
+    pub fn set(&mut self, i: usize, x: M::S) {
+        assert!(i < self.n, "Index out of range");
+
+        let k = i + self.size;
+        for h in (1..=self.log).rev() {
+            self.push(k >> h);
+        }
+        self.data[k] = x;
+        for h in 1..=self.log {
+            self.update(k >> h);
+        }
+    }
+
+    /// Returns the i-th element.
+    /// Consumes O(log n) time.
+    pub fn get(&mut self, i: usize) -> M::S {
+        assert!(i < self.n, "Index out of range");
+
+        let k = i + self.size;
+        for h in (1..=self.log).rev() {
+            self.push(k >> h);
+        }
+        self.data[k].clone()
+    }
+
+    /// Returns the product of the elements in [l, r), or the identity if the range is empty.
+    /// Consumes O(log n) time.
+    pub fn prod(&mut self, l: usize, r: usize) -> M::S {
+        assert!(l <= r && r <= self.n);
+        if l == r {
+            return M::identity();
+        }
+
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        self.push_boundary(l, r);
+
+        let mut left = M::identity();
+        let mut right = M::identity();
+        while l < r {
+            if l & 1 == 1 {
+                left = M::op(&left, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::op(&self.data[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+
+        M::op(&left, &right)
+    }
+
+    /// Returns the product of all the elements.
+    /// Consumes O(1) time.
+    pub fn all_prod(&self) -> M::S {
+        self.data[1].clone()
+    }
+
+    /// Applies f to each element in [l, r).
+    /// Consumes O(log n) time.
+    pub fn apply_range(&mut self, l: usize, r: usize, f: F::S) {
+        assert!(l <= r && r <= self.n);
+        if l == r {
+            return;
+        }
+
+        let (l, r) = (l + self.size, r + self.size);
+        self.push_boundary(l, r);
+
+        let (mut lo, mut hi) = (l, r);
+        while lo < hi {
+            if lo & 1 == 1 {
+                self.apply_node(lo, &f);
+                lo += 1;
+            }
+            if hi & 1 == 1 {
+                hi -= 1;
+                self.apply_node(hi, &f);
+            }
+            lo >>= 1;
+            hi >>= 1;
+        }
+
+        for h in 1..=self.log {
+            if (l >> h) << h != l {
+                self.update(l >> h);
+            }
+            if (r >> h) << h != r {
+                self.update((r - 1) >> h);
+            }
+        }
+    }
+
+    // Pushes down the maps above the nodes at the boundaries of [l, r).
+    fn push_boundary(&mut self, l: usize, r: usize) {
+        for h in (1..=self.log).rev() {
+            if (l >> h) << h != l {
+                self.push(l >> h);
+            }
+            if (r >> h) << h != r {
+                self.push((r - 1) >> h);
+            }
+        }
+    }
+
+    fn update(&mut self, k: usize) {
+        self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+    }
+
+    fn apply_node(&mut self, k: usize, f: &F::S) {
+        self.data[k] = F::apply(f, &self.data[k]);
+        if k < self.size {
+            self.lazy[k] = F::op(f, &self.lazy[k]);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = replace(&mut self.lazy[k], F::identity());
+        self.apply_node(2 * k, &f);
+        self.apply_node(2 * k + 1, &f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::segtree::MinMonoidI64;
+    use crate::utils::random::XorShift64;
+
+    // (sum, length)
+    struct Sum;
+    impl Monoid for Sum {
+        type S = (i64, i64);
+        fn identity() -> (i64, i64) {
+            (0, 0)
+        }
+        fn op(a: &(i64, i64), b: &(i64, i64)) -> (i64, i64) {
+            (a.0 + b.0, a.1 + b.1)
+        }
+    }
+
+    struct Add;
+    impl Monoid for Add {
+        type S = i64;
+        fn identity() -> i64 {
+            0
+        }
+        fn op(f: &i64, g: &i64) -> i64 {
+            f + g
+        }
+    }
+    impl Action<Sum> for Add {
+        fn apply(f: &i64, x: &(i64, i64)) -> (i64, i64) {
+            (x.0 + f * x.1, x.1)
+        }
+    }
+    impl Action<MinMonoidI64> for Add {
+        fn apply(f: &i64, x: &i64) -> i64 {
+            if *x == std::i64::MAX {
+                *x
+            } else {
+                x + f
+            }
+        }
+    }
+
+    // Assigns a value, with None as the identity map.
+    struct Assign;
+    impl Monoid for Assign {
+        type S = Option<i64>;
+        fn identity() -> Option<i64> {
+            None
+        }
+        fn op(f: &Option<i64>, g: &Option<i64>) -> Option<i64> {
+            f.or(*g)
+        }
+    }
+    impl Action<MinMonoidI64> for Assign {
+        fn apply(f: &Option<i64>, x: &i64) -> i64 {
+            f.unwrap_or(*x)
+        }
+    }
+
+    #[test]
+    fn range_add_range_sum() {
+        let mut st = LazySegTree::<Sum, Add>::from_vec(vec![(0, 1); 5]);
+        st.apply_range(1, 4, 5);
+        st.apply_range(0, 2, -1);
+        assert_eq!((-1, 1), st.get(0));
+        assert_eq!((4, 1), st.get(1));
+        assert_eq!(13, st.prod(0, 5).0);
+        assert_eq!(10, st.prod(2, 5).0);
+        assert_eq!((0, 0), st.prod(3, 3));
+
+        st.set(2, (10, 1));
+        assert_eq!(18, st.all_prod().0);
+    }
+
+    // Mixes random range updates, point sets and range products against a plain Vec.
+    fn check<F: Action<MinMonoidI64>, G: Fn(&mut XorShift64) -> F::S>(seed: u64, map: G) {
+        let n = 37;
+        let mut rng = XorShift64::new(seed);
+        let mut naive: Vec<i64> = (0..n).map(|_| rng.next_range(0, 1000) as i64).collect();
+        let mut st = LazySegTree::<MinMonoidI64, F>::from_vec(naive.clone());
+
+        for _ in 0..2000 {
+            let l = rng.next_range(0, n as u64 + 1) as usize;
+            let r = rng.next_range(l as u64, n as u64 + 1) as usize;
+            match rng.next_range(0, 3) {
+                0 => {
+                    let f = map(&mut rng);
+                    naive[l..r].iter_mut().for_each(|x| *x = F::apply(&f, x));
+                    st.apply_range(l, r, f);
+                }
+                1 if l < n => {
+                    let x = rng.next_range(0, 1000) as i64;
+                    naive[l] = x;
+                    st.set(l, x);
+                }
+                _ => {
+                    let expected = naive[l..r].iter().copied().min().unwrap_or(std::i64::MAX);
+                    assert_eq!(expected, st.prod(l, r));
+                }
+            }
+        }
+        for (i, &x) in naive.iter().enumerate() {
+            assert_eq!(x, st.get(i));
+        }
+    }
+
+    #[test]
+    fn range_add_range_min() {
+        check::<Add, _>(515, |rng| rng.next_range(0, 201) as i64 - 100);
+    }
+
+    #[test]
+    fn range_assign_range_min() {
+        check::<Assign, _>(516, |rng| Some(rng.next_range(0, 1000) as i64));
+    }
+
+    #[test]
+    fn range_add_range_sum_random() {
+        let n = 50;
+        let mut rng = XorShift64::new(517);
+        let mut naive = vec![0i64; n];
+        let mut st = LazySegTree::<Sum, Add>::new(n);
+        for i in 0..n {
+            st.set(i, (0, 1));
+        }
+
+        for _ in 0..2000 {
+            let l = rng.next_range(0, n as u64 + 1) as usize;
+            let r = rng.next_range(l as u64, n as u64 + 1) as usize;
+            if rng.next_range(0, 2) == 0 {
+                let f = rng.next_range(0, 201) as i64 - 100;
+                naive[l..r].iter_mut().for_each(|x| *x += f);
+                st.apply_range(l, r, f);
+            } else {
+                assert_eq!(naive[l..r].iter().sum::<i64>(), st.prod(l, r).0);
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut st = LazySegTree::<Sum, Add>::new(0);
+        st.apply_range(0, 0, 3);
+        assert_eq!((0, 0), st.prod(0, 0));
+        assert_eq!((0, 0), st.all_prod());
+    }
+}
@@ -0,0 +1,248 @@
+//! Li Chao Tree.
+
+/// Li Chao Tree. Maintains a set of lines and answers the minimum value at a point.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::li_chao::LiChaoTree;
+///
+/// let mut lc = LiChaoTree::new(&[-2, 0, 3]);
+/// lc.add_line(1, 0);
+/// lc.add_line(-1, 1);
+///
+/// assert_eq!(-2, lc.query_min(-2));
+/// assert_eq!(0, lc.query_min(0));
+/// assert_eq!(-2, lc.query_min(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiChaoTree {
+    xs: Option<Vec<i64>>,
+    lo: i64,
+    hi: i64,
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    line: Option<(i64, i64)>,
+    children: [Option<usize>; 2],
+}
+
+impl Node {
+    fn new() -> Self {
+        Self {
+            line: None,
+            children: [None; 2],
+        }
+    }
+}
+
+impl LiChaoTree {
+    /// Constructs a new Li Chao Tree which accepts queries only at the given points.
+    ///
+    /// # Panics
+    /// Panics if `xs` is empty.
+    pub fn new(xs: &[i64]) -> Self {
+        let mut xs = xs.to_vec();
+        xs.sort();
+        xs.dedup();
+        assert!(!xs.is_empty());
+
+        Self {
+            lo: 0,
+            hi: xs.len() as i64 - 1,
+            xs: Some(xs),
+            nodes: vec![Node::new()],
+        }
+    }
+
+    /// Constructs a new Li Chao Tree which accepts queries at any point in [x_lo, x_hi].
+    /// Nodes are allocated lazily, O(log(x_hi - x_lo)) per line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::data_structures::li_chao::LiChaoTree;
+    ///
+    /// let mut lc = LiChaoTree::new_unbounded(-1_000_000_000, 1_000_000_000);
+    /// lc.add_line(2, 3);
+    /// lc.add_line(-3, 0);
+    ///
+    /// assert_eq!(-2_999_999_997, lc.query_min(999_999_999));
+    /// assert_eq!(-1_999_999_997, lc.query_min(-1_000_000_000));
+    /// ```
+    pub fn new_unbounded(x_lo: i64, x_hi: i64) -> Self {
+        assert!(x_lo <= x_hi);
+
+        Self {
+            xs: None,
+            lo: x_lo,
+            hi: x_hi,
+            nodes: vec![Node::new()],
+        }
+    }
+
+    fn coord(&self, i: i64) -> i64 {
+        match &self.xs {
+            Some(xs) => xs[i as usize],
+            None => i,
+        }
+    }
+
+    /// Adds the line `slope * x + intercept`.
+    /// Consumes O(log n) time.
+    #[allow(clippy::many_single_char_names)]
+    pub fn add_line(&mut self, slope: i64, intercept: i64) {
+        let mut line = (slope, intercept);
+        let (mut l, mut r) = (self.lo, self.hi);
+        let mut v = 0;
+
+        loop {
+            let cur = match self.nodes[v].line {
+                Some(cur) => cur,
+                None => {
+                    self.nodes[v].line = Some(line);
+                    return;
+                }
+            };
+
+            let m = l + (r - l) / 2;
+            let left_better = eval(line, self.coord(l)) < eval(cur, self.coord(l));
+            let mid_better = eval(line, self.coord(m)) < eval(cur, self.coord(m));
+            if mid_better {
+                self.nodes[v].line = Some(line);
+                line = cur;
+            }
+            if l == r {
+                return;
+            }
+
+            let dir = if left_better != mid_better {
+                r = m;
+                0
+            } else {
+                l = m + 1;
+                1
+            };
+            v = match self.nodes[v].children[dir] {
+                Some(u) => u,
+                None => {
+                    let u = self.nodes.len();
+                    self.nodes.push(Node::new());
+                    self.nodes[v].children[dir] = Some(u);
+                    u
+                }
+            };
+        }
+    }
+
+    /// Returns the minimum value of the lines at x,
+    /// or `i64::MAX` if no line has been added.
+    /// Consumes O(log n) time.
+    ///
+    /// # Panics
+    /// Panics if x is not one of the points given on construction.
+    #[allow(clippy::many_single_char_names)]
+    pub fn query_min(&self, x: i64) -> i64 {
+        let i = match &self.xs {
+            Some(xs) => xs.binary_search(&x).expect("The point is not registered") as i64,
+            None => {
+                assert!(self.lo <= x && x <= self.hi, "The point is out of range");
+                x
+            }
+        };
+
+        let (mut l, mut r) = (self.lo, self.hi);
+        let mut v = Some(0);
+        let mut res = std::i64::MAX;
+
+        while let Some(u) = v {
+            if let Some(line) = self.nodes[u].line {
+                res = res.min(eval(line, x));
+            }
+
+            let m = l + (r - l) / 2;
+            v = if i <= m {
+                r = m;
+                self.nodes[u].children[0]
+            } else {
+                l = m + 1;
+                self.nodes[u].children[1]
+            };
+        }
+
+        res
+    }
+}
+
+fn eval((a, b): (i64, i64), x: i64) -> i64 {
+    a * x + b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, range: i64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, 2 * range as u64 + 1) as i64 - range)
+            .collect()
+    }
+
+    #[test]
+    fn random_lines() {
+        let slopes = random_vec(200, 1, 1000);
+        let intercepts = random_vec(200, 2, 1_000_000);
+        let xs = random_vec(100, 3, 1000);
+
+        let mut offline = LiChaoTree::new(&xs);
+        let mut online = LiChaoTree::new_unbounded(-1000, 1000);
+        for (k, (&a, &b)) in slopes.iter().zip(&intercepts).enumerate() {
+            offline.add_line(a, b);
+            online.add_line(a, b);
+
+            for &x in xs.iter() {
+                let naive = (0..=k)
+                    .map(|i| slopes[i] * x + intercepts[i])
+                    .min()
+                    .unwrap();
+                assert_eq!(naive, offline.query_min(x));
+                assert_eq!(naive, online.query_min(x));
+            }
+        }
+    }
+
+    #[test]
+    fn convex_hull_trick() {
+        // dp[i] = min_{j < i} dp[j] + (x[i] - x[j])^2 + c
+        let c = 1000;
+        let mut xs = random_vec(300, 4, 10000);
+        xs.sort();
+
+        let mut naive = vec![0; xs.len()];
+        for i in 1..xs.len() {
+            naive[i] = (0..i)
+                .map(|j| naive[j] + (xs[i] - xs[j]).pow(2) + c)
+                .min()
+                .unwrap();
+        }
+
+        let mut lc = LiChaoTree::new(&xs);
+        let mut dp = vec![0; xs.len()];
+        lc.add_line(-2 * xs[0], xs[0] * xs[0]);
+        for i in 1..xs.len() {
+            dp[i] = lc.query_min(xs[i]) + xs[i] * xs[i] + c;
+            lc.add_line(-2 * xs[i], dp[i] + xs[i] * xs[i]);
+        }
+
+        assert_eq!(naive, dp);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(std::i64::MAX, LiChaoTree::new_unbounded(0, 0).query_min(0));
+    }
+}
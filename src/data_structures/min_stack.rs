@@ -0,0 +1,204 @@
+//! Stack and queue with the minimum.
+
+/// Stack keeping its minimum.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::min_stack::MinStack;
+///
+/// let mut st = MinStack::new();
+/// st.push(3);
+/// st.push(1);
+/// st.push(4);
+/// assert_eq!(Some(&1), st.min());
+///
+/// st.pop();
+/// st.pop();
+/// assert_eq!(Some(&3), st.min());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinStack<T: Ord> {
+    values: Vec<T>,
+    // mins[i] is the index of the minimum of values[..=i]
+    mins: Vec<usize>,
+}
+
+impl<T: Ord> MinStack<T> {
+    /// Constructs a new empty stack.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            values: vec![],
+            mins: vec![],
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pushes a value.
+    /// Consumes O(1) time.
+    pub fn push(&mut self, val: T) {
+        let i = self.values.len();
+        let m = match self.mins.last() {
+            Some(&m) if self.values[m] <= val => m,
+            _ => i,
+        };
+        self.values.push(val);
+        self.mins.push(m);
+    }
+
+    /// Pops the last value, or returns None if the stack is empty.
+    /// Consumes O(1) time.
+    pub fn pop(&mut self) -> Option<T> {
+        self.mins.pop();
+        self.values.pop()
+    }
+
+    /// Returns the last value, or None if the stack is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.values.last()
+    }
+
+    /// Returns the minimum, or None if the stack is empty.
+    /// Consumes O(1) time.
+    pub fn min(&self) -> Option<&T> {
+        self.mins.last().map(|&m| &self.values[m])
+    }
+}
+
+/// Queue keeping its minimum, using two stacks.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::min_stack::MinQueue;
+///
+/// let mut q = MinQueue::new();
+/// q.push(3);
+/// q.push(1);
+/// q.push(4);
+/// assert_eq!(Some(&1), q.min());
+///
+/// assert_eq!(Some(3), q.pop());
+/// assert_eq!(Some(1), q.pop());
+/// assert_eq!(Some(&4), q.min());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinQueue<T: Ord> {
+    front: MinStack<T>,
+    back: MinStack<T>,
+}
+
+impl<T: Ord> MinQueue<T> {
+    /// Constructs a new empty queue.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            front: MinStack::new(),
+            back: MinStack::new(),
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Pushes a value to the back.
+    /// Consumes O(1) time.
+    pub fn push(&mut self, val: T) {
+        self.back.push(val);
+    }
+
+    /// Pops the front value, or returns None if the queue is empty.
+    /// Consumes amortized O(1) time.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            while let Some(x) = self.back.pop() {
+                self.front.push(x);
+            }
+        }
+        self.front.pop()
+    }
+
+    /// Returns the minimum, or None if the queue is empty.
+    /// Consumes O(1) time.
+    pub fn min(&self) -> Option<&T> {
+        match (self.front.min(), self.back.min()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn stack() {
+        let mut rng = XorShift64::new(1);
+
+        let mut st = MinStack::new();
+        let mut naive = vec![];
+        for _ in 0..1000 {
+            if rng.next_range(0, 3) == 0 {
+                assert_eq!(naive.pop(), st.pop());
+            } else {
+                let v = rng.next_range(0, 50);
+                st.push(v);
+                naive.push(v);
+            }
+            assert_eq!(naive.len(), st.len());
+            assert_eq!(naive.last(), st.peek());
+            assert_eq!(naive.iter().min(), st.min());
+        }
+    }
+
+    #[test]
+    fn queue() {
+        let mut rng = XorShift64::new(2);
+
+        let mut q = MinQueue::new();
+        let mut naive = VecDeque::new();
+        for _ in 0..1000 {
+            if rng.next_range(0, 3) == 0 {
+                assert_eq!(naive.pop_front(), q.pop());
+            } else {
+                let v = rng.next_range(0, 50);
+                q.push(v);
+                naive.push_back(v);
+            }
+            assert_eq!(naive.len(), q.len());
+            assert_eq!(naive.iter().min(), q.min());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut st = MinStack::<i32>::new();
+        assert_eq!(None, st.min());
+        assert_eq!(None, st.pop());
+
+        let mut q = MinQueue::<i32>::new();
+        assert!(q.is_empty());
+        assert_eq!(None, q.min());
+        assert_eq!(None, q.pop());
+    }
+}
@@ -0,0 +1,170 @@
+//! Monotone deque.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// Deque keeping the minimum of a sliding window.
+/// Values are indexed, and the indices are required to be pushed in increasing order.
+/// The order is given by a comparator, so that [`with_cmp`] keeps, for example, the maximum instead.
+///
+/// [`with_cmp`]: #method.with_cmp
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::monotone_deque::MonotoneDeque;
+///
+/// let a = [3, 1, 4, 1, 5, 9, 2, 6];
+/// let k = 3;
+///
+/// let mut dq = MonotoneDeque::new();
+/// let mut mins = vec![];
+/// for (i, &x) in a.iter().enumerate() {
+///     dq.push_back(x, i);
+///     if i + 1 >= k {
+///         dq.pop_expired(i + 1 - k);
+///         mins.push(dq.front_val().unwrap());
+///     }
+/// }
+///
+/// assert_eq!(vec![1, 1, 1, 1, 2, 2], mins);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonotoneDeque<T, F = fn(&T, &T) -> Ordering> {
+    deque: VecDeque<(T, usize)>,
+    cmp: F,
+}
+
+impl<T: Ord> MonotoneDeque<T> {
+    /// Constructs a new empty deque keeping the minimum.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_cmp(Ord::cmp)
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> MonotoneDeque<T, F> {
+    /// Constructs a new empty deque keeping the minimum with respect to `cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::data_structures::monotone_deque::MonotoneDeque;
+    ///
+    /// let mut dq = MonotoneDeque::with_cmp(|a: &i32, b: &i32| b.cmp(a));
+    /// dq.push_back(3, 0);
+    /// dq.push_back(5, 1);
+    /// dq.push_back(4, 2);
+    /// assert_eq!(Some(5), dq.front_val());
+    ///
+    /// dq.pop_expired(2);
+    /// assert_eq!(Some(4), dq.front_val());
+    /// ```
+    pub fn with_cmp(cmp: F) -> Self {
+        Self {
+            deque: VecDeque::new(),
+            cmp,
+        }
+    }
+
+    /// Pushes a value with its index.
+    /// Consumes amortized O(1) time.
+    pub fn push_back(&mut self, val: T, idx: usize) {
+        while let Some((x, _)) = self.deque.back() {
+            if (self.cmp)(x, &val) == Ordering::Less {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((val, idx));
+    }
+
+    /// Removes the values whose indices are less than `min_idx`.
+    /// Consumes amortized O(1) time.
+    pub fn pop_expired(&mut self, min_idx: usize) {
+        while self.deque.front().map_or(false, |&(_, i)| i < min_idx) {
+            self.deque.pop_front();
+        }
+    }
+}
+
+impl<T: Clone, F> MonotoneDeque<T, F> {
+    /// Returns the minimum value, or None if the deque is empty.
+    pub fn front_val(&self) -> Option<T> {
+        self.deque.front().map(|(x, _)| x.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+    use std::cmp::Reverse;
+
+    fn random_vec(len: usize, seed: u64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, 100) as i64).collect()
+    }
+
+    fn sliding<T: Ord + Clone>(a: &[T], k: usize) -> Vec<T> {
+        let mut dq = MonotoneDeque::new();
+        let mut res = vec![];
+        for (i, x) in a.iter().enumerate() {
+            dq.push_back(x.clone(), i);
+            if i + 1 >= k {
+                dq.pop_expired(i + 1 - k);
+                res.push(dq.front_val().unwrap());
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn window_min() {
+        let a = random_vec(200, 1);
+        for k in 1..=20 {
+            let naive: Vec<i64> = a.windows(k).map(|w| *w.iter().min().unwrap()).collect();
+            assert_eq!(naive, sliding(&a, k));
+        }
+    }
+
+    #[test]
+    fn window_max() {
+        let a = random_vec(200, 2);
+        for k in 1..=20 {
+            let naive: Vec<i64> = a.windows(k).map(|w| *w.iter().max().unwrap()).collect();
+            let b: Vec<Reverse<i64>> = a.iter().map(|&x| Reverse(x)).collect();
+            let res: Vec<i64> = sliding(&b, k).into_iter().map(|Reverse(x)| x).collect();
+            assert_eq!(naive, res);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut dq = MonotoneDeque::new();
+        assert_eq!(None, dq.front_val());
+
+        dq.push_back(1, 0);
+        dq.pop_expired(1);
+        assert_eq!(None, dq.front_val());
+    }
+
+    #[test]
+    fn window_max_cmp() {
+        let a = random_vec(300, 3);
+        for k in 1..=30 {
+            let naive: Vec<i64> = a.windows(k).map(|w| *w.iter().max().unwrap()).collect();
+
+            let mut dq = MonotoneDeque::with_cmp(|x: &i64, y: &i64| y.cmp(x));
+            let mut res = vec![];
+            for (i, &x) in a.iter().enumerate() {
+                dq.push_back(x, i);
+                if i + 1 >= k {
+                    dq.pop_expired(i + 1 - k);
+                    res.push(dq.front_val().unwrap());
+                }
+            }
+            assert_eq!(naive, res);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Multiset with order statistics.
+
+use std::collections::BTreeMap;
+
+/// Multiset with rank and k-th element queries, backed by `BTreeMap` from values to counts.
+/// The queries walk the distinct values, so they are linear in their number.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::order_stat_tree::OrderStatTree;
+///
+/// let mut tree = OrderStatTree::new();
+/// tree.insert(5);
+/// tree.insert(1);
+/// tree.insert(5);
+///
+/// assert_eq!(Some(&5), tree.kth(2));
+/// assert_eq!(1, tree.rank(&5));
+/// assert_eq!(2, tree.count_eq(&5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderStatTree<T: Ord> {
+    counts: BTreeMap<T, usize>,
+    len: usize,
+}
+
+impl<T: Ord> OrderStatTree<T> {
+    /// Constructs a new empty multiset.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements, counting multiplicity.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the multiset is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts x.
+    /// Consumes O(log n) time.
+    pub fn insert(&mut self, x: T) {
+        *self.counts.entry(x).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    /// Removes one occurrence of x. Returns false if x is not contained.
+    /// Consumes O(log n) time.
+    pub fn remove(&mut self, x: T) -> bool {
+        match self.counts.get_mut(&x) {
+            None => false,
+            Some(c) => {
+                *c -= 1;
+                if *c == 0 {
+                    self.counts.remove(&x);
+                }
+                self.len -= 1;
+                true
+            }
+        }
+    }
+
+    /// Returns the k-th smallest element, where k is 1-indexed.
+    /// Returns None if k is 0 or exceeds the size.
+    /// Consumes O(n) time.
+    pub fn kth(&self, k: usize) -> Option<&T> {
+        if k == 0 {
+            return None;
+        }
+
+        let mut acc = 0;
+        self.counts.iter().find_map(|(x, &c)| {
+            acc += c;
+            if acc >= k {
+                Some(x)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the number of elements strictly smaller than x.
+    /// Consumes O(n) time.
+    pub fn rank(&self, x: &T) -> usize {
+        self.counts.range(..x).map(|(_, &c)| c).sum()
+    }
+
+    /// Returns the number of occurrences of x.
+    /// Consumes O(log n) time.
+    pub fn count_eq(&self, x: &T) -> usize {
+        self.counts.get(x).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = |m: u64| rng.next_range(0, m);
+
+        let mut tree = OrderStatTree::new();
+        let mut naive: Vec<u64> = vec![];
+        for _ in 0..500 {
+            let v = rand(30);
+            if rand(3) == 0 {
+                let pos = naive.iter().position(|&y| y == v);
+                assert_eq!(pos.is_some(), tree.remove(v));
+                if let Some(i) = pos {
+                    naive.remove(i);
+                }
+            } else {
+                tree.insert(v);
+                naive.push(v);
+            }
+            naive.sort();
+
+            assert_eq!(naive.len(), tree.len());
+            for q in 0..31 {
+                assert_eq!(naive.iter().filter(|&&y| y < q).count(), tree.rank(&q));
+                assert_eq!(naive.iter().filter(|&&y| y == q).count(), tree.count_eq(&q));
+            }
+            for k in 0..=naive.len() + 1 {
+                let expected = if k == 0 { None } else { naive.get(k - 1) };
+                assert_eq!(expected, tree.kth(k));
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut tree = OrderStatTree::<i32>::new();
+        assert!(tree.is_empty());
+        assert_eq!(None, tree.kth(1));
+        assert_eq!(0, tree.rank(&3));
+        assert!(!tree.remove(3));
+    }
+}
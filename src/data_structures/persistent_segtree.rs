@@ -0,0 +1,175 @@
+//! Persistent segment tree.
+
+use std::rc::Rc;
+
+#[derive(Debug)]
+struct Node {
+    sum: i64,
+    left: Link,
+    right: Link,
+}
+
+type Link = Option<Rc<Node>>;
+
+fn sum(node: &Link) -> i64 {
+    node.as_ref().map_or(0, |v| v.sum)
+}
+
+/// Persistent segment tree for point additions and range sums, using path copying.
+/// Each update returns a new version sharing the untouched nodes,
+/// and the old versions stay valid. Ranges are half-open.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::persistent_segtree::PersistentSegTree;
+///
+/// let v0 = PersistentSegTree::new(5);
+/// let v1 = v0.update(1, 3);
+/// let v2 = v1.update(3, 4);
+///
+/// assert_eq!(0, v0.query(0, 5));
+/// assert_eq!(3, v1.query(0, 5));
+/// assert_eq!(7, v2.query(0, 5));
+/// assert_eq!(4, v2.query(2, 5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PersistentSegTree {
+    n: usize,
+    root: Link,
+}
+
+impl PersistentSegTree {
+    /// Constructs a new tree of n zeros.
+    /// Consumes O(1) time, as the nodes are created lazily.
+    pub fn new(n: usize) -> Self {
+        Self { n, root: None }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Returns the new version where delta is added to the i-th element.
+    /// Consumes O(log n) time and space.
+    ///
+    /// # Panics
+    /// Panics if i is out of range.
+    pub fn update(&self, i: usize, delta: i64) -> Self {
+        assert!(i < self.n, "Index out of range");
+
+        Self {
+            n: self.n,
+            root: Some(Self::update_rec(&self.root, 0, self.n, i, delta)),
+        }
+    }
+
+    fn update_rec(node: &Link, lo: usize, hi: usize, i: usize, delta: i64) -> Rc<Node> {
+        let (left, right) = match node {
+            Some(v) => (v.left.clone(), v.right.clone()),
+            None => (None, None),
+        };
+
+        let (left, right) = if hi - lo == 1 {
+            (left, right)
+        } else {
+            let mid = (lo + hi) / 2;
+            if i < mid {
+                (Some(Self::update_rec(&left, lo, mid, i, delta)), right)
+            } else {
+                (left, Some(Self::update_rec(&right, mid, hi, i, delta)))
+            }
+        };
+
+        Rc::new(Node {
+            sum: sum(node) + delta,
+            left,
+            right,
+        })
+    }
+
+    /// Returns the sum of the elements in [l, r).
+    /// Consumes O(log n) time.
+    pub fn query(&self, l: usize, r: usize) -> i64 {
+        assert!(l <= r && r <= self.n);
+
+        Self::query_rec(&self.root, 0, self.n, l, r)
+    }
+
+    fn query_rec(node: &Link, lo: usize, hi: usize, l: usize, r: usize) -> i64 {
+        match node {
+            None => 0,
+            _ if r <= lo || hi <= l => 0,
+            _ if l <= lo && hi <= r => sum(node),
+            Some(v) => {
+                let mid = (lo + hi) / 2;
+                Self::query_rec(&v.left, lo, mid, l, r) + Self::query_rec(&v.right, mid, hi, l, r)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn versions() {
+        let n = 13;
+        let mut rng = XorShift64::new(1);
+        let mut rand = |m: u64| rng.next_range(0, m);
+
+        let mut versions = vec![PersistentSegTree::new(n)];
+        let mut arrays = vec![vec![0; n]];
+        for _ in 0..10 {
+            let i = rand(n as u64) as usize;
+            let delta = rand(201) as i64 - 100;
+
+            versions.push(versions.last().unwrap().update(i, delta));
+            let mut a = arrays.last().unwrap().clone();
+            a[i] += delta;
+            arrays.push(a);
+        }
+
+        // the old versions are not affected by the later updates
+        for (tree, a) in versions.iter().zip(&arrays) {
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(a[l..r].iter().sum::<i64>(), tree.query(l, r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn branching() {
+        let base = PersistentSegTree::new(4).update(0, 1);
+        let a = base.update(2, 10);
+        let b = base.update(2, 100);
+
+        assert_eq!(1, base.query(0, 4));
+        assert_eq!(11, a.query(0, 4));
+        assert_eq!(101, b.query(0, 4));
+    }
+
+    #[test]
+    fn single() {
+        let t = PersistentSegTree::new(1).update(0, 5).update(0, -2);
+        assert_eq!(3, t.query(0, 1));
+        assert_eq!(0, t.query(1, 1));
+        assert_eq!(0, PersistentSegTree::new(0).query(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let _ = PersistentSegTree::new(3).update(3, 1);
+    }
+}
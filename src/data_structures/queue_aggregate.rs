@@ -0,0 +1,164 @@
+//! Queue with the aggregate of its elements.
+
+use super::segtree::Monoid;
+
+/// FIFO queue which maintains the product of its elements over a monoid, from front to back,
+/// using two stacks each with their own prefix products.
+/// The operation is not required to be commutative.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::{queue_aggregate::QueueWithAgg, segtree::MinMonoidI64};
+///
+/// let mut q = QueueWithAgg::<i64, MinMonoidI64>::new();
+/// q.push_back(3);
+/// q.push_back(1);
+/// q.push_back(4);
+/// assert_eq!(1, q.aggregate());
+///
+/// assert_eq!(Some(3), q.pop_front());
+/// assert_eq!(Some(1), q.pop_front());
+/// assert_eq!(4, q.aggregate());
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueueWithAgg<T: Clone, M: Monoid<S = T>> {
+    // the front elements with the products from each one to the bottom, the front on the top
+    front: Vec<(T, T)>,
+    // the back elements in order, and their product
+    back: Vec<T>,
+    back_agg: T,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<T: Clone, M: Monoid<S = T>> QueueWithAgg<T, M> {
+    /// Constructs a new empty queue.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            front: vec![],
+            back: vec![],
+            back_agg: M::identity(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pushes an element to the back.
+    /// Consumes O(1) time.
+    pub fn push_back(&mut self, x: T) {
+        self.back_agg = M::op(&self.back_agg, &x);
+        self.back.push(x);
+    }
+
+    /// Pops the front element, or returns None if the queue is empty.
+    /// Consumes O(1) amortized time.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            while let Some(x) = self.back.pop() {
+                let agg = match self.front.last() {
+                    Some((_, agg)) => M::op(&x, agg),
+                    None => x.clone(),
+                };
+                self.front.push((x, agg));
+            }
+            self.back_agg = M::identity();
+        }
+        self.front.pop().map(|(x, _)| x)
+    }
+
+    /// Returns the product of all the elements, or the identity if the queue is empty.
+    /// Consumes O(1) time.
+    pub fn aggregate(&self) -> T {
+        match self.front.last() {
+            Some((_, agg)) => M::op(agg, &self.back_agg),
+            None => self.back_agg.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::segtree::{MinMonoidI64, SumMonoidI64};
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn sliding_window_min() {
+        let mut rng = XorShift64::new(399);
+        let a: Vec<i64> = (0..500).map(|_| rng.next_range(0, 1000) as i64).collect();
+
+        for &k in &[1, 2, 7, 100, 500] {
+            let mut q = QueueWithAgg::<i64, MinMonoidI64>::new();
+            let mut mins = vec![];
+            for (i, &x) in a.iter().enumerate() {
+                q.push_back(x);
+                if i >= k {
+                    assert_eq!(Some(a[i - k]), q.pop_front());
+                }
+                if i + 1 >= k {
+                    mins.push(q.aggregate());
+                }
+            }
+
+            let naive: Vec<i64> = a.windows(k).map(|w| *w.iter().min().unwrap()).collect();
+            assert_eq!(naive, mins);
+        }
+    }
+
+    #[test]
+    fn random_sum() {
+        let mut rng = XorShift64::new(4);
+        let mut q = QueueWithAgg::<i64, SumMonoidI64>::new();
+        let mut naive = std::collections::VecDeque::new();
+        for _ in 0..2000 {
+            if rng.next_range(0, 3) == 0 {
+                assert_eq!(naive.pop_front(), q.pop_front());
+            } else {
+                let x = rng.next_range(0, 100) as i64 - 50;
+                q.push_back(x);
+                naive.push_back(x);
+            }
+            assert_eq!(naive.len(), q.len());
+            assert_eq!(naive.iter().sum::<i64>(), q.aggregate());
+        }
+    }
+
+    #[test]
+    fn order() {
+        struct Concat;
+        impl Monoid for Concat {
+            type S = String;
+            fn identity() -> String {
+                String::new()
+            }
+            fn op(a: &String, b: &String) -> String {
+                a.clone() + b
+            }
+        }
+
+        let mut q = QueueWithAgg::<String, Concat>::new();
+        assert!(q.is_empty());
+        assert_eq!("", q.aggregate());
+        for &s in &["a", "b", "c"] {
+            q.push_back(s.to_string());
+        }
+        assert_eq!("abc", q.aggregate());
+        q.pop_front();
+        q.push_back("d".to_string());
+        assert_eq!("bcd", q.aggregate());
+        q.pop_front();
+        q.pop_front();
+        q.push_back("e".to_string());
+        assert_eq!("de", q.aggregate());
+    }
+}
@@ -0,0 +1,361 @@
+//! Segment tree.
+
+use crate::math::gcd::gcd;
+use crate::math::modint::{ModInt, ModTrait};
+use std::marker::PhantomData;
+
+/// Monoid, a set with an associative operation and its identity.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::segtree::{Monoid, SegTree};
+///
+/// // the concatenation of strings
+/// struct Concat;
+/// impl Monoid for Concat {
+///     type S = String;
+///     fn identity() -> String {
+///         String::new()
+///     }
+///     fn op(a: &String, b: &String) -> String {
+///         a.clone() + b
+///     }
+/// }
+///
+/// let st = SegTree::<Concat>::from_vec(vec!["a".into(), "b".into(), "c".into()]);
+/// assert_eq!("bc", st.prod(1, 3));
+/// ```
+pub trait Monoid {
+    type S: Clone;
+
+    /// Returns the identity element.
+    fn identity() -> Self::S;
+
+    /// Returns the product of a and b.
+    fn op(a: &Self::S, b: &Self::S) -> Self::S;
+}
+
+/// Segment tree over a monoid, for point updates and range products.
+/// Ranges are half-open.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::segtree::{MinMonoidI64, SegTree};
+///
+/// let mut st = SegTree::<MinMonoidI64>::from_vec(vec![5, 3, 7, 1, 4]);
+/// assert_eq!(3, st.prod(0, 3));
+///
+/// st.set(1, 6);
+/// assert_eq!(5, st.prod(0, 3));
+/// assert_eq!(1, st.all_prod());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegTree<M: Monoid> {
+    n: usize,
+    size: usize,
+    data: Vec<M::S>,
+}
+
+impl<M: Monoid> SegTree<M> {
+    /// Constructs a new tree of n identity elements.
+    /// Consumes O(n) time.
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![M::identity(); n])
+    }
+
+    /// Constructs a new tree from the values.
+    /// Consumes O(n) time.
+    pub fn from_vec(v: Vec<M::S>) -> Self {
+        let n = v.len();
+        let size = n.next_power_of_two();
+        let mut data = vec![M::identity(); 2 * size];
+        for (i, x) in v.into_iter().enumerate() {
+            data[size + i] = x;
+        }
+        for k in (1..size).rev() {
+            data[k] = M::op(&data[2 * k], &data[2 * k + 1]);
+        }
+
+        Self { n, size, data }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Sets the i-th element to x.
+    /// Consumes O(log n) time.
+    pub fn set(&mut self, i: usize, x: M::S) {
+        assert!(i < self.n, "Index out of range");
+
+        let mut k = i + self.size;
+        self.data[k] = x;
+        while k > 1 {
+            k >>= 1;
+            self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+        }
+    }
+
+    /// Returns the i-th element.
+    pub fn get(&self, i: usize) -> M::S {
+        assert!(i < self.n, "Index out of range");
+        self.data[i + self.size].clone()
+    }
+
+    /// Returns the product of the elements in [l, r), or the identity if the range is empty.
+    /// Consumes O(log n) time.
+    pub fn prod(&self, l: usize, r: usize) -> M::S {
+        assert!(l <= r && r <= self.n);
+
+        let mut left = M::identity();
+        let mut right = M::identity();
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        while l < r {
+            if l & 1 == 1 {
+                left = M::op(&left, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::op(&self.data[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+
+        M::op(&left, &right)
+    }
+
+    /// Returns the product of all the elements.
+    /// Consumes O(1) time.
+    pub fn all_prod(&self) -> M::S {
+        self.data[1].clone()
+    }
+}
+
+/// Addition on i64.
+#[derive(Debug, Clone)]
+pub struct SumMonoidI64;
+
+impl Monoid for SumMonoidI64 {
+    type S = i64;
+
+    fn identity() -> i64 {
+        0
+    }
+
+    fn op(a: &i64, b: &i64) -> i64 {
+        a + b
+    }
+}
+
+/// Maximum on i64, with the identity `i64::MIN`.
+#[derive(Debug, Clone)]
+pub struct MaxMonoidI64;
+
+impl Monoid for MaxMonoidI64 {
+    type S = i64;
+
+    fn identity() -> i64 {
+        std::i64::MIN
+    }
+
+    fn op(a: &i64, b: &i64) -> i64 {
+        *a.max(b)
+    }
+}
+
+/// Minimum on i64, with the identity `i64::MAX`.
+#[derive(Debug, Clone)]
+pub struct MinMonoidI64;
+
+impl Monoid for MinMonoidI64 {
+    type S = i64;
+
+    fn identity() -> i64 {
+        std::i64::MAX
+    }
+
+    fn op(a: &i64, b: &i64) -> i64 {
+        *a.min(b)
+    }
+}
+
+/// Greatest common divisor on u64, with the identity 0.
+#[derive(Debug, Clone)]
+pub struct GCDMonoid;
+
+impl Monoid for GCDMonoid {
+    type S = u64;
+
+    fn identity() -> u64 {
+        0
+    }
+
+    fn op(a: &u64, b: &u64) -> u64 {
+        gcd(*a, *b)
+    }
+}
+
+/// Bitwise XOR on u64.
+#[derive(Debug, Clone)]
+pub struct XORMonoid;
+
+impl Monoid for XORMonoid {
+    type S = u64;
+
+    fn identity() -> u64 {
+        0
+    }
+
+    fn op(a: &u64, b: &u64) -> u64 {
+        a ^ b
+    }
+}
+
+/// Multiplication on ModInt.
+#[derive(Debug, Clone)]
+pub struct ProductMonoid<Mod: ModTrait> {
+    _marker: PhantomData<fn() -> Mod>,
+}
+
+impl<Mod: ModTrait> Monoid for ProductMonoid<Mod> {
+    type S = ModInt<Mod>;
+
+    fn identity() -> ModInt<Mod> {
+        ModInt::new(1)
+    }
+
+    fn op(a: &ModInt<Mod>, b: &ModInt<Mod>) -> ModInt<Mod> {
+        *a * *b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::{Mod998244353, ModInt998244353};
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, modulus)).collect()
+    }
+
+    // Checks every range and a few point updates against folding a plain Vec.
+    fn check<M: Monoid>(init: Vec<M::S>, updates: Vec<M::S>)
+    where
+        M::S: PartialEq + std::fmt::Debug,
+    {
+        let mut naive = init.clone();
+        let mut st = SegTree::<M>::from_vec(init);
+        let n = naive.len();
+
+        for (step, x) in updates.into_iter().enumerate() {
+            for l in 0..=n {
+                for r in l..=n {
+                    let expected = naive[l..r]
+                        .iter()
+                        .fold(M::identity(), |acc, y| M::op(&acc, y));
+                    assert_eq!(expected, st.prod(l, r));
+                }
+            }
+            assert_eq!(st.prod(0, n), st.all_prod());
+
+            let i = step * 7 % n;
+            st.set(i, x.clone());
+            naive[i] = x;
+            assert_eq!(naive[i], st.get(i));
+        }
+    }
+
+    #[test]
+    fn sum_max_min() {
+        let signed = |v: Vec<u64>| -> Vec<i64> { v.into_iter().map(|x| x as i64 - 500).collect() };
+
+        check::<SumMonoidI64>(
+            signed(random_vec(20, 1, 1000)),
+            signed(random_vec(5, 2, 1000)),
+        );
+        check::<MaxMonoidI64>(
+            signed(random_vec(20, 3, 1000)),
+            signed(random_vec(5, 4, 1000)),
+        );
+        check::<MinMonoidI64>(
+            signed(random_vec(20, 5, 1000)),
+            signed(random_vec(5, 6, 1000)),
+        );
+    }
+
+    // Mixes 1000 random updates and range products on a longer array.
+    fn check_random<M: Monoid<S = i64>>(seed: u64) {
+        let n = 300;
+        let mut rng = XorShift64::new(seed);
+        let mut naive: Vec<i64> = (0..n)
+            .map(|_| rng.next_range(0, 2001) as i64 - 1000)
+            .collect();
+        let mut st = SegTree::<M>::from_vec(naive.clone());
+
+        for _ in 0..1000 {
+            if rng.next_range(0, 2) == 0 {
+                let i = rng.next_range(0, n as u64) as usize;
+                let x = rng.next_range(0, 2001) as i64 - 1000;
+                st.set(i, x);
+                naive[i] = x;
+            } else {
+                let l = rng.next_range(0, n as u64 + 1) as usize;
+                let r = rng.next_range(l as u64, n as u64 + 1) as usize;
+                let expected = naive[l..r]
+                    .iter()
+                    .fold(M::identity(), |acc, y| M::op(&acc, y));
+                assert_eq!(expected, st.prod(l, r));
+            }
+        }
+    }
+
+    #[test]
+    fn random_queries() {
+        check_random::<SumMonoidI64>(13);
+        check_random::<MaxMonoidI64>(14);
+        check_random::<MinMonoidI64>(15);
+    }
+
+    #[test]
+    fn gcd_xor() {
+        let multiples = |v: Vec<u64>| -> Vec<u64> { v.into_iter().map(|x| x * 6).collect() };
+
+        check::<GCDMonoid>(
+            multiples(random_vec(13, 7, 50)),
+            multiples(random_vec(5, 8, 50)),
+        );
+        check::<XORMonoid>(random_vec(13, 9, 1 << 40), random_vec(5, 10, 1 << 40));
+    }
+
+    #[test]
+    fn product() {
+        let mints = |v: Vec<u64>| -> Vec<ModInt998244353> {
+            v.into_iter().map(ModInt998244353::new).collect()
+        };
+
+        check::<ProductMonoid<Mod998244353>>(
+            mints(random_vec(17, 11, 998_244_353)),
+            mints(random_vec(5, 12, 998_244_353)),
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let st = SegTree::<MinMonoidI64>::new(3);
+        assert_eq!(std::i64::MAX, st.all_prod());
+        assert_eq!(0, SegTree::<SumMonoidI64>::new(0).all_prod());
+        assert_eq!(0, SegTree::<GCDMonoid>::from_vec(vec![0, 0]).prod(0, 2));
+    }
+}
@@ -0,0 +1,183 @@
+//! Skew heap.
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    val: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Meldable min-heap, using a skew heap.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::skew_heap::SkewHeap;
+///
+/// let mut a = SkewHeap::new();
+/// a.push(5);
+/// a.push(1);
+/// let mut b = SkewHeap::new();
+/// b.push(3);
+///
+/// let mut c = a.meld(b);
+/// assert_eq!(Some(&1), c.peek());
+/// assert_eq!(Some(1), c.pop());
+/// assert_eq!(Some(3), c.pop());
+/// assert_eq!(Some(5), c.pop());
+/// assert_eq!(None, c.pop());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkewHeap<T: Ord> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> SkewHeap<T> {
+    /// Constructs a new empty heap.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Pushes a value.
+    /// Consumes O(log n) amortized time.
+    pub fn push(&mut self, x: T) {
+        let node = Some(Box::new(Node {
+            val: x,
+            left: None,
+            right: None,
+        }));
+        self.root = Self::meld_links(self.root.take(), node);
+        self.len += 1;
+    }
+
+    /// Pops the minimum, or returns None if the heap is empty.
+    /// Consumes O(log n) amortized time.
+    pub fn pop(&mut self) -> Option<T> {
+        let root = *self.root.take()?;
+        self.root = Self::meld_links(root.left, root.right);
+        self.len -= 1;
+        Some(root.val)
+    }
+
+    /// Returns the minimum, or None if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.val)
+    }
+
+    /// Melds two heaps into one.
+    /// Consumes O(log n) amortized time.
+    pub fn meld(mut self, mut other: Self) -> Self {
+        Self {
+            root: Self::meld_links(self.root.take(), other.root.take()),
+            len: self.len + other.len,
+        }
+    }
+
+    fn meld_links(mut a: Link<T>, mut b: Link<T>) -> Link<T> {
+        // merges the right spines, then swaps the children along the merged path
+        let mut spine = vec![];
+        let mut rest = loop {
+            match (a, b) {
+                (None, x) | (x, None) => break x,
+                (Some(mut x), Some(y)) => {
+                    if x.val > y.val {
+                        a = Some(y);
+                        b = Some(x);
+                        continue;
+                    }
+                    a = x.right.take();
+                    b = Some(y);
+                    spine.push(x);
+                }
+            }
+        };
+
+        while let Some(mut node) = spine.pop() {
+            node.right = node.left.take();
+            node.left = rest;
+            rest = Some(node);
+        }
+        rest
+    }
+}
+
+impl<T: Ord> Drop for SkewHeap<T> {
+    // drops the nodes iteratively, as the paths can be long
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, 1000)).collect()
+    }
+
+    #[test]
+    fn meld() {
+        let a = random_vec(100, 1);
+        let b = random_vec(100, 2);
+
+        let mut ha = SkewHeap::new();
+        a.iter().for_each(|&x| ha.push(x));
+        let mut hb = SkewHeap::new();
+        b.iter().for_each(|&x| hb.push(x));
+
+        let mut h = ha.meld(hb);
+        assert_eq!(200, h.len());
+
+        let mut expected: Vec<u64> = a.iter().chain(&b).copied().collect();
+        expected.sort();
+        let popped: Vec<u64> = std::iter::from_fn(|| h.pop()).collect();
+        assert_eq!(expected, popped);
+        assert!(h.is_empty());
+    }
+
+    #[test]
+    fn interleaved() {
+        let mut h = SkewHeap::new();
+        let mut naive = std::collections::BinaryHeap::new();
+        for (i, &x) in random_vec(1000, 3).iter().enumerate() {
+            if i % 3 == 2 {
+                assert_eq!(naive.pop().map(|std::cmp::Reverse(y)| y), h.pop());
+            } else {
+                h.push(x);
+                naive.push(std::cmp::Reverse(x));
+            }
+            assert_eq!(naive.peek().map(|std::cmp::Reverse(y)| y), h.peek());
+        }
+    }
+
+    #[test]
+    fn long_path() {
+        let mut h = SkewHeap::new();
+        for x in (0..200_000).rev() {
+            h.push(x);
+        }
+        assert_eq!(Some(0), h.pop());
+        assert_eq!(Some(&1), h.peek());
+    }
+}
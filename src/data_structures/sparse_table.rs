@@ -0,0 +1,120 @@
+//! Sparse table.
+
+/// Static range product for an idempotent operation such as min, max and gcd.
+/// Idempotence, `op(x, x) = x`, lets a query combine two overlapping power-of-two ranges,
+/// so the result is wrong for operations like addition;
+/// use [`DisjointSparseTable`] for them.
+///
+/// [`DisjointSparseTable`]: ../disjoint_sparse_table/struct.DisjointSparseTable.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::sparse_table::SparseTable;
+///
+/// let table = SparseTable::new(&[3, 1, 4, 1, 5, 9, 2, 6], std::cmp::min);
+/// assert_eq!(1, table.query(1, 5));
+/// assert_eq!(2, table.query(5, 8));
+/// assert_eq!(9, table.query(5, 6));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseTable<T: Clone, F: Fn(T, T) -> T> {
+    // table[k][i] is the product of [i, i + 2^k)
+    table: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T: Clone, F: Fn(T, T) -> T> SparseTable<T, F> {
+    /// Constructs the table with the associative and idempotent operation `op`.
+    /// Consumes O(n log n) time.
+    pub fn new(data: &[T], op: F) -> Self {
+        let mut table = vec![data.to_vec()];
+        let mut k = 1;
+        while 2 * k <= data.len() {
+            let prev = table.last().unwrap();
+            let level = (0..=data.len() - 2 * k)
+                .map(|i| op(prev[i].clone(), prev[i + k].clone()))
+                .collect();
+            table.push(level);
+            k *= 2;
+        }
+        Self { table, op }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the product of `[l, r)`.
+    /// Consumes O(1) time.
+    ///
+    /// # Panics
+    /// Panics if `l >= r` or `r > n`.
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(
+            l < r && r <= self.len(),
+            "The range is required to be nonempty"
+        );
+        let k = (0usize.leading_zeros() - (r - l).leading_zeros()) as usize - 1;
+        (self.op)(
+            self.table[k][l].clone(),
+            self.table[k][r - (1 << k)].clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::segtree::{GCDMonoid, Monoid};
+    use crate::utils::random::XorShift64;
+
+    fn check<F: Fn(u64, u64) -> u64>(data: &[u64], op: F, rng: &mut XorShift64) {
+        let table = SparseTable::new(data, &op);
+        let n = data.len();
+        for _ in 0..10000 {
+            let l = rng.next_range(0, n as u64) as usize;
+            let r = rng.next_range(l as u64 + 1, n as u64 + 1) as usize;
+            let expected = data[l + 1..r].iter().fold(data[l], |acc, &x| op(acc, x));
+            assert_eq!(expected, table.query(l, r));
+        }
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(516);
+        let data: Vec<u64> = (0..1000).map(|_| rng.next_range(0, 1_000_000)).collect();
+        check(&data, std::cmp::min, &mut rng);
+        check(&data, std::cmp::max, &mut rng);
+
+        let multiples: Vec<u64> = data.iter().map(|&x| x % 100 * 12).collect();
+        check(&multiples, |a, b| GCDMonoid::op(&a, &b), &mut rng);
+    }
+
+    #[test]
+    fn every_range() {
+        for n in 1..20 {
+            let data: Vec<i64> = (0..n).map(|i| (i * 7 % 11) as i64).collect();
+            let table = SparseTable::new(&data, std::cmp::max);
+            assert_eq!(n, table.len());
+            for l in 0..n {
+                for r in l + 1..=n {
+                    assert_eq!(*data[l..r].iter().max().unwrap(), table.query(l, r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_range() {
+        let table = SparseTable::new(&[1, 2, 3], std::cmp::min);
+        table.query(1, 1);
+    }
+}
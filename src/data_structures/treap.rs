@@ -0,0 +1,315 @@
+//! Implicit treap.
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    val: T,
+    priority: u64,
+    size: usize,
+    rev: bool,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+fn size<T>(t: &Link<T>) -> usize {
+    t.as_ref().map_or(0, |node| node.size)
+}
+
+fn push<T>(node: &mut Node<T>) {
+    if node.rev {
+        std::mem::swap(&mut node.left, &mut node.right);
+        for child in node.left.iter_mut().chain(node.right.iter_mut()) {
+            child.rev ^= true;
+        }
+        node.rev = false;
+    }
+}
+
+fn update<T>(node: &mut Node<T>) {
+    node.size = 1 + size(&node.left) + size(&node.right);
+}
+
+fn merge_links<T>(a: Link<T>, b: Link<T>) -> Link<T> {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                push(&mut a);
+                a.right = merge_links(a.right.take(), Some(b));
+                update(&mut a);
+                Some(a)
+            } else {
+                push(&mut b);
+                b.left = merge_links(Some(a), b.left.take());
+                update(&mut b);
+                Some(b)
+            }
+        }
+    }
+}
+
+// Splits into the first k values and the rest.
+fn split_links<T>(t: Link<T>, k: usize) -> (Link<T>, Link<T>) {
+    match t {
+        None => (None, None),
+        Some(mut node) => {
+            push(&mut node);
+            let left_size = size(&node.left);
+            if k <= left_size {
+                let (a, b) = split_links(node.left.take(), k);
+                node.left = b;
+                update(&mut node);
+                (a, Some(node))
+            } else {
+                let (a, b) = split_links(node.right.take(), k - left_size - 1);
+                node.right = a;
+                update(&mut node);
+                (Some(node), b)
+            }
+        }
+    }
+}
+
+/// Sequence with implicit keys, using a treap.
+/// Supports splitting, merging, and reversing ranges in O(log n) expected time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::treap::Treap;
+///
+/// let mut t = Treap::new();
+/// for (i, &x) in [1, 2, 3, 4, 5].iter().enumerate() {
+///     t.insert_at(i, x);
+/// }
+///
+/// t.range_reverse(1, 4);
+/// assert_eq!(vec![1, 4, 3, 2, 5], t.to_vec());
+///
+/// assert_eq!(3, t.remove_at(2));
+/// let (left, right) = t.split(1);
+/// assert_eq!(vec![1], left.to_vec());
+/// assert_eq!(vec![4, 2, 5], right.to_vec());
+///
+/// let t = Treap::merge(right, left);
+/// assert_eq!(vec![4, 2, 5, 1], t.to_vec());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Treap<T: Clone> {
+    root: Link<T>,
+    seed: u64,
+}
+
+impl<T: Clone> Treap<T> {
+    /// Constructs a new empty sequence.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            seed: 88_172_645_463_325_252,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Returns true if the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Splits into the first pos values and the rest, leaving self empty.
+    /// Consumes O(log n) expected time.
+    ///
+    /// # Panics
+    /// Panics if pos is out of bounds.
+    pub fn split(&mut self, pos: usize) -> (Self, Self) {
+        assert!(pos <= self.len());
+        let (a, b) = split_links(self.root.take(), pos);
+        let seed = self.next_priority();
+        (
+            Self { root: a, seed },
+            Self {
+                root: b,
+                seed: seed.rotate_left(32),
+            },
+        )
+    }
+
+    /// Concatenates two sequences.
+    /// Consumes O(log n) expected time.
+    pub fn merge(left: Self, right: Self) -> Self {
+        Self {
+            root: merge_links(left.root, right.root),
+            seed: left.seed ^ right.seed.rotate_left(17),
+        }
+    }
+
+    /// Inserts a value so that it is at pos.
+    /// Consumes O(log n) expected time.
+    ///
+    /// # Panics
+    /// Panics if pos is out of bounds.
+    pub fn insert_at(&mut self, pos: usize, val: T) {
+        assert!(pos <= self.len());
+        let node = Some(Box::new(Node {
+            val,
+            priority: self.next_priority(),
+            size: 1,
+            rev: false,
+            left: None,
+            right: None,
+        }));
+        let (a, b) = split_links(self.root.take(), pos);
+        self.root = merge_links(merge_links(a, node), b);
+    }
+
+    /// Removes the value at pos and returns it.
+    /// Consumes O(log n) expected time.
+    ///
+    /// # Panics
+    /// Panics if pos is out of bounds.
+    pub fn remove_at(&mut self, pos: usize) -> T {
+        assert!(pos < self.len());
+        let (a, b) = split_links(self.root.take(), pos);
+        let (mid, c) = split_links(b, 1);
+        self.root = merge_links(a, c);
+        mid.unwrap().val
+    }
+
+    /// Reverses the values in [l, r).
+    /// Consumes O(log n) expected time.
+    ///
+    /// # Panics
+    /// Panics if the range is invalid.
+    pub fn range_reverse(&mut self, l: usize, r: usize) {
+        assert!(l <= r && r <= self.len());
+        let (left, rest) = split_links(self.root.take(), l);
+        let (mut mid, right) = split_links(rest, r - l);
+        if let Some(node) = mid.as_mut() {
+            node.rev ^= true;
+        }
+        self.root = merge_links(merge_links(left, mid), right);
+    }
+
+    /// Returns the values in order.
+    /// Consumes O(n) time.
+    pub fn to_vec(&self) -> Vec<T> {
+        fn dfs<T: Clone>(t: &Link<T>, rev: bool, out: &mut Vec<T>) {
+            if let Some(node) = t {
+                let rev = rev ^ node.rev;
+                let (first, second) = if rev {
+                    (&node.right, &node.left)
+                } else {
+                    (&node.left, &node.right)
+                };
+                dfs(first, rev, out);
+                out.push(node.val.clone());
+                dfs(second, rev, out);
+            }
+        }
+
+        let mut out = Vec::with_capacity(self.len());
+        dfs(&self.root, false, &mut out);
+        out
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 7;
+        self.seed ^= self.seed >> 9;
+        self.seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn josephus() {
+        // n people in a circle, every k-th one is eliminated
+        let (n, k) = (41, 3);
+        let mut t = Treap::new();
+        for i in 0..n {
+            t.insert_at(i, i + 1);
+        }
+
+        let mut order = vec![];
+        while !t.is_empty() {
+            // rotate so that the k-th person comes first
+            let pos = (k - 1) % t.len();
+            let (left, right) = t.split(pos);
+            t = Treap::merge(right, left);
+            order.push(t.remove_at(0));
+        }
+
+        let mut naive: Vec<usize> = (1..=n).collect();
+        let mut expected = vec![];
+        let mut pos = 0;
+        while !naive.is_empty() {
+            pos = (pos + k - 1) % naive.len();
+            expected.push(naive.remove(pos));
+        }
+        assert_eq!(expected, order);
+        // the survivor of the classic story
+        assert_eq!(Some(&31), order.last());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(13);
+        let mut rand = |m: usize| rng.next_range(0, m as u64) as usize;
+
+        let mut t = Treap::new();
+        let mut naive = vec![];
+        for step in 0..3000 {
+            match rand(4) {
+                0 => {
+                    let pos = rand(naive.len() + 1);
+                    t.insert_at(pos, step);
+                    naive.insert(pos, step);
+                }
+                1 if !naive.is_empty() => {
+                    let pos = rand(naive.len());
+                    assert_eq!(naive.remove(pos), t.remove_at(pos));
+                }
+                2 => {
+                    let l = rand(naive.len() + 1);
+                    let r = l + rand(naive.len() - l + 1);
+                    t.range_reverse(l, r);
+                    naive[l..r].reverse();
+                }
+                _ => {
+                    let pos = rand(naive.len() + 1);
+                    let (left, right) = t.split(pos);
+                    assert!(t.is_empty());
+                    assert_eq!(pos, left.len());
+                    assert_eq!(&naive[pos..], right.to_vec().as_slice());
+                    t = Treap::merge(left, right);
+                }
+            }
+            assert_eq!(naive.len(), t.len());
+        }
+        assert_eq!(naive, t.to_vec());
+    }
+
+    #[test]
+    fn reverse_twice() {
+        let mut t = Treap::new();
+        for i in 0..10 {
+            t.insert_at(i, i);
+        }
+        t.range_reverse(2, 8);
+        assert_eq!(vec![0, 1, 7, 6, 5, 4, 3, 2, 8, 9], t.to_vec());
+        t.range_reverse(0, 5);
+        t.range_reverse(3, 3);
+        t.range_reverse(0, 5);
+        t.range_reverse(2, 8);
+        assert_eq!((0..10).collect::<Vec<_>>(), t.to_vec());
+    }
+}
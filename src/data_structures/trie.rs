@@ -0,0 +1,131 @@
+//! Trie.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<u8, usize>,
+    // the number of words ending at this node
+    end: usize,
+    // the number of words passing through this node
+    pass: usize,
+}
+
+/// Trie, or prefix tree. Stores a multiset of words.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::trie::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert(b"apple");
+/// trie.insert(b"app");
+///
+/// assert!(trie.contains(b"app"));
+/// assert!(!trie.contains(b"ap"));
+/// assert!(trie.starts_with(b"ap"));
+/// assert_eq!(2, trie.count_with_prefix(b"app"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    /// Constructs a new empty trie.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Inserts a word.
+    /// Consumes O(|word|) time.
+    pub fn insert(&mut self, word: &[u8]) {
+        let mut v = 0;
+        self.nodes[v].pass += 1;
+
+        for &c in word {
+            v = match self.nodes[v].children.get(&c) {
+                Some(&u) => u,
+                None => {
+                    let u = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[v].children.insert(c, u);
+                    u
+                }
+            };
+            self.nodes[v].pass += 1;
+        }
+
+        self.nodes[v].end += 1;
+    }
+
+    fn find(&self, word: &[u8]) -> Option<&Node> {
+        word.iter()
+            .try_fold(0, |v, c| self.nodes[v].children.get(c).copied())
+            .map(|v| &self.nodes[v])
+    }
+
+    /// Returns true if the word has been inserted.
+    pub fn contains(&self, word: &[u8]) -> bool {
+        self.find(word).map_or(false, |node| node.end > 0)
+    }
+
+    /// Returns true if some inserted word starts with the prefix.
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.count_with_prefix(prefix) > 0
+    }
+
+    /// Returns the number of inserted words, including duplicates.
+    pub fn count_words(&self) -> usize {
+        self.nodes[0].pass
+    }
+
+    /// Returns the number of inserted words starting with the prefix, including duplicates.
+    pub fn count_with_prefix(&self, prefix: &[u8]) -> usize {
+        self.find(prefix).map_or(0, |node| node.pass)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&[u8]; 8] = [b"tea", b"ten", b"to", b"inn", b"in", b"i", b"team", b"tea"];
+
+    #[test]
+    fn contains() {
+        let mut trie = Trie::new();
+        WORDS.iter().for_each(|w| trie.insert(w));
+
+        for w in WORDS.iter() {
+            assert!(trie.contains(w));
+        }
+        for w in &[&b""[..], b"t", b"te", b"tem", b"inns", b"a"] {
+            assert!(!trie.contains(w));
+        }
+    }
+
+    #[test]
+    fn prefix() {
+        let mut trie = Trie::new();
+        assert!(!trie.starts_with(b""));
+
+        WORDS.iter().for_each(|w| trie.insert(w));
+
+        assert_eq!(8, trie.count_words());
+        assert_eq!(8, trie.count_with_prefix(b""));
+        assert_eq!(5, trie.count_with_prefix(b"t"));
+        assert_eq!(3, trie.count_with_prefix(b"tea"));
+        assert_eq!(1, trie.count_with_prefix(b"team"));
+        assert_eq!(3, trie.count_with_prefix(b"i"));
+        assert_eq!(0, trie.count_with_prefix(b"x"));
+
+        assert!(trie.starts_with(b"te"));
+        assert!(!trie.starts_with(b"tx"));
+        assert!(!trie.starts_with(b"teams"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Union-find.
+
+/// Disjoint set union with path compression and union by rank.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::union_find::UnionFind;
+///
+/// let mut uf = UnionFind::new(5);
+/// assert!(uf.unite(0, 1));
+/// assert!(uf.unite(3, 4));
+/// assert!(!uf.unite(1, 0));
+///
+/// assert!(uf.same(0, 1));
+/// assert!(!uf.same(1, 3));
+/// assert_eq!(2, uf.size(4));
+/// assert_eq!(1, uf.size(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u32>,
+    // size[v] is the size of the component if v is a root
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Constructs n singletons.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the root of the component containing x.
+    /// Consumes amortized O(α(n)) time.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut v = x;
+        while self.parent[v] != root {
+            let next = self.parent[v];
+            self.parent[v] = root;
+            v = next;
+        }
+        root
+    }
+
+    /// Merges the components containing x and y.
+    /// Returns true if they were different.
+    /// Consumes amortized O(α(n)) time.
+    pub fn unite(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        if self.rank[x] < self.rank[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        if self.rank[x] == self.rank[y] {
+            self.rank[x] += 1;
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        true
+    }
+
+    /// Returns true if x and y are in the same component.
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Returns the size of the component containing x.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn merge_all() {
+        let n = 10;
+        let mut uf = UnionFind::new(n);
+        assert_eq!(n, uf.len());
+        for i in 0..n {
+            assert_eq!(i, uf.find(i));
+            assert_eq!(1, uf.size(i));
+        }
+
+        for i in 1..n {
+            assert!(uf.unite(i - 1, i));
+            assert_eq!(i + 1, uf.size(0));
+        }
+        assert!(!uf.unite(0, n - 1));
+        assert!((0..n).all(|i| uf.same(0, i) && uf.size(i) == n));
+
+        let root = uf.find(3);
+        assert!((0..n).all(|i| uf.find(i) == root));
+    }
+
+    #[test]
+    fn random() {
+        let n = 50;
+        let mut rng = XorShift64::new(517);
+        let mut uf = UnionFind::new(n);
+        // label of the component of each element
+        let mut label: Vec<usize> = (0..n).collect();
+
+        for _ in 0..200 {
+            let x = rng.next_range(0, n as u64) as usize;
+            let y = rng.next_range(0, n as u64) as usize;
+            let (lx, ly) = (label[x], label[y]);
+            assert_eq!(lx != ly, uf.unite(x, y));
+            label.iter_mut().filter(|l| **l == ly).for_each(|l| *l = lx);
+
+            for v in 0..n {
+                assert_eq!(label[x] == label[v], uf.same(x, v));
+            }
+            let size = label.iter().filter(|&&l| l == label[y]).count();
+            assert_eq!(size, uf.size(y));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Wavelet tree.
+
+const BITS: usize = 32;
+
+/// Wavelet tree over 32-bit values, stored level by level as a wavelet matrix.
+/// Ranges are half-open.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::wavelet::WaveletTree;
+///
+/// let wt = WaveletTree::new(&[5, 1, 4, 1, 3, 9, 2]);
+///
+/// assert_eq!(3, wt.kth_smallest(1, 5, 3));
+/// assert_eq!(2, wt.count_less_than(1, 5, 3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaveletTree {
+    len: usize,
+    // zeros[k][i]: the number of values among the first i at the level whose bit is 0
+    zeros: Vec<Vec<usize>>,
+}
+
+impl WaveletTree {
+    /// Constructs a new wavelet tree.
+    /// Consumes O(32 n) time.
+    pub fn new(data: &[u32]) -> Self {
+        let mut cur = data.to_vec();
+        let mut zeros = Vec::with_capacity(BITS);
+
+        for k in (0..BITS).rev() {
+            let mut z = Vec::with_capacity(cur.len() + 1);
+            z.push(0);
+            for &x in cur.iter() {
+                z.push(z.last().unwrap() + (x >> k & 1 ^ 1) as usize);
+            }
+            zeros.push(z);
+
+            // stable partition by the bit
+            let (lo, hi): (Vec<u32>, Vec<u32>) = cur.iter().partition(|&&x| x >> k & 1 == 0);
+            cur = lo;
+            cur.extend(hi);
+        }
+
+        Self {
+            len: data.len(),
+            zeros,
+        }
+    }
+
+    /// Returns the length of the data.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the k-th smallest value in `data[l..r]`, where k is 1-indexed.
+    /// Consumes O(32) time.
+    ///
+    /// # Panics
+    /// Panics unless `1 <= k <= r - l`.
+    pub fn kth_smallest(&self, mut l: usize, mut r: usize, mut k: usize) -> u32 {
+        assert!(l <= r && r <= self.len);
+        assert!(1 <= k && k <= r - l, "k is out of range");
+
+        let mut res = 0;
+        for (level, z) in self.zeros.iter().enumerate() {
+            let total = z[self.len];
+            let count = z[r] - z[l];
+            if k <= count {
+                l = z[l];
+                r = z[r];
+            } else {
+                k -= count;
+                res |= 1 << (BITS - 1 - level);
+                l = total + l - z[l];
+                r = total + r - z[r];
+            }
+        }
+
+        res
+    }
+
+    /// Returns the number of values less than v in `data[l..r]`.
+    /// Consumes O(32) time.
+    pub fn count_less_than(&self, mut l: usize, mut r: usize, v: u32) -> usize {
+        assert!(l <= r && r <= self.len);
+
+        let mut res = 0;
+        for (level, z) in self.zeros.iter().enumerate() {
+            let total = z[self.len];
+            if v >> (BITS - 1 - level) & 1 == 0 {
+                l = z[l];
+                r = z[r];
+            } else {
+                res += z[r] - z[l];
+                l = total + l - z[l];
+                r = total + r - z[r];
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u32> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, modulus) as u32)
+            .collect()
+    }
+
+    #[test]
+    fn kth_smallest() {
+        for &modulus in &[5, 1000, 1 << 32] {
+            let a = random_vec(100, modulus, modulus);
+            let wt = WaveletTree::new(&a);
+
+            for l in 0..a.len() {
+                for r in l + 1..=a.len() {
+                    let mut sorted = a[l..r].to_vec();
+                    sorted.sort();
+                    for k in 1..=r - l {
+                        assert_eq!(sorted[k - 1], wt.kth_smallest(l, r, k));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn count_less_than() {
+        for &modulus in &[5, 1000, 1 << 32] {
+            let a = random_vec(100, !modulus, modulus);
+            let queries = random_vec(20, modulus, modulus);
+            let wt = WaveletTree::new(&a);
+
+            for l in 0..=a.len() {
+                for r in l..=a.len() {
+                    for &v in queries.iter().chain(&[0, std::u32::MAX]) {
+                        let naive = a[l..r].iter().filter(|&&x| x < v).count();
+                        assert_eq!(naive, wt.count_less_than(l, r, v));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn kth_out_of_range() {
+        WaveletTree::new(&[1, 2, 3]).kth_smallest(0, 2, 3);
+    }
+}
@@ -0,0 +1,133 @@
+//! Sliding window sum.
+
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+
+/// Queue keeping the sum of its values, for sliding windows and two pointers.
+/// Works with any type with addition and subtraction, such as ModInt.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::window::WindowSum;
+///
+/// let mut window = WindowSum::new();
+/// window.push_back(3);
+/// window.push_back(1);
+/// window.push_back(4);
+/// assert_eq!(8, window.sum());
+///
+/// assert_eq!(Some(3), window.pop_front());
+/// assert_eq!(5, window.sum());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WindowSum<T> {
+    values: VecDeque<T>,
+    sum: T,
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> WindowSum<T> {
+    /// Constructs a new empty window.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            values: VecDeque::new(),
+            sum: T::default(),
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pushes a value to the back.
+    /// Consumes O(1) time.
+    pub fn push_back(&mut self, x: T) {
+        self.values.push_back(x);
+        self.sum = self.sum + x;
+    }
+
+    /// Pops the value at the front, or returns None if the window is empty.
+    /// Consumes O(1) time.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let x = self.values.pop_front()?;
+        self.sum = self.sum - x;
+        Some(x)
+    }
+
+    /// Returns the sum of the values, which is `T::default()` if the window is empty.
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::ModInt998244353;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn it_works() {
+        let a = [1, 2, 3, 4, 5];
+        let k = 3;
+
+        let mut window = WindowSum::new();
+        let mut sums = vec![window.sum()];
+        for (i, &x) in a.iter().enumerate() {
+            window.push_back(x);
+            if i >= k {
+                window.pop_front();
+            }
+            sums.push(window.sum());
+        }
+        assert_eq!(vec![0, 1, 3, 6, 9, 12], sums);
+        assert_eq!(k, window.len());
+    }
+
+    #[test]
+    fn two_pointers() {
+        // the number of subarrays with sum at most s
+        let mut rng = XorShift64::new(3);
+        let a: Vec<i64> = (0..200).map(|_| rng.next_range(0, 10) as i64).collect();
+        let s = 30;
+
+        let mut window = WindowSum::new();
+        let mut count = 0;
+        for &x in &a {
+            window.push_back(x);
+            while window.sum() > s {
+                window.pop_front();
+            }
+            count += window.len();
+        }
+
+        let naive = (0..a.len())
+            .flat_map(|l| (l + 1..=a.len()).map(move |r| (l, r)))
+            .filter(|&(l, r)| a[l..r].iter().sum::<i64>() <= s)
+            .count();
+        assert_eq!(naive, count);
+    }
+
+    #[test]
+    fn modint() {
+        type Mint = ModInt998244353;
+        let mut window = WindowSum::new();
+        window.push_back(Mint::new(998_244_352));
+        window.push_back(Mint::new(5));
+        assert_eq!(Mint::new(4), window.sum());
+
+        assert_eq!(Some(Mint::new(998_244_352)), window.pop_front());
+        assert_eq!(Mint::new(5), window.sum());
+        assert_eq!(Some(Mint::new(5)), window.pop_front());
+        assert_eq!(None, window.pop_front());
+        assert!(window.is_empty());
+        assert_eq!(Mint::new(0), window.sum());
+    }
+}
@@ -0,0 +1,162 @@
+//! Binary trie for XOR queries.
+
+const BITS: usize = 64;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: [Option<usize>; 2],
+    count: usize,
+}
+
+/// Binary trie storing a multiset of 64-bit integers, from MSB to LSB.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::xor_trie::XorTrie;
+///
+/// let mut trie = XorTrie::new();
+/// trie.insert(3);
+/// trie.insert(10);
+/// trie.insert(5);
+///
+/// assert_eq!(15, trie.max_xor(5));
+///
+/// trie.remove(10);
+/// assert_eq!(6, trie.max_xor(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorTrie {
+    nodes: Vec<Node>,
+}
+
+impl XorTrie {
+    /// Constructs a new empty trie.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Inserts x.
+    /// Consumes O(64) time.
+    pub fn insert(&mut self, x: u64) {
+        let mut v = 0;
+        self.nodes[v].count += 1;
+
+        for k in (0..BITS).rev() {
+            let b = (x >> k & 1) as usize;
+            v = match self.nodes[v].children[b] {
+                Some(u) => u,
+                None => {
+                    let u = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[v].children[b] = Some(u);
+                    u
+                }
+            };
+            self.nodes[v].count += 1;
+        }
+    }
+
+    /// Removes one occurrence of x.
+    /// Consumes O(64) time.
+    ///
+    /// # Panics
+    /// Panics if x is not contained.
+    pub fn remove(&mut self, x: u64) {
+        let mut path = Vec::with_capacity(BITS + 1);
+        let mut v = 0;
+        path.push(v);
+
+        for k in (0..BITS).rev() {
+            v = self.nodes[v].children[(x >> k & 1) as usize]
+                .filter(|&u| self.nodes[u].count > 0)
+                .expect("Attempted to remove an absent value");
+            path.push(v);
+        }
+
+        path.into_iter().for_each(|v| self.nodes[v].count -= 1);
+    }
+
+    /// Returns the maximum of `x ^ y` over the contained values y.
+    /// Consumes O(64) time.
+    ///
+    /// # Panics
+    /// Panics if the trie is empty.
+    pub fn max_xor(&self, x: u64) -> u64 {
+        assert!(self.nodes[0].count > 0, "The trie is empty");
+
+        let alive = |u: Option<usize>| u.filter(|&u| self.nodes[u].count > 0);
+
+        let mut v = 0;
+        let mut res = 0;
+        for k in (0..BITS).rev() {
+            let b = (x >> k & 1) as usize;
+            v = match alive(self.nodes[v].children[b ^ 1]) {
+                Some(u) => {
+                    res |= 1 << k;
+                    u
+                }
+                None => alive(self.nodes[v].children[b]).unwrap(),
+            };
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, modulus)).collect()
+    }
+
+    #[test]
+    fn max_xor() {
+        for &modulus in &[4, 1000, std::u64::MAX] {
+            let a = random_vec(50, modulus, modulus);
+            let queries = random_vec(50, !modulus, modulus);
+
+            let mut trie = XorTrie::new();
+            for (i, &x) in a.iter().enumerate() {
+                trie.insert(x);
+                for &q in queries.iter() {
+                    let naive = a[..=i].iter().map(|&y| q ^ y).max().unwrap();
+                    assert_eq!(naive, trie.max_xor(q));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let a = random_vec(40, 7, 64);
+        let queries = random_vec(20, 8, 64);
+
+        let mut trie = XorTrie::new();
+        a.iter().for_each(|&x| trie.insert(x));
+
+        for i in 0..a.len() - 1 {
+            trie.remove(a[i]);
+            for &q in queries.iter() {
+                let naive = a[i + 1..].iter().map(|&y| q ^ y).max().unwrap();
+                assert_eq!(naive, trie.max_xor(q));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_absent() {
+        let mut trie = XorTrie::new();
+        trie.insert(1);
+        trie.remove(1);
+        trie.remove(1);
+    }
+}
@@ -0,0 +1,6 @@
+//! Geometry.
+
+pub mod closest_pair;
+pub mod convex_hull;
+pub mod min_enclosing_circle;
+pub mod point;
@@ -0,0 +1,165 @@
+//! Closest pair of points.
+
+use super::point::Point;
+
+/// Finds the closest pair of points, using divide and conquer.
+/// Returns the distance and the indices i < j of the pair in the original order.
+/// `points` are sorted in place by x and then y.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if there are fewer than 2 points.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::geometry::{closest_pair::closest_pair, point::Point};
+///
+/// let mut points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(5.0, 4.0),
+///     Point::new(3.0, 1.0),
+///     Point::new(4.0, 3.0),
+/// ];
+///
+/// let (d, i, j) = closest_pair(&mut points);
+/// assert!((d - 2f64.sqrt()).abs() < 1e-9);
+/// assert_eq!((1, 3), (i, j));
+/// ```
+pub fn closest_pair(points: &mut [Point<f64>]) -> (f64, usize, usize) {
+    assert!(points.len() >= 2, "At least 2 points are required");
+
+    let original = points.to_vec();
+    let mut idx: Vec<usize> = (0..points.len()).collect();
+    idx.sort_by(|&a, &b| {
+        let (p, q) = (original[a], original[b]);
+        (p.x, p.y).partial_cmp(&(q.x, q.y)).unwrap()
+    });
+    for (p, &i) in points.iter_mut().zip(&idx) {
+        *p = original[i];
+    }
+
+    let (d, i, j) = closest_rec(&original, &mut idx);
+    (d, i.min(j), i.max(j))
+}
+
+// idx is sorted by x on entry, and by y on exit
+fn closest_rec(ps: &[Point<f64>], idx: &mut [usize]) -> (f64, usize, usize) {
+    let n = idx.len();
+    if n <= 3 {
+        let mut best = (std::f64::INFINITY, 0, 0);
+        for a in 0..n {
+            for b in a + 1..n {
+                let d = ps[idx[a]].dist(&ps[idx[b]]);
+                if d < best.0 {
+                    best = (d, idx[a], idx[b]);
+                }
+            }
+        }
+        idx.sort_by(|&a, &b| ps[a].y.partial_cmp(&ps[b].y).unwrap());
+        return best;
+    }
+
+    let mid = n / 2;
+    let mid_x = ps[idx[mid]].x;
+    let left = closest_rec(ps, &mut idx[..mid]);
+    let right = closest_rec(ps, &mut idx[mid..]);
+    let mut best = if left.0 <= right.0 { left } else { right };
+
+    // merge by y
+    let mut merged = Vec::with_capacity(n);
+    let (mut a, mut b) = (0, mid);
+    while a < mid || b < n {
+        if b == n || (a < mid && ps[idx[a]].y <= ps[idx[b]].y) {
+            merged.push(idx[a]);
+            a += 1;
+        } else {
+            merged.push(idx[b]);
+            b += 1;
+        }
+    }
+    idx.copy_from_slice(&merged);
+
+    // the points in the strip, compared with the few preceding ones by y
+    let mut strip: Vec<usize> = Vec::new();
+    for &i in idx.iter() {
+        if (ps[i].x - mid_x).abs() >= best.0 {
+            continue;
+        }
+        for &j in strip.iter().rev() {
+            if ps[i].y - ps[j].y >= best.0 {
+                break;
+            }
+            let d = ps[i].dist(&ps[j]);
+            if d < best.0 {
+                best = (d, j, i);
+            }
+        }
+        strip.push(i);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn brute_force(ps: &[Point<f64>]) -> f64 {
+        let mut best = std::f64::INFINITY;
+        for i in 0..ps.len() {
+            for j in i + 1..ps.len() {
+                best = best.min(ps[i].dist(&ps[j]));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = |m: u64| rng.next_range(0, m) as f64;
+
+        for &m in &[10, 1000, 1_000_000] {
+            for _ in 0..20 {
+                let original: Vec<Point<f64>> =
+                    (0..50).map(|_| Point::new(rand(m), rand(m))).collect();
+                let mut points = original.clone();
+
+                let (d, i, j) = closest_pair(&mut points);
+                assert!(i < j);
+                assert!((brute_force(&original) - d).abs() < 1e-9);
+                assert!((original[i].dist(&original[j]) - d).abs() < 1e-9);
+                assert!(points
+                    .windows(2)
+                    .all(|w| (w[0].x, w[0].y) <= (w[1].x, w[1].y)));
+            }
+        }
+    }
+
+    #[test]
+    fn identical() {
+        let mut points = vec![
+            Point::new(1.0, 1.0),
+            Point::new(7.0, 2.0),
+            Point::new(-3.0, 5.0),
+            Point::new(7.0, 2.0),
+            Point::new(0.0, 9.0),
+        ];
+        assert_eq!((0.0, 1, 3), closest_pair(&mut points));
+    }
+
+    #[test]
+    fn two_points() {
+        let mut points = vec![Point::new(3.0, 4.0), Point::new(0.0, 0.0)];
+        assert_eq!((5.0, 0, 1), closest_pair(&mut points));
+        assert_eq!(vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)], points);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single() {
+        closest_pair(&mut [Point::new(0.0, 0.0)]);
+    }
+}
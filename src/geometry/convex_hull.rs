@@ -0,0 +1,171 @@
+//! Convex hull.
+
+use super::point::Point;
+
+const EPS: f64 = 1e-9;
+
+/// Computes the convex hull, using Andrew's monotone chain algorithm.
+/// Returns the vertices in counterclockwise order,
+/// starting from the lowest point among the leftmost ones.
+/// If `strict` is false, the points on the edges of the hull are also included.
+/// `points` are sorted in place.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::geometry::{convex_hull::convex_hull, point::Point};
+///
+/// let mut points: Vec<_> = [(0.0, 0.0), (2.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 2.0), (1.0, 0.0)]
+///     .iter()
+///     .map(|&(x, y)| Point::new(x, y))
+///     .collect();
+///
+/// let hull = convex_hull(&mut points, true);
+/// assert_eq!(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 2.0)], hull);
+///
+/// let hull = convex_hull(&mut points, false);
+/// assert_eq!(5, hull.len());
+/// ```
+pub fn convex_hull(points: &mut [Point<f64>], strict: bool) -> Vec<Point<f64>> {
+    points.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+
+    let mut ps = points.to_vec();
+    ps.dedup();
+    if ps.len() <= 1 {
+        return ps;
+    }
+
+    let first = ps[0];
+    let last = ps[ps.len() - 1];
+    if ps
+        .iter()
+        .all(|&p| (last - first).cross(&(p - first)).abs() <= EPS)
+    {
+        return if strict { vec![first, last] } else { ps };
+    }
+
+    let removable = |a: Point<f64>, b: Point<f64>, c: Point<f64>| {
+        let cross = (b - a).cross(&(c - a));
+        if strict {
+            cross <= EPS
+        } else {
+            cross < -EPS
+        }
+    };
+
+    let mut hull: Vec<Point<f64>> = Vec::with_capacity(2 * ps.len());
+    for &p in ps.iter() {
+        while hull.len() >= 2 && removable(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    let lower_len = hull.len();
+    for &p in ps.iter().rev().skip(1) {
+        while hull.len() > lower_len && removable(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(ps: &[(f64, f64)]) -> Vec<Point<f64>> {
+        ps.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn unit_square() {
+        let mut ps = points(&[
+            (0.5, 0.5),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (0.2, 0.7),
+        ]);
+        let expected = points(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+
+        assert_eq!(expected, convex_hull(&mut ps, true));
+        assert_eq!(expected, convex_hull(&mut ps, false));
+    }
+
+    #[test]
+    fn collinear() {
+        let mut ps = points(&[(2.0, 2.0), (0.0, 0.0), (3.0, 3.0), (1.0, 1.0), (1.0, 1.0)]);
+
+        assert_eq!(
+            points(&[(0.0, 0.0), (3.0, 3.0)]),
+            convex_hull(&mut ps, true)
+        );
+        assert_eq!(
+            points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]),
+            convex_hull(&mut ps, false)
+        );
+    }
+
+    #[test]
+    fn collinear_on_edges() {
+        let mut ps = points(&[
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (2.0, 2.0),
+            (1.0, 1.0),
+            (0.0, 2.0),
+            (0.0, 1.0),
+        ]);
+
+        assert_eq!(4, convex_hull(&mut ps, true).len());
+        assert_eq!(
+            points(&[
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (2.0, 2.0),
+                (0.0, 2.0),
+                (0.0, 1.0)
+            ]),
+            convex_hull(&mut ps, false)
+        );
+    }
+
+    #[test]
+    fn hexagon() {
+        let mut ps: Vec<Point<f64>> = (0..6)
+            .map(|i| {
+                let t = std::f64::consts::PI / 3.0 * i as f64;
+                Point::new(t.cos(), t.sin())
+            })
+            .collect();
+        ps.push(Point::new(0.0, 0.0));
+        ps.push(Point::new(0.3, -0.4));
+
+        let hull = convex_hull(&mut ps, true);
+        assert_eq!(6, hull.len());
+        assert!(hull.iter().all(|p| (p.norm() - 1.0).abs() < EPS));
+
+        let n = hull.len();
+        assert!((0..n)
+            .all(|i| (hull[(i + 1) % n] - hull[i]).cross(&(hull[(i + 2) % n] - hull[i])) > 0.0));
+    }
+
+    #[test]
+    fn small() {
+        assert!(convex_hull(&mut [], true).is_empty());
+        assert_eq!(
+            points(&[(1.0, 2.0)]),
+            convex_hull(&mut points(&[(1.0, 2.0), (1.0, 2.0)]), false)
+        );
+    }
+}
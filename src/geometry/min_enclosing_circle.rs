@@ -0,0 +1,137 @@
+//! Minimum enclosing circle.
+
+use super::point::Point;
+use crate::utils::random::XorShift64;
+
+const EPS: f64 = 1e-9;
+
+#[allow(clippy::many_single_char_names)]
+fn circumcircle(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> (Point<f64>, f64) {
+    let (u, v) = (b - a, c - a);
+    let d = 2.0 * u.cross(&v);
+
+    if d.abs() < EPS {
+        // collinear; the farthest pair spans the circle
+        let (p, q) = [(a, b), (a, c), (b, c)]
+            .iter()
+            .copied()
+            .max_by(|x, y| x.0.dist(&x.1).partial_cmp(&y.0.dist(&y.1)).unwrap())
+            .unwrap();
+        return ((p + q) * 0.5, p.dist(&q) / 2.0);
+    }
+
+    let (nu, nv) = (u.norm_sq(), v.norm_sq());
+    let center = a + Point::new(v.y * nu - u.y * nv, u.x * nv - v.x * nu) * (1.0 / d);
+    (center, center.dist(&a))
+}
+
+/// Computes the smallest circle containing all the points, using Welzl's algorithm.
+/// Returns the center and the radius.
+/// The points are visited in a pseudo-random order fixed by a seed.
+/// Consumes O(n) expected time.
+///
+/// # Panics
+/// Panics if `points` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::geometry::{min_enclosing_circle::min_enclosing_circle, point::Point};
+///
+/// let points = [Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 0.5)];
+/// let (center, r) = min_enclosing_circle(&points);
+///
+/// assert!(center.dist(&Point::new(1.0, 0.0)) < 1e-9);
+/// assert!((r - 1.0).abs() < 1e-9);
+/// ```
+pub fn min_enclosing_circle(points: &[Point<f64>]) -> (Point<f64>, f64) {
+    assert!(!points.is_empty(), "There are no points");
+
+    let mut ps = points.to_vec();
+    XorShift64::new(0x2545_F491_4F6C_DD1D).shuffle(&mut ps);
+
+    let outside = |c: &(Point<f64>, f64), p: &Point<f64>| c.0.dist(p) > c.1 + EPS;
+
+    let mut circle = (ps[0], 0.0);
+    for i in 1..ps.len() {
+        if !outside(&circle, &ps[i]) {
+            continue;
+        }
+        circle = (ps[i], 0.0);
+        for j in 0..i {
+            if !outside(&circle, &ps[j]) {
+                continue;
+            }
+            circle = ((ps[i] + ps[j]) * 0.5, ps[i].dist(&ps[j]) / 2.0);
+            for k in 0..j {
+                if outside(&circle, &ps[k]) {
+                    circle = circumcircle(ps[i], ps[j], ps[k]);
+                }
+            }
+        }
+    }
+
+    circle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_circle(expected: (Point<f64>, f64), actual: (Point<f64>, f64)) {
+        assert!(expected.0.dist(&actual.0) < 1e-9, "{:?}", actual);
+        assert!((expected.1 - actual.1).abs() < 1e-9, "{:?}", actual);
+    }
+
+    #[test]
+    fn single() {
+        let p = Point::new(3.0, -1.0);
+        assert_circle((p, 0.0), min_enclosing_circle(&[p]));
+        assert_circle((p, 0.0), min_enclosing_circle(&[p, p, p]));
+    }
+
+    #[test]
+    fn diameter() {
+        let points = [Point::new(-1.0, 2.0), Point::new(3.0, 5.0)];
+        assert_circle((Point::new(1.0, 3.5), 2.5), min_enclosing_circle(&points));
+
+        // the middle point does not matter
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(4.0, 0.0),
+        ];
+        assert_circle((Point::new(2.0, 0.0), 2.0), min_enclosing_circle(&points));
+    }
+
+    #[test]
+    fn equilateral() {
+        let h = 3f64.sqrt();
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, h),
+        ];
+        assert_circle(
+            (Point::new(1.0, h / 3.0), 2.0 / h),
+            min_enclosing_circle(&points),
+        );
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = || rng.next_range(0, 2001) as f64 / 10.0 - 100.0;
+
+        let points: Vec<Point<f64>> = (0..100).map(|_| Point::new(rand(), rand())).collect();
+        let (center, r) = min_enclosing_circle(&points);
+
+        assert!(points.iter().all(|p| center.dist(p) <= r + 1e-9));
+        // at least two points lie on the boundary of the minimum circle
+        let on_boundary = points
+            .iter()
+            .filter(|p| (center.dist(p) - r).abs() < 1e-9)
+            .count();
+        assert!(on_boundary >= 2);
+    }
+}
@@ -0,0 +1,170 @@
+//! Two-dimensional point.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Point, or vector, on a plane.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::geometry::point::Point;
+///
+/// let a = Point::new(1, 2);
+/// let b = Point::new(3, 4);
+///
+/// assert_eq!(Point::new(4, 6), a + b);
+/// assert_eq!(-2, a.cross(&b));
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    /// Constructs a new point.
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Point<T> {
+    /// Returns the dot product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::geometry::point::Point;
+    ///
+    /// assert_eq!(11, Point::new(1, 2).dot(&Point::new(3, 4)));
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the cross product.
+    /// It is positive if `other` is counterclockwise from self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::geometry::point::Point;
+    ///
+    /// assert_eq!(1, Point::new(1, 0).cross(&Point::new(0, 1)));
+    /// ```
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared norm.
+    pub fn norm_sq(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl Point<f64> {
+    /// Returns the Euclidean norm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::geometry::point::Point;
+    ///
+    /// assert_eq!(5.0, Point::new(3.0, 4.0).norm());
+    /// ```
+    pub fn norm(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns the Euclidean distance.
+    pub fn dist(&self, other: &Self) -> f64 {
+        (*self - *other).norm()
+    }
+
+    /// Returns the argument in the range [-π, π].
+    pub fn angle(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+}
+
+//
+// Ops
+//
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ops() {
+        let a = Point::new(1, -2);
+        let b = Point::new(4, 3);
+
+        assert_eq!(Point::new(5, 1), a + b);
+        assert_eq!(Point::new(-3, -5), a - b);
+        assert_eq!(Point::new(-1, 2), -a);
+        assert_eq!(Point::new(3, -6), a * 3);
+    }
+
+    #[test]
+    fn orientation() {
+        let o = Point::new(0, 0);
+        let a = Point::new(2, 1);
+
+        assert!(a.cross(&Point::new(1, 3)) > 0);
+        assert!(a.cross(&Point::new(3, -1)) < 0);
+        assert_eq!(0, a.cross(&(a * -2)));
+        assert_eq!(0, o.cross(&a));
+    }
+
+    #[test]
+    fn dot() {
+        let a = Point::new(3, 4);
+
+        assert_eq!(0, a.dot(&Point::new(-4, 3)));
+        assert_eq!(25, a.norm_sq());
+        assert_eq!(-25, a.dot(&-a));
+    }
+
+    #[test]
+    fn dist() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(4.0, 5.0);
+
+        assert!((a.dist(&b) - 5.0).abs() < 1e-9);
+        assert!((b.dist(&a) - 5.0).abs() < 1e-9);
+        assert!((Point::new(0.0, 2.0).angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+        assert!((Point::new(-1.0, 0.0).angle() - std::f64::consts::PI).abs() < 1e-9);
+    }
+}
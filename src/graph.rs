@@ -0,0 +1,172 @@
+//! Graph algorithms.
+
+use crate::utils::scan::Scanner;
+use std::io::Read;
+use std::str::FromStr;
+
+pub mod bfs;
+pub mod biconnected;
+pub mod bridges;
+pub mod centroid;
+pub mod dijkstra;
+pub mod dynamic_connectivity;
+pub mod euler_path;
+pub mod euler_tour;
+pub mod floyd_warshall;
+pub mod functional;
+pub mod matching;
+pub mod mcmf;
+pub mod mst;
+pub mod rerooting;
+pub mod scc;
+pub mod toposort;
+pub mod tree;
+pub mod two_sat;
+
+/// Directed graph stored as adjacency lists of `(head, weight)`.
+/// Use `W = ()` for unweighted graphs.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::Graph;
+///
+/// let mut g = Graph::new(3);
+/// g.add_undirected_edge(0, 1, 1);
+/// g.add_edge(1, 2, 5);
+///
+/// assert_eq!(&[(1, 1)], g.neighbors(0));
+/// assert_eq!(&[(0, 1), (2, 5)], g.neighbors(1));
+/// assert!(g.neighbors(2).is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Graph<W> {
+    adj: Vec<Vec<(usize, W)>>,
+}
+
+impl<W: Copy> Graph<W> {
+    /// Constructs a new graph with n vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            adj: vec![vec![]; n],
+        }
+    }
+
+    /// Reads m edges as lines of `u v w` with 1-indexed vertices.
+    /// Adds each edge in both directions unless `directed` is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::graph::Graph;
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// 3 2
+    /// 1 2 10
+    /// 2 3 20
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    /// let (n, m) = (sc.read(), sc.read());
+    ///
+    /// let g = Graph::<i64>::from_scanner(&mut sc, n, m, false);
+    /// assert_eq!(&[(0, 10), (2, 20)], g.neighbors(1));
+    /// ```
+    pub fn from_scanner<R: Read>(sc: &mut Scanner<R>, n: usize, m: usize, directed: bool) -> Self
+    where
+        W: FromStr,
+    {
+        let mut g = Self::new(n);
+        for _ in 0..m {
+            let (from, to): (usize, usize) = (sc.read(), sc.read());
+            let weight = sc.read();
+            if directed {
+                g.add_edge(from - 1, to - 1, weight);
+            } else {
+                g.add_undirected_edge(from - 1, to - 1, weight);
+            }
+        }
+        g
+    }
+
+    /// Returns the number of vertices.
+    pub fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// Returns true if the graph has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.adj.is_empty()
+    }
+
+    /// Adds a directed edge from u to v with weight w.
+    pub fn add_edge(&mut self, u: usize, v: usize, w: W) {
+        assert!(v < self.adj.len());
+        self.adj[u].push((v, w));
+    }
+
+    /// Adds an undirected edge between u and v with weight w.
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, w: W) {
+        self.add_edge(u, v, w);
+        self.add_edge(v, u, w);
+    }
+
+    /// Returns the edges from u as pairs of the head and the weight, in insertion order.
+    pub fn neighbors(&self, u: usize) -> &[(usize, W)] {
+        &self.adj[u]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undirected() {
+        let mut g = Graph::new(4);
+        g.add_undirected_edge(0, 1, ());
+        g.add_undirected_edge(1, 2, ());
+        g.add_undirected_edge(2, 0, ());
+
+        assert_eq!(4, g.len());
+        assert_eq!(&[(1, ()), (2, ())], g.neighbors(0));
+        assert_eq!(&[(0, ()), (2, ())], g.neighbors(1));
+        assert_eq!(&[(1, ()), (0, ())], g.neighbors(2));
+        assert!(g.neighbors(3).is_empty());
+    }
+
+    #[test]
+    fn directed() {
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, -4);
+        g.add_edge(1, 1, 7);
+
+        assert_eq!(&[(1, 1)], g.neighbors(0));
+        assert_eq!(&[(2, -4), (1, 7)], g.neighbors(1));
+        assert!(g.neighbors(2).is_empty());
+    }
+
+    #[test]
+    fn mixed() {
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1, 2.5);
+        g.add_undirected_edge(1, 2, 0.5);
+        g.add_edge(2, 0, 1.0);
+
+        assert_eq!(&[(1, 2.5)], g.neighbors(0));
+        assert_eq!(&[(2, 0.5)], g.neighbors(1));
+        assert_eq!(&[(1, 0.5), (0, 1.0)], g.neighbors(2));
+    }
+
+    #[test]
+    fn scan_directed() {
+        let input = "1 2 3\n3 1 4\n2 2 5\n";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let g = Graph::<u32>::from_scanner(&mut sc, 3, 3, true);
+        assert_eq!(&[(1, 3)], g.neighbors(0));
+        assert_eq!(&[(1, 5)], g.neighbors(1));
+        assert_eq!(&[(0, 4)], g.neighbors(2));
+    }
+}
@@ -0,0 +1,209 @@
+//! Breadth-first search.
+
+use super::Graph;
+use std::collections::VecDeque;
+
+/// Computes the numbers of edges on the shortest paths from `source`, using BFS.
+/// Returns None for the unreachable vertices.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{bfs::bfs, Graph};
+///
+/// let mut g = Graph::new(4);
+/// g.add_undirected_edge(0, 1, ());
+/// g.add_undirected_edge(1, 2, ());
+///
+/// assert_eq!(vec![Some(1), Some(0), Some(1), None], bfs(&g, 1));
+/// ```
+pub fn bfs(graph: &Graph<()>, source: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; graph.len()];
+    let mut queue = VecDeque::new();
+    dist[source] = Some(0);
+    queue.push_back(source);
+
+    while let Some(v) = queue.pop_front() {
+        let d = dist[v].unwrap();
+        for &(u, ()) in graph.neighbors(v) {
+            if dist[u].is_none() {
+                dist[u] = Some(d + 1);
+                queue.push_back(u);
+            }
+        }
+    }
+
+    dist
+}
+
+/// Computes the shortest distances from `source` in a graph with weights 0 or 1, using 0-1 BFS.
+/// Returns None for the unreachable vertices.
+/// Consumes O(n + m) time.
+///
+/// # Panics
+/// Panics if a reachable edge has a weight other than 0 or 1.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{bfs::bfs01, Graph};
+///
+/// let mut g = Graph::new(3);
+/// g.add_edge(0, 1, 1u8);
+/// g.add_edge(0, 2, 1);
+/// g.add_edge(2, 1, 0);
+///
+/// assert_eq!(vec![Some(0), Some(1), Some(1)], bfs01(&g, 0));
+/// ```
+pub fn bfs01<W: Copy + Into<u64>>(graph: &Graph<W>, source: usize) -> Vec<Option<u64>> {
+    let mut dist: Vec<Option<u64>> = vec![None; graph.len()];
+    let mut deque = VecDeque::new();
+    dist[source] = Some(0);
+    deque.push_back((0, source));
+
+    while let Some((d, v)) = deque.pop_front() {
+        if dist[v] != Some(d) {
+            continue;
+        }
+        for &(u, w) in graph.neighbors(v) {
+            let w = w.into();
+            assert!(w <= 1, "The weights are required to be 0 or 1");
+            let nd = d + w;
+            if dist[u].map_or(true, |du| nd < du) {
+                dist[u] = Some(nd);
+                if w == 0 {
+                    deque.push_front((nd, u));
+                } else {
+                    deque.push_back((nd, u));
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::dijkstra::dijkstra;
+    use crate::utils::random::XorShift64;
+
+    // Builds the graph of the '.' cells of a grid, adjacent in four directions.
+    fn grid_graph(grid: &[&[u8]]) -> Graph<()> {
+        let (h, w) = (grid.len(), grid[0].len());
+        let mut g = Graph::new(h * w);
+        for i in 0..h {
+            for j in 0..w {
+                if grid[i][j] != b'.' {
+                    continue;
+                }
+                if i + 1 < h && grid[i + 1][j] == b'.' {
+                    g.add_undirected_edge(i * w + j, (i + 1) * w + j, ());
+                }
+                if j + 1 < w && grid[i][j + 1] == b'.' {
+                    g.add_undirected_edge(i * w + j, i * w + j + 1, ());
+                }
+            }
+        }
+        g
+    }
+
+    #[test]
+    fn grid() {
+        let grid: [&[u8]; 4] = [b"..#.", b"#...", b"..#.", b".##."];
+        let dist = bfs(&grid_graph(&grid), 0);
+
+        // (0,0) -> (0,1) -> (1,1) -> (1,2) -> (1,3) -> (2,3) -> (3,3)
+        assert_eq!(Some(6), dist[3 * 4 + 3]);
+        // (1,1) -> (2,1) -> (2,0) -> (3,0)
+        assert_eq!(Some(5), dist[3 * 4]);
+        // (1,3) -> (0,3)
+        assert_eq!(Some(5), dist[3]);
+        assert_eq!(None, dist[2]);
+        assert_eq!(None, dist[4]);
+        assert_eq!(Some(0), dist[0]);
+    }
+
+    #[test]
+    fn grid_walled_off() {
+        let grid: [&[u8]; 3] = [b".#.", b"##.", b"..."];
+        let dist = bfs(&grid_graph(&grid), 8);
+        assert_eq!(
+            vec![
+                None,
+                None,
+                Some(2),
+                None,
+                None,
+                Some(1),
+                Some(2),
+                Some(1),
+                Some(0)
+            ],
+            dist
+        );
+    }
+
+    #[test]
+    fn zero_one() {
+        // moving right is free and moving down costs 1 on a 3x3 grid
+        let mut g = Graph::new(9);
+        for i in 0..3 {
+            for j in 0..3 {
+                if j + 1 < 3 {
+                    g.add_edge(i * 3 + j, i * 3 + j + 1, 0u32);
+                }
+                if i + 1 < 3 {
+                    g.add_edge(i * 3 + j, (i + 1) * 3 + j, 1);
+                }
+            }
+        }
+        let dist = bfs01(&g, 0);
+        let expected: Vec<_> = (0..9).map(|v| Some(v / 3)).collect();
+        assert_eq!(expected, dist);
+        assert_eq!(
+            vec![
+                None,
+                None,
+                Some(0),
+                None,
+                None,
+                Some(1),
+                None,
+                None,
+                Some(2)
+            ],
+            bfs01(&g, 2)
+        );
+    }
+
+    #[test]
+    fn zero_one_random() {
+        let mut rng = XorShift64::new(524);
+        for _ in 0..100 {
+            let n = rng.next_range(1, 10) as usize;
+            let mut g01 = Graph::new(n);
+            let mut g = Graph::new(n);
+            for _ in 0..rng.next_range(0, 25) {
+                let u = rng.next_range(0, n as u64) as usize;
+                let v = rng.next_range(0, n as u64) as usize;
+                let w = rng.next_range(0, 2);
+                g01.add_edge(u, v, w);
+                g.add_edge(u, v, w);
+            }
+            for s in 0..n {
+                assert_eq!(dijkstra(&g, s), bfs01(&g01, s));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_one_heavy_edge() {
+        let mut g = Graph::new(2);
+        g.add_edge(0, 1, 2u64);
+        bfs01(&g, 0);
+    }
+}
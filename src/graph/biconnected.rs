@@ -0,0 +1,282 @@
+//! Biconnected components and the block-cut tree.
+
+const NONE: usize = std::usize::MAX;
+
+/// Decomposes an undirected graph into its biconnected components (blocks),
+/// using Tarjan's low-link.
+/// `adj` contains each edge in both directions.
+/// Returns the vertex sets of the blocks, each sorted.
+/// Every edge belongs to exactly one block, an isolated vertex forms a block by itself,
+/// and the articulation points are exactly the vertices in two or more blocks.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::biconnected::biconnected_components;
+///
+/// // bowtie: triangles 0-1-2 and 2-3-4 sharing the vertex 2
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3, 4], vec![2, 4], vec![2, 3]];
+///
+/// let mut bccs = biconnected_components(5, &adj);
+/// bccs.sort();
+/// assert_eq!(vec![vec![0, 1, 2], vec![2, 3, 4]], bccs);
+/// ```
+pub fn biconnected_components(n: usize, adj: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut ord = vec![NONE; n];
+    let mut low = vec![NONE; n];
+    let mut count = 0;
+    let mut bccs = vec![];
+
+    // vertices visited but not yet assigned to a block below their parents
+    let mut stack = vec![];
+
+    for s in 0..n {
+        if ord[s] != NONE {
+            continue;
+        }
+        if adj[s].is_empty() {
+            bccs.push(vec![s]);
+            continue;
+        }
+
+        // (vertex, parent, index of the next edge)
+        let mut dfs = vec![(s, NONE, 0)];
+        ord[s] = count;
+        low[s] = count;
+        count += 1;
+
+        while let Some(&mut (v, p, ref mut e)) = dfs.last_mut() {
+            if let Some(&u) = adj[v].get(*e) {
+                *e += 1;
+                if ord[u] == NONE {
+                    ord[u] = count;
+                    low[u] = count;
+                    count += 1;
+                    stack.push(u);
+                    dfs.push((u, v, 0));
+                } else {
+                    low[v] = low[v].min(ord[u]);
+                }
+                continue;
+            }
+
+            dfs.pop();
+            if p == NONE {
+                continue;
+            }
+            low[p] = low[p].min(low[v]);
+
+            // p separates the subtree of v from the rest
+            if low[v] >= ord[p] {
+                let mut bcc = vec![p];
+                while let Some(w) = stack.pop() {
+                    bcc.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                bcc.sort();
+                bccs.push(bcc);
+            }
+        }
+    }
+
+    bccs
+}
+
+/// Builds the block-cut tree from the result of [`biconnected_components`].
+/// The nodes `0..n` are the vertices and the nodes `n..n + bccs.len()` are the blocks,
+/// and each vertex is adjacent to the blocks containing it.
+/// The result is a forest, and a tree if the graph is connected.
+/// The articulation points are the vertices with two or more neighbors.
+/// Consumes O(n + Σ|bccs[i]|) time.
+///
+/// [`biconnected_components`]: fn.biconnected_components.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::biconnected::{biconnected_components, block_cut_tree};
+///
+/// // bowtie: triangles 0-1-2 and 2-3-4 sharing the vertex 2
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3, 4], vec![2, 4], vec![2, 3]];
+/// let bccs = biconnected_components(5, &adj);
+///
+/// let tree = block_cut_tree(5, &bccs);
+/// assert_eq!(7, tree.len());
+/// assert_eq!(2, tree[2].len());
+/// assert!((0..5).filter(|&v| v != 2).all(|v| tree[v].len() == 1));
+/// ```
+pub fn block_cut_tree(n: usize, bccs: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut tree = vec![vec![]; n + bccs.len()];
+    for (i, bcc) in bccs.iter().enumerate() {
+        for &v in bcc {
+            tree[v].push(n + i);
+            tree[n + i].push(v);
+        }
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::bridges::{find_articulation_points, find_bridges};
+    use crate::utils::random::XorShift64;
+
+    fn to_adj(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut adj = vec![vec![]; n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        adj
+    }
+
+    // Returns true if the vertices of `vs` are connected by the edges within them.
+    fn connected_within(vs: &[usize], edges: &[(usize, usize)]) -> bool {
+        let mut reached = vec![vs[0]];
+        let mut i = 0;
+        while i < reached.len() {
+            let v = reached[i];
+            for &(a, b) in edges {
+                for &(x, y) in &[(a, b), (b, a)] {
+                    if x == v && vs.contains(&y) && !reached.contains(&y) {
+                        reached.push(y);
+                    }
+                }
+            }
+            i += 1;
+        }
+        reached.len() == vs.len()
+    }
+
+    fn check(n: usize, edges: &[(usize, usize)]) {
+        let adj = to_adj(n, edges);
+        let bccs = biconnected_components(n, &adj);
+
+        // every edge lies in exactly one block
+        for &(u, v) in edges {
+            let owners = bccs
+                .iter()
+                .filter(|b| b.contains(&u) && b.contains(&v))
+                .count();
+            assert_eq!(1, owners, "{:?} {:?}", edges, bccs);
+        }
+
+        // a block stays connected after removing any one of its vertices
+        for bcc in &bccs {
+            assert!(connected_within(bcc, edges));
+            if bcc.len() > 2 {
+                for &x in bcc {
+                    let rest: Vec<_> = bcc.iter().copied().filter(|&v| v != x).collect();
+                    assert!(connected_within(&rest, edges), "{:?} {:?}", edges, bccs);
+                }
+            }
+        }
+
+        // the vertices in two or more blocks are the articulation points
+        let arts: Vec<_> = (0..n)
+            .filter(|&v| bccs.iter().filter(|b| b.contains(&v)).count() >= 2)
+            .collect();
+        assert_eq!(find_articulation_points(n, &adj), arts);
+
+        // the block-cut tree is a forest with a component per component of the graph
+        let tree = block_cut_tree(n, &bccs);
+        let tree_edges: usize = tree.iter().map(Vec::len).sum::<usize>() / 2;
+        let mut uf: Vec<usize> = (0..n).collect();
+        fn find(uf: &mut Vec<usize>, v: usize) -> usize {
+            if uf[v] != v {
+                uf[v] = find(uf, uf[v]);
+            }
+            uf[v]
+        }
+        for &(u, v) in edges {
+            let (u, v) = (find(&mut uf, u), find(&mut uf, v));
+            uf[u] = v;
+        }
+        let components = (0..n).filter(|&v| find(&mut uf, v) == v).count();
+        assert_eq!(tree.len() - components, tree_edges);
+    }
+
+    #[test]
+    fn bowtie() {
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)];
+        let adj = to_adj(5, &edges);
+
+        let mut bccs = biconnected_components(5, &adj);
+        bccs.sort();
+        assert_eq!(vec![vec![0, 1, 2], vec![2, 3, 4]], bccs);
+        check(5, &edges);
+    }
+
+    #[test]
+    fn tree() {
+        // every edge of a tree is a bridge and a block by itself
+        let edges = [(0, 1), (1, 2), (1, 3), (3, 4), (3, 5)];
+        let adj = to_adj(6, &edges);
+
+        let mut bccs = biconnected_components(6, &adj);
+        bccs.sort();
+        assert_eq!(find_bridges(6, &adj).len(), bccs.len());
+        assert_eq!(
+            edges.iter().map(|&(u, v)| vec![u, v]).collect::<Vec<_>>(),
+            bccs
+        );
+        check(6, &edges);
+    }
+
+    #[test]
+    fn mixed() {
+        // two cycles 0-1-2 and 3-4-5 joined by the bridge 2-3,
+        // a pendant 6 on 5, a double edge 7=0, and an isolated vertex 8
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+            (0, 7),
+            (7, 0),
+        ];
+        let adj = to_adj(9, &edges);
+
+        let mut bccs = biconnected_components(9, &adj);
+        bccs.sort();
+        assert_eq!(
+            vec![
+                vec![0, 1, 2],
+                vec![0, 7],
+                vec![2, 3],
+                vec![3, 4, 5],
+                vec![5, 6],
+                vec![8]
+            ],
+            bccs
+        );
+        check(9, &edges);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(5);
+        for _ in 0..200 {
+            let n = rng.next_range(1, 9) as usize;
+            let m = rng.next_range(0, 14);
+            let edges: Vec<_> = (0..m)
+                .map(|_| {
+                    (
+                        rng.next_range(0, n as u64) as usize,
+                        rng.next_range(0, n as u64) as usize,
+                    )
+                })
+                .filter(|&(u, v)| u != v)
+                .collect();
+            check(n, &edges);
+        }
+    }
+}
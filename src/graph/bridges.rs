@@ -0,0 +1,230 @@
+//! Bridges and articulation points.
+
+const NONE: usize = std::usize::MAX;
+
+// Computes the DFS order and the low-links, calling `on_tree_edge(p, v)` when v is finished.
+fn lowlink<F: FnMut(usize, usize, &[usize], &[usize])>(
+    n: usize,
+    adj: &[Vec<usize>],
+    mut on_tree_edge: F,
+) -> Vec<usize> {
+    let mut ord = vec![NONE; n];
+    let mut low = vec![NONE; n];
+    let mut count = 0;
+    let mut roots = vec![];
+
+    for s in 0..n {
+        if ord[s] != NONE {
+            continue;
+        }
+        roots.push(s);
+
+        // (vertex, parent, index of the next edge, whether the edge to the parent is skipped)
+        let mut dfs = vec![(s, NONE, 0, false)];
+        ord[s] = count;
+        low[s] = count;
+        count += 1;
+
+        while let Some(&mut (v, p, ref mut e, ref mut skipped)) = dfs.last_mut() {
+            if let Some(&u) = adj[v].get(*e) {
+                *e += 1;
+                if u == p && !*skipped {
+                    // the tree edge itself, but not its multi-edges
+                    *skipped = true;
+                } else if ord[u] == NONE {
+                    ord[u] = count;
+                    low[u] = count;
+                    count += 1;
+                    dfs.push((u, v, 0, false));
+                } else {
+                    low[v] = low[v].min(ord[u]);
+                }
+                continue;
+            }
+
+            dfs.pop();
+            if p != NONE {
+                low[p] = low[p].min(low[v]);
+                on_tree_edge(p, v, &ord, &low);
+            }
+        }
+    }
+
+    roots
+}
+
+/// Finds the bridges of an undirected graph, using Tarjan's low-link.
+/// `adj` contains each edge in both directions.
+/// Returns the bridges as `(u, v)` with `u < v`, sorted.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::bridges::find_bridges;
+///
+/// // triangle 0-1-2 with a tail 2-3
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2]];
+///
+/// assert_eq!(vec![(2, 3)], find_bridges(4, &adj));
+/// ```
+pub fn find_bridges(n: usize, adj: &[Vec<usize>]) -> Vec<(usize, usize)> {
+    let mut bridges = vec![];
+    lowlink(n, adj, |p, v, ord, low| {
+        if low[v] > ord[p] {
+            bridges.push((p.min(v), p.max(v)));
+        }
+    });
+
+    bridges.sort();
+    bridges
+}
+
+/// Finds the articulation points of an undirected graph, using Tarjan's low-link.
+/// `adj` contains each edge in both directions.
+/// Returns the articulation points sorted.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::bridges::find_articulation_points;
+///
+/// // triangle 0-1-2 with a tail 2-3
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2]];
+///
+/// assert_eq!(vec![2], find_articulation_points(4, &adj));
+/// ```
+pub fn find_articulation_points(n: usize, adj: &[Vec<usize>]) -> Vec<usize> {
+    let mut is_art = vec![false; n];
+    let mut children = vec![0; n];
+
+    let roots = lowlink(n, adj, |p, v, ord, low| {
+        children[p] += 1;
+        if low[v] >= ord[p] {
+            is_art[p] = true;
+        }
+    });
+
+    // a root is an articulation point iff it has two or more children
+    for r in roots {
+        is_art[r] = children[r] >= 2;
+    }
+
+    (0..n).filter(|&v| is_art[v]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn to_adj(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut adj = vec![vec![]; n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        adj
+    }
+
+    fn components(n: usize, edges: &[(usize, usize)]) -> usize {
+        let mut uf: Vec<usize> = (0..n).collect();
+        fn find(uf: &mut Vec<usize>, v: usize) -> usize {
+            if uf[v] != v {
+                uf[v] = find(uf, uf[v]);
+            }
+            uf[v]
+        }
+        for &(u, v) in edges {
+            let (u, v) = (find(&mut uf, u), find(&mut uf, v));
+            uf[u] = v;
+        }
+        (0..n).filter(|&v| find(&mut uf, v) == v).count()
+    }
+
+    #[test]
+    fn tree() {
+        let edges = [(0, 1), (1, 2), (1, 3), (3, 4), (3, 5)];
+        let adj = to_adj(6, &edges);
+
+        assert_eq!(edges.to_vec(), find_bridges(6, &adj));
+        assert_eq!(vec![1, 3], find_articulation_points(6, &adj));
+    }
+
+    #[test]
+    fn complete() {
+        let n = 5;
+        let edges: Vec<_> = (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .collect();
+        let adj = to_adj(n, &edges);
+
+        assert!(find_bridges(n, &adj).is_empty());
+        assert!(find_articulation_points(n, &adj).is_empty());
+    }
+
+    #[test]
+    fn mixed() {
+        // two cycles 0-1-2 and 3-4-5 joined by the bridge 2-3,
+        // a pendant 6 on 5, a double edge 7=0, and an isolated vertex 8
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+            (0, 7),
+            (7, 0),
+        ];
+        let adj = to_adj(9, &edges);
+
+        assert_eq!(vec![(2, 3), (5, 6)], find_bridges(9, &adj));
+        assert_eq!(vec![0, 2, 3, 5], find_articulation_points(9, &adj));
+    }
+
+    #[test]
+    fn brute_force() {
+        let mut rng = XorShift64::new(3);
+        let mut rand = |m: u64| rng.next_range(0, m) as usize;
+
+        for _ in 0..100 {
+            let n = rand(8) + 1;
+            let edges: Vec<_> = (0..rand(12))
+                .map(|_| (rand(n as u64), rand(n as u64)))
+                .filter(|&(u, v)| u != v)
+                .collect();
+            let adj = to_adj(n, &edges);
+            let base = components(n, &edges);
+
+            let mut bridges: Vec<_> = (0..edges.len())
+                .filter(|&i| {
+                    let rest: Vec<_> = (0..edges.len())
+                        .filter(|&j| j != i)
+                        .map(|j| edges[j])
+                        .collect();
+                    components(n, &rest) > base
+                })
+                .map(|i| (edges[i].0.min(edges[i].1), edges[i].0.max(edges[i].1)))
+                .collect();
+            bridges.sort();
+            assert_eq!(bridges, find_bridges(n, &adj));
+
+            let arts: Vec<_> = (0..n)
+                .filter(|&v| {
+                    let rest: Vec<_> = edges
+                        .iter()
+                        .copied()
+                        .filter(|&(a, b)| a != v && b != v)
+                        .collect();
+                    // removing v leaves one less vertex
+                    components(n, &rest) - 1 > base
+                })
+                .collect();
+            assert_eq!(arts, find_articulation_points(n, &adj));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Centroid decomposition.
+
+use std::collections::VecDeque;
+
+/// Centroid decomposition of a tree.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::centroid::CentroidDecomposition;
+///
+/// // 0 - 1 - 2 - 3 - 4
+/// let tree = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+/// let cd = CentroidDecomposition::new(&tree);
+///
+/// assert_eq!(2, cd.root());
+///
+/// let mut path = vec![];
+/// cd.query_path(0, |c, d| path.push((c, d)));
+/// assert_eq!(vec![(0, 0), (1, 1), (2, 2)], path);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CentroidDecomposition {
+    parent: Vec<Option<usize>>,
+    // the centroid ancestors with distances, from the root of the decomposition
+    ancestors: Vec<Vec<(usize, usize)>>,
+}
+
+impl CentroidDecomposition {
+    /// Decomposes the tree given as adjacency lists.
+    /// Consumes O(n log n) time.
+    ///
+    /// # Panics
+    /// Panics if the tree is empty.
+    pub fn new(tree: &[Vec<usize>]) -> Self {
+        let n = tree.len();
+        assert!(n > 0);
+
+        let mut removed = vec![false; n];
+        let mut size = vec![0; n];
+        let mut bfs_parent = vec![None; n];
+        let mut parent = vec![None; n];
+        let mut ancestors = vec![vec![]; n];
+
+        // (a vertex of the component, the centroid of the parent component)
+        let mut components = vec![(0, None)];
+        while let Some((r, p)) = components.pop() {
+            let order = bfs(tree, &removed, r);
+            for &(v, q, _) in order.iter() {
+                size[v] = 1;
+                bfs_parent[v] = q;
+            }
+            for &(v, q, _) in order.iter().rev() {
+                if let Some(q) = q {
+                    size[q] += size[v];
+                }
+            }
+
+            let total = order.len();
+            let mut c = r;
+            while let Some(&u) = tree[c]
+                .iter()
+                .find(|&&u| !removed[u] && Some(u) != bfs_parent[c] && size[u] * 2 > total)
+            {
+                c = u;
+            }
+
+            parent[c] = p;
+            for (v, _, d) in bfs(tree, &removed, c) {
+                ancestors[v].push((c, d));
+            }
+
+            removed[c] = true;
+            for &u in tree[c].iter().filter(|&&u| !removed[u]) {
+                components.push((u, Some(c)));
+            }
+        }
+
+        Self { parent, ancestors }
+    }
+
+    /// Returns the root of the decomposition.
+    pub fn root(&self) -> usize {
+        self.parent.iter().position(Option::is_none).unwrap()
+    }
+
+    /// Returns the parent of the centroid v in the decomposition.
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+
+    /// Calls `f(c, d)` for each centroid ancestor c of v,
+    /// where d is the distance between v and c in the original tree.
+    /// The ancestors are visited from v itself up to the root.
+    /// Consumes O(log n) time besides `f`.
+    pub fn query_path<F: FnMut(usize, usize)>(&self, v: usize, mut f: F) {
+        self.ancestors[v].iter().rev().for_each(|&(c, d)| f(c, d));
+    }
+}
+
+// (vertex, parent, distance) in BFS order
+fn bfs(tree: &[Vec<usize>], removed: &[bool], r: usize) -> Vec<(usize, Option<usize>, usize)> {
+    let mut res = vec![(r, None, 0)];
+    let mut queue = VecDeque::new();
+    queue.push_back(0);
+
+    while let Some(i) = queue.pop_front() {
+        let (v, p, d) = res[i];
+        for &u in tree[v].iter() {
+            if !removed[u] && Some(u) != p {
+                queue.push_back(res.len());
+                res.push((u, Some(v), d + 1));
+            }
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_tree(n: usize, seed: u64) -> Vec<Vec<usize>> {
+        let mut rng = XorShift64::new(seed);
+        let mut tree = vec![vec![]; n];
+        for v in 1..n {
+            // biased towards long paths
+            let p = v - 1 - rng.next_range(0, v.min(3) as u64) as usize;
+            tree[v].push(p);
+            tree[p].push(v);
+        }
+        tree
+    }
+
+    fn distances(tree: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let removed = vec![false; tree.len()];
+        (0..tree.len())
+            .map(|s| {
+                let mut dist = vec![0; tree.len()];
+                bfs(tree, &removed, s)
+                    .into_iter()
+                    .for_each(|(v, _, d)| dist[v] = d);
+                dist
+            })
+            .collect()
+    }
+
+    #[test]
+    fn depth() {
+        for &n in &[1, 2, 7, 100, 1000] {
+            let cd = CentroidDecomposition::new(&random_tree(n, n as u64));
+
+            let mut max_depth = 0;
+            for v in 0..n {
+                let mut depth = 0;
+                cd.query_path(v, |_, _| depth += 1);
+                max_depth = max_depth.max(depth);
+            }
+            assert!(1 << (max_depth - 1) <= n);
+        }
+    }
+
+    #[test]
+    fn paths_overlap() {
+        let n = 60;
+        let tree = random_tree(n, 1);
+        let dist = distances(&tree);
+        let cd = CentroidDecomposition::new(&tree);
+
+        for u in 0..n {
+            let mut path_u = vec![];
+            cd.query_path(u, |c, d| path_u.push((c, d)));
+            assert_eq!((u, 0), path_u[0]);
+            assert_eq!(cd.root(), path_u.last().unwrap().0);
+
+            for v in 0..n {
+                let mut path_v = vec![];
+                cd.query_path(v, |c, d| path_v.push((c, d)));
+
+                // the lowest common centroid ancestor lies on the path between u and v
+                let &(c, du) = path_u
+                    .iter()
+                    .find(|&&(c, _)| path_v.iter().any(|&(w, _)| w == c))
+                    .unwrap();
+                let &(_, dv) = path_v.iter().find(|&&(w, _)| w == c).unwrap();
+                assert_eq!(dist[u][v], du + dv);
+                assert_eq!((dist[u][c], dist[v][c]), (du, dv));
+            }
+        }
+    }
+
+    #[test]
+    fn sum_of_distances() {
+        // maintains the sum of the distances to marked vertices
+        let n = 80;
+        let tree = random_tree(n, 2);
+        let dist = distances(&tree);
+        let cd = CentroidDecomposition::new(&tree);
+
+        let path = |v: usize| {
+            let mut path = vec![];
+            cd.query_path(v, |c, d| path.push((c, d)));
+            path
+        };
+
+        let mut cnt = vec![0; n];
+        let mut sum = vec![0; n];
+        // the sums of the distances to the parent centroid
+        let mut sum_up = vec![0; n];
+        let mut marked = vec![];
+
+        for v in (0..n).step_by(3) {
+            let p = path(v);
+            for (k, &(c, d)) in p.iter().enumerate() {
+                cnt[c] += 1;
+                sum[c] += d;
+                if let Some(&(_, d_up)) = p.get(k + 1) {
+                    sum_up[c] += d_up;
+                }
+            }
+            marked.push(v);
+
+            for (u, du) in dist.iter().enumerate() {
+                let p = path(u);
+                let mut res = 0;
+                for (k, &(c, d)) in p.iter().enumerate() {
+                    res += sum[c] + cnt[c] * d;
+                    if k > 0 {
+                        let (child, _) = p[k - 1];
+                        res -= sum_up[child] + cnt[child] * d;
+                    }
+                }
+
+                let naive: usize = marked.iter().map(|&w| du[w]).sum();
+                assert_eq!(naive, res);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Dijkstra's algorithm.
+
+use super::Graph;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Add;
+
+/// Computes the shortest distances from `source`, using Dijkstra's algorithm with a binary heap.
+/// The weights are required to be non-negative, that is, at least `W::default()`.
+/// Returns None for the unreachable vertices.
+/// Consumes O((n + m) log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{dijkstra::dijkstra, Graph};
+///
+/// let mut g = Graph::new(4);
+/// g.add_edge(0, 1, 4);
+/// g.add_edge(0, 2, 1);
+/// g.add_edge(2, 1, 2);
+///
+/// assert_eq!(vec![Some(0), Some(3), Some(1), None], dijkstra(&g, 0));
+/// ```
+pub fn dijkstra<W>(graph: &Graph<W>, source: usize) -> Vec<Option<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    search(graph, source, None)
+}
+
+/// Computes the shortest distance from `source` to `target`, using [`dijkstra`].
+/// Stops as soon as the target is settled.
+/// Returns None if the target is unreachable.
+///
+/// [`dijkstra`]: fn.dijkstra.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{dijkstra::dijkstra_to, Graph};
+///
+/// let mut g = Graph::new(3);
+/// g.add_undirected_edge(0, 1, 5u64);
+/// g.add_undirected_edge(1, 2, 7);
+///
+/// assert_eq!(Some(12), dijkstra_to(&g, 2, 0));
+/// ```
+pub fn dijkstra_to<W>(graph: &Graph<W>, source: usize, target: usize) -> Option<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    search(graph, source, Some(target))[target]
+}
+
+fn search<W>(graph: &Graph<W>, source: usize, target: Option<usize>) -> Vec<Option<W>>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let mut dist = vec![None; graph.len()];
+    let mut heap = BinaryHeap::new();
+    dist[source] = Some(W::default());
+    heap.push(Reverse((W::default(), source)));
+
+    while let Some(Reverse((d, v))) = heap.pop() {
+        if dist[v] != Some(d) {
+            continue;
+        }
+        if Some(v) == target {
+            break;
+        }
+        for &(u, w) in graph.neighbors(v) {
+            let nd = d + w;
+            if dist[u].map_or(true, |du| nd < du) {
+                dist[u] = Some(nd);
+                heap.push(Reverse((nd, u)));
+            }
+        }
+    }
+
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::floyd_warshall::{floyd_warshall, INF};
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn triangle() {
+        let mut g = Graph::new(3);
+        g.add_undirected_edge(0, 1, 1);
+        g.add_undirected_edge(1, 2, 2);
+        g.add_undirected_edge(0, 2, 5);
+
+        assert_eq!(vec![Some(0), Some(1), Some(3)], dijkstra(&g, 0));
+        assert_eq!(vec![Some(3), Some(2), Some(0)], dijkstra(&g, 2));
+        assert_eq!(Some(3), dijkstra_to(&g, 0, 2));
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut g = Graph::new(5);
+        g.add_edge(0, 1, 3u32);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 0, 1);
+
+        assert_eq!(vec![Some(0), Some(3), None, None, None], dijkstra(&g, 0));
+        assert_eq!(None, dijkstra_to(&g, 0, 4));
+        assert_eq!(Some(5), dijkstra_to(&g, 3, 1));
+        assert_eq!(Some(0), dijkstra_to(&g, 2, 2));
+    }
+
+    #[test]
+    fn zero_edges() {
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1, 0);
+        g.add_edge(1, 2, 0);
+        g.add_edge(2, 0, 0);
+
+        assert_eq!(vec![Some(0), Some(0), Some(0), None], dijkstra(&g, 1));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(523);
+        for _ in 0..50 {
+            let n = rng.next_range(1, 12) as usize;
+            let m = rng.next_range(0, 30);
+            let mut g = Graph::new(n);
+            let mut dist = vec![vec![INF; n]; n];
+            for (v, row) in dist.iter_mut().enumerate() {
+                row[v] = 0;
+            }
+            for _ in 0..m {
+                let u = rng.next_range(0, n as u64) as usize;
+                let v = rng.next_range(0, n as u64) as usize;
+                let w = rng.next_range(0, 20) as i64;
+                g.add_edge(u, v, w);
+                dist[u][v] = dist[u][v].min(w);
+            }
+            floyd_warshall(&mut dist);
+
+            for (s, row) in dist.iter().enumerate() {
+                let expected: Vec<_> = row
+                    .iter()
+                    .map(|&d| if d < INF { Some(d) } else { None })
+                    .collect();
+                assert_eq!(expected, dijkstra(&g, s));
+                for (t, &d) in expected.iter().enumerate() {
+                    assert_eq!(d, dijkstra_to(&g, s, t));
+                }
+            }
+        }
+    }
+}
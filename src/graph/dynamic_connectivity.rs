@@ -0,0 +1,229 @@
+//! Offline dynamic connectivity.
+
+#[derive(Debug, Clone)]
+struct RollbackDsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    history: Vec<Option<(usize, usize)>>,
+}
+
+impl RollbackDsu {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            history: vec![],
+        }
+    }
+
+    // no path compression, so that each union can be undone
+    fn find(&self, mut v: usize) -> usize {
+        while self.parent[v] != v {
+            v = self.parent[v];
+        }
+        v
+    }
+
+    fn unite(&mut self, u: usize, v: usize) {
+        let (mut u, mut v) = (self.find(u), self.find(v));
+        if u == v {
+            self.history.push(None);
+            return;
+        }
+        if self.size[u] < self.size[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        self.parent[v] = u;
+        self.size[u] += self.size[v];
+        self.history.push(Some((u, v)));
+    }
+
+    fn rollback(&mut self) {
+        if let Some((u, v)) = self.history.pop().unwrap() {
+            self.parent[v] = v;
+            self.size[u] -= self.size[v];
+        }
+    }
+}
+
+/// Offline dynamic connectivity, using a segment tree on the time axis
+/// and union-find with rollback.
+/// Each edge exists during a half-open interval of time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::dynamic_connectivity::DynamicConnectivity;
+///
+/// let mut dc = DynamicConnectivity::new(3);
+/// dc.add_event(0, 1, 0, 5);
+/// dc.add_event(1, 2, 2, 4);
+/// dc.add_query(0, 2, 1);
+/// dc.add_query(0, 2, 3);
+/// dc.add_query(0, 2, 4);
+///
+/// assert_eq!(vec![false, true, false], dc.run());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicConnectivity {
+    n: usize,
+    events: Vec<(usize, usize, usize, usize)>,
+    queries: Vec<(usize, usize, usize)>,
+}
+
+impl DynamicConnectivity {
+    /// Constructs a new instance with n vertices.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            events: vec![],
+            queries: vec![],
+        }
+    }
+
+    /// Adds an undirected edge between u and v existing at times in [time_start, time_end).
+    pub fn add_event(&mut self, u: usize, v: usize, time_start: usize, time_end: usize) {
+        assert!(u < self.n && v < self.n);
+        assert!(time_start <= time_end);
+
+        self.events.push((u, v, time_start, time_end));
+    }
+
+    /// Adds a query asking whether u and v are connected at the time.
+    pub fn add_query(&mut self, u: usize, v: usize, time: usize) {
+        assert!(u < self.n && v < self.n);
+
+        self.queries.push((u, v, time));
+    }
+
+    /// Answers the queries in the order they are added.
+    /// Consumes O((n + m log T) log n + q log n) time,
+    /// where m is the number of edges and T is the time span.
+    pub fn run(&self) -> Vec<bool> {
+        let span = self
+            .queries
+            .iter()
+            .map(|&(_, _, t)| t + 1)
+            .chain(self.events.iter().map(|&(_, _, _, e)| e))
+            .max()
+            .unwrap_or(0);
+        let size = span.next_power_of_two();
+
+        // the edges covering each node of the segment tree
+        let mut edges = vec![vec![]; 2 * size];
+        for &(u, v, s, e) in self.events.iter() {
+            let (mut l, mut r) = (s + size, e + size);
+            while l < r {
+                if l & 1 == 1 {
+                    edges[l].push((u, v));
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    edges[r].push((u, v));
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+        }
+
+        let mut at = vec![vec![]; size];
+        for (i, &(_, _, t)) in self.queries.iter().enumerate() {
+            at[t].push(i);
+        }
+
+        let mut res = vec![false; self.queries.len()];
+        let mut dsu = RollbackDsu::new(self.n);
+        self.dfs(1, size, &edges, &at, &mut dsu, &mut res);
+        res
+    }
+
+    fn dfs(
+        &self,
+        k: usize,
+        size: usize,
+        edges: &[Vec<(usize, usize)>],
+        at: &[Vec<usize>],
+        dsu: &mut RollbackDsu,
+        res: &mut [bool],
+    ) {
+        edges[k].iter().for_each(|&(u, v)| dsu.unite(u, v));
+
+        if k >= size {
+            for &i in at[k - size].iter() {
+                let (u, v, _) = self.queries[i];
+                res[i] = dsu.find(u) == dsu.find(v);
+            }
+        } else {
+            self.dfs(2 * k, size, edges, at, dsu, res);
+            self.dfs(2 * k + 1, size, edges, at, dsu, res);
+        }
+
+        edges[k].iter().for_each(|_| dsu.rollback());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn naive(
+        n: usize,
+        events: &[(usize, usize, usize, usize)],
+        u: usize,
+        v: usize,
+        t: usize,
+    ) -> bool {
+        let mut visited = vec![false; n];
+        let mut stack = vec![u];
+        visited[u] = true;
+        while let Some(x) = stack.pop() {
+            let alive = events.iter().filter(|&&(_, _, s, e)| s <= t && t < e);
+            for &(a, b, _, _) in alive {
+                for &(p, q) in &[(a, b), (b, a)] {
+                    if p == x && !visited[q] {
+                        visited[q] = true;
+                        stack.push(q);
+                    }
+                }
+            }
+        }
+        visited[v]
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = |m: usize| rng.next_range(0, m as u64) as usize;
+
+        for n in 1..=8 {
+            let mut dc = DynamicConnectivity::new(n);
+            let mut events = vec![];
+            for _ in 0..2 * n {
+                let (u, v) = (rand(n), rand(n));
+                let s = rand(20);
+                let e = s + rand(10);
+                dc.add_event(u, v, s, e);
+                events.push((u, v, s, e));
+            }
+
+            let mut expected = vec![];
+            for _ in 0..50 {
+                let (u, v, t) = (rand(n), rand(n), rand(32));
+                dc.add_query(u, v, t);
+                expected.push(naive(n, &events, u, v, t));
+            }
+
+            assert_eq!(expected, dc.run());
+        }
+    }
+
+    #[test]
+    fn no_queries() {
+        let mut dc = DynamicConnectivity::new(2);
+        dc.add_event(0, 1, 0, 3);
+        assert!(dc.run().is_empty());
+        assert!(DynamicConnectivity::new(0).run().is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+//! Eulerian circuits.
+
+/// Finds an Eulerian circuit of a directed graph, using Hierholzer's algorithm.
+/// The edges are consumed from `adj`.
+/// Returns the vertices of the circuit, starting and ending at the smallest vertex with an edge,
+/// or an empty vector if there are no edges,
+/// or None if the graph has no Eulerian circuit.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::euler_path::directed_euler_circuit;
+///
+/// let mut adj = vec![vec![1], vec![2, 0], vec![1]];
+/// assert_eq!(Some(vec![0, 1, 2, 1, 0]), directed_euler_circuit(3, &mut adj));
+///
+/// let mut adj = vec![vec![1], vec![2], vec![]];
+/// assert_eq!(None, directed_euler_circuit(3, &mut adj));
+/// ```
+pub fn directed_euler_circuit(n: usize, adj: &mut Vec<Vec<usize>>) -> Option<Vec<usize>> {
+    let mut balance = vec![0i64; n];
+    let mut m = 0;
+    for (v, edges) in adj.iter().enumerate() {
+        balance[v] += edges.len() as i64;
+        for &u in edges {
+            balance[u] -= 1;
+        }
+        m += edges.len();
+    }
+    if balance.iter().any(|&b| b != 0) {
+        return None;
+    }
+    let start = match (0..n).find(|&v| !adj[v].is_empty()) {
+        Some(v) => v,
+        None => return Some(vec![]),
+    };
+
+    // reversed edges keep the order of the circuit along the lists
+    adj.iter_mut().for_each(|edges| edges.reverse());
+    let mut circuit = Vec::with_capacity(m + 1);
+    let mut stack = vec![start];
+    while let Some(&v) = stack.last() {
+        match adj[v].pop() {
+            Some(u) => stack.push(u),
+            None => circuit.push(stack.pop().unwrap()),
+        }
+    }
+
+    // the edges unreachable from the start are left
+    if circuit.len() != m + 1 {
+        return None;
+    }
+    circuit.reverse();
+    Some(circuit)
+}
+
+/// Finds an Eulerian circuit of an undirected graph, using Hierholzer's algorithm.
+/// `adj` contains each edge as `(neighbor, edge_id)` in both directions,
+/// with the ids `0..m` and a self-loop twice in the same list.
+/// The edges are consumed from `adj`.
+/// Returns the vertices of the circuit, starting and ending at the smallest vertex with an edge,
+/// or an empty vector if there are no edges,
+/// or None if the graph has no Eulerian circuit.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::euler_path::undirected_euler_circuit;
+///
+/// // two triangles 0-1-2 and 0-3-4 sharing the vertex 0
+/// let mut adj = vec![
+///     vec![(1, 0), (2, 2), (3, 3), (4, 5)],
+///     vec![(0, 0), (2, 1)],
+///     vec![(1, 1), (0, 2)],
+///     vec![(0, 3), (4, 4)],
+///     vec![(3, 4), (0, 5)],
+/// ];
+/// assert_eq!(Some(vec![0, 1, 2, 0, 3, 4, 0]), undirected_euler_circuit(5, &mut adj));
+/// ```
+pub fn undirected_euler_circuit(
+    n: usize,
+    adj: &mut Vec<Vec<(usize, usize)>>,
+) -> Option<Vec<usize>> {
+    if adj.iter().any(|edges| edges.len() % 2 != 0) {
+        return None;
+    }
+    let m = adj.iter().map(Vec::len).sum::<usize>() / 2;
+    let start = match (0..n).find(|&v| !adj[v].is_empty()) {
+        Some(v) => v,
+        None => return Some(vec![]),
+    };
+
+    adj.iter_mut().for_each(|edges| edges.reverse());
+    let mut used = vec![false; m];
+    let mut circuit = Vec::with_capacity(m + 1);
+    let mut stack = vec![start];
+    while let Some(&v) = stack.last() {
+        match adj[v].pop() {
+            Some((_, id)) if used[id] => {}
+            Some((u, id)) => {
+                used[id] = true;
+                stack.push(u);
+            }
+            None => circuit.push(stack.pop().unwrap()),
+        }
+    }
+
+    if circuit.len() != m + 1 {
+        return None;
+    }
+    circuit.reverse();
+    Some(circuit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn to_undirected(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+        let mut adj = vec![vec![]; n];
+        for (id, &(u, v)) in edges.iter().enumerate() {
+            adj[u].push((v, id));
+            adj[v].push((u, id));
+        }
+        adj
+    }
+
+    fn complete(n: usize) -> Vec<(usize, usize)> {
+        (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .collect()
+    }
+
+    // Checks that the circuit uses every edge exactly once.
+    fn check_undirected(edges: &[(usize, usize)], circuit: &[usize]) {
+        assert_eq!(edges.len() + 1, circuit.len());
+        assert_eq!(circuit[0], circuit[edges.len()]);
+        let mut walked: Vec<_> = circuit
+            .windows(2)
+            .map(|w| (w[0].min(w[1]), w[0].max(w[1])))
+            .collect();
+        let mut expected: Vec<_> = edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        walked.sort();
+        expected.sort();
+        assert_eq!(expected, walked);
+    }
+
+    fn check_directed(edges: &[(usize, usize)], circuit: &[usize]) {
+        assert_eq!(edges.len() + 1, circuit.len());
+        assert_eq!(circuit[0], circuit[edges.len()]);
+        let mut walked: Vec<_> = circuit.windows(2).map(|w| (w[0], w[1])).collect();
+        let mut expected = edges.to_vec();
+        walked.sort();
+        expected.sort();
+        assert_eq!(expected, walked);
+    }
+
+    #[test]
+    fn complete_graphs() {
+        // every vertex of K4 has degree 3
+        let edges = complete(4);
+        assert_eq!(
+            None,
+            undirected_euler_circuit(4, &mut to_undirected(4, &edges))
+        );
+
+        let edges = complete(5);
+        let circuit = undirected_euler_circuit(5, &mut to_undirected(5, &edges)).unwrap();
+        check_undirected(&edges, &circuit);
+
+        // both directions of every edge of K4
+        let mut adj: Vec<Vec<usize>> = (0..4)
+            .map(|u| (0..4).filter(|&v| v != u).collect())
+            .collect();
+        let edges: Vec<_> = (0..4)
+            .flat_map(|u| (0..4).filter(move |&v| v != u).map(move |v| (u, v)))
+            .collect();
+        let circuit = directed_euler_circuit(4, &mut adj).unwrap();
+        check_directed(&edges, &circuit);
+    }
+
+    #[test]
+    fn no_circuit() {
+        let tree = [(0, 1), (1, 2), (1, 3), (3, 4)];
+        assert_eq!(
+            None,
+            undirected_euler_circuit(5, &mut to_undirected(5, &tree))
+        );
+
+        // the seven bridges of Königsberg: all four land masses have odd degree
+        let bridges = [(0, 1), (0, 1), (0, 2), (0, 2), (0, 3), (1, 3), (2, 3)];
+        let mut adj = to_undirected(4, &bridges);
+        assert_eq!(4, adj.iter().filter(|edges| edges.len() % 2 == 1).count());
+        assert_eq!(None, undirected_euler_circuit(4, &mut adj));
+
+        // balanced but disconnected
+        let cycles = [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)];
+        assert_eq!(
+            None,
+            undirected_euler_circuit(6, &mut to_undirected(6, &cycles))
+        );
+        let mut adj = vec![vec![1], vec![0], vec![3], vec![2]];
+        assert_eq!(None, directed_euler_circuit(4, &mut adj));
+
+        // unbalanced
+        let mut adj = vec![vec![1, 2], vec![2], vec![0]];
+        assert_eq!(None, directed_euler_circuit(3, &mut adj));
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(
+            Some(vec![]),
+            undirected_euler_circuit(3, &mut vec![vec![]; 3])
+        );
+        assert_eq!(Some(vec![]), directed_euler_circuit(0, &mut vec![]));
+
+        // isolated vertices and self-loops
+        let edges = [(1, 1), (1, 2), (2, 1)];
+        let circuit = undirected_euler_circuit(4, &mut to_undirected(4, &edges)).unwrap();
+        check_undirected(&edges, &circuit);
+        assert_eq!(1, circuit[0]);
+
+        let mut adj = vec![vec![], vec![1, 2], vec![1]];
+        assert_eq!(Some(vec![1, 1, 2, 1]), directed_euler_circuit(3, &mut adj));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(408);
+        for _ in 0..200 {
+            // a random closed walk always has an Eulerian circuit
+            let n = rng.next_range(1, 8) as usize;
+            let len = rng.next_range(1, 20) as usize;
+            let mut walk: Vec<usize> = (0..len)
+                .map(|_| rng.next_range(0, n as u64) as usize)
+                .collect();
+            walk.push(walk[0]);
+            let edges: Vec<_> = walk.windows(2).map(|w| (w[0], w[1])).collect();
+
+            let circuit = undirected_euler_circuit(n, &mut to_undirected(n, &edges)).unwrap();
+            check_undirected(&edges, &circuit);
+
+            let mut adj = vec![vec![]; n];
+            for &(u, v) in &edges {
+                adj[u].push(v);
+            }
+            let circuit = directed_euler_circuit(n, &mut adj).unwrap();
+            check_directed(&edges, &circuit);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Euler tour of a tree.
+
+use std::ops::Range;
+
+/// Euler tour of a rooted tree.
+/// Each subtree occupies a contiguous range of the DFS entry times.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::euler_tour::EulerTour;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// let et = EulerTour::new(&tree, 0);
+///
+/// assert_eq!(1..4, et.subtree_range(1));
+/// assert!(et.is_ancestor(1, 4));
+/// assert!(!et.is_ancestor(2, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EulerTour {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+}
+
+impl EulerTour {
+    /// Computes the Euler tour of the tree given as adjacency lists.
+    /// Consumes O(n) time.
+    pub fn new(tree: &[Vec<usize>], root: usize) -> Self {
+        let n = tree.len();
+        let mut tin = vec![0; n];
+        let mut tout = vec![0; n];
+        let mut time = 0;
+
+        // (vertex, parent, index of the next edge)
+        let mut dfs = vec![(root, None, 0)];
+        tin[root] = time;
+        time += 1;
+
+        while let Some(&mut (v, p, ref mut e)) = dfs.last_mut() {
+            if let Some(&u) = tree[v].get(*e) {
+                *e += 1;
+                if Some(u) != p {
+                    tin[u] = time;
+                    time += 1;
+                    dfs.push((u, Some(v), 0));
+                }
+            } else {
+                tout[v] = time;
+                dfs.pop();
+            }
+        }
+
+        Self { tin, tout }
+    }
+
+    /// Returns the DFS entry time of v.
+    pub fn tin(&self, v: usize) -> usize {
+        self.tin[v]
+    }
+
+    /// Returns the DFS exit time of v,
+    /// which is the entry time of v plus the size of the subtree.
+    pub fn tout(&self, v: usize) -> usize {
+        self.tout[v]
+    }
+
+    /// Returns the range of the entry times of the vertices in the subtree of v.
+    pub fn subtree_range(&self, v: usize) -> Range<usize> {
+        self.tin[v]..self.tout[v]
+    }
+
+    /// Returns true if u is an ancestor of v. A vertex is an ancestor of itself.
+    pub fn is_ancestor(&self, u: usize, v: usize) -> bool {
+        self.tin[u] <= self.tin[v] && self.tout[v] <= self.tout[u]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::fenwick::Fenwick;
+    use crate::utils::random::XorShift64;
+
+    fn random_parents(n: usize, seed: u64) -> Vec<usize> {
+        let mut rng = XorShift64::new(seed);
+        (0..n)
+            .map(|v| {
+                if v == 0 {
+                    0
+                } else {
+                    rng.next_range(0, v as u64) as usize
+                }
+            })
+            .collect()
+    }
+
+    fn to_tree(parents: &[usize]) -> Vec<Vec<usize>> {
+        let mut tree = vec![vec![]; parents.len()];
+        for (v, &p) in parents.iter().enumerate().skip(1) {
+            tree[v].push(p);
+            tree[p].push(v);
+        }
+        tree
+    }
+
+    #[test]
+    fn ancestors() {
+        //       0
+        //     / | \
+        //    1  2  3
+        //   /|     |
+        //  4 5     6
+        //          |
+        //          7
+        let parents = [0, 0, 0, 0, 1, 1, 3, 6];
+        let et = EulerTour::new(&to_tree(&parents), 0);
+
+        for v in 0..8 {
+            let mut ancestors = vec![v];
+            while *ancestors.last().unwrap() != 0 {
+                ancestors.push(parents[*ancestors.last().unwrap()]);
+            }
+            for u in 0..8 {
+                assert_eq!(ancestors.contains(&u), et.is_ancestor(u, v));
+            }
+        }
+        assert_eq!(8, et.subtree_range(0).len());
+        assert_eq!(3, et.subtree_range(3).len());
+    }
+
+    #[test]
+    fn subtree_sum() {
+        let n = 100;
+        let parents = random_parents(n, 1);
+        let et = EulerTour::new(&to_tree(&parents), 0);
+
+        let mut values: Vec<i64> = (0..n as i64).map(|v| v * v % 17).collect();
+        let mut fw = Fenwick::from_slice(&vec![0; n]);
+        for (v, &x) in values.iter().enumerate() {
+            fw.add(et.tin(v) + 1, x);
+        }
+
+        for step in 0..50 {
+            let w = step * 7 % n;
+            values[w] += step as i64;
+            fw.add(et.tin(w) + 1, step as i64);
+
+            for v in 0..n {
+                let range = et.subtree_range(v);
+                let naive: i64 = (0..n)
+                    .filter(|&u| {
+                        std::iter::successors(Some(u), |&w| Some(parents[w]).filter(|_| w != 0))
+                            .any(|w| w == v)
+                    })
+                    .map(|u| values[u])
+                    .sum();
+                assert_eq!(naive, fw.sum(range.end) - fw.sum(range.start));
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Floyd-Warshall algorithm.
+
+/// The distance representing a missing edge.
+pub const INF: i64 = std::i64::MAX / 2;
+
+/// Computes the all-pairs shortest distances in place, using the Floyd-Warshall algorithm.
+/// `dist[i][j]` is the weight of the edge from i to j, or `INF` if there is no edge,
+/// and `dist[i][i]` is usually 0.
+/// After the call, `dist[i][j]` is the shortest distance, or `INF` if j is unreachable from i.
+/// Consumes O(n^3) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::floyd_warshall::{floyd_warshall, INF};
+///
+/// let mut dist = vec![vec![0, 4, INF], vec![INF, 0, -1], vec![2, INF, 0]];
+/// floyd_warshall(&mut dist);
+///
+/// assert_eq!(vec![vec![0, 4, 3], vec![1, 0, -1], vec![2, 6, 0]], dist);
+/// ```
+pub fn floyd_warshall(dist: &mut Vec<Vec<i64>>) {
+    let n = dist.len();
+    for k in 0..n {
+        for i in 0..n {
+            if dist[i][k] >= INF {
+                continue;
+            }
+            for j in 0..n {
+                if dist[k][j] < INF {
+                    dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if the graph has a negative cycle,
+/// given the distances computed by `floyd_warshall`.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::floyd_warshall::{floyd_warshall, has_negative_cycle, INF};
+///
+/// let mut dist = vec![vec![0, 1, INF], vec![INF, 0, -2], vec![INF, 1, 0]];
+/// floyd_warshall(&mut dist);
+///
+/// assert!(has_negative_cycle(&dist));
+/// ```
+pub fn has_negative_cycle(dist: &[Vec<i64>]) -> bool {
+    dist.iter().enumerate().any(|(i, row)| row[i] < 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    fn dijkstra(adj: &[Vec<(usize, i64)>], s: usize) -> Vec<i64> {
+        let mut dist = vec![INF; adj.len()];
+        let mut heap = BinaryHeap::new();
+        dist[s] = 0;
+        heap.push(Reverse((0, s)));
+
+        while let Some(Reverse((d, v))) = heap.pop() {
+            if d > dist[v] {
+                continue;
+            }
+            for &(u, w) in adj[v].iter() {
+                if d + w < dist[u] {
+                    dist[u] = d + w;
+                    heap.push(Reverse((dist[u], u)));
+                }
+            }
+        }
+
+        dist
+    }
+
+    #[test]
+    fn dijkstra_from_each() {
+        let n = 30;
+        let mut adj = vec![vec![]; n];
+        let mut dist = vec![vec![INF; n]; n];
+        (0..n).for_each(|i| dist[i][i] = 0);
+
+        let mut rng = XorShift64::new(1);
+        for _ in 0..120 {
+            let mut rand = |m: u64| rng.next_range(0, m) as usize;
+            let (u, v, w) = (rand(n as u64), rand(n as u64), rand(100) as i64);
+            adj[u].push((v, w));
+            dist[u][v] = dist[u][v].min(w);
+        }
+
+        floyd_warshall(&mut dist);
+        assert!(!has_negative_cycle(&dist));
+        for (s, row) in dist.iter().enumerate() {
+            assert_eq!(&dijkstra(&adj, s), row);
+        }
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 1 with the cycle weight -1, and an isolated vertex 4
+        let mut dist = vec![vec![INF; 5]; 5];
+        (0..5).for_each(|i| dist[i][i] = 0);
+        dist[0][1] = 3;
+        dist[1][2] = 2;
+        dist[2][3] = -4;
+        dist[3][1] = 1;
+
+        floyd_warshall(&mut dist);
+        assert!(has_negative_cycle(&dist));
+        assert!(dist[1][1] < 0 && dist[2][2] < 0 && dist[3][3] < 0);
+        assert_eq!(0, dist[0][0]);
+        assert_eq!(0, dist[4][4]);
+    }
+
+    #[test]
+    fn negative_edges_without_cycle() {
+        let mut dist = vec![vec![0, -1, INF], vec![INF, 0, -2], vec![INF, INF, 0]];
+        floyd_warshall(&mut dist);
+
+        assert!(!has_negative_cycle(&dist));
+        assert_eq!(-3, dist[0][2]);
+        assert_eq!(INF, dist[2][0]);
+    }
+}
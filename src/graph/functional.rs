@@ -0,0 +1,145 @@
+//! Functional graph.
+
+/// Functional graph, where each vertex has exactly one outgoing edge.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::functional::FunctionalGraph;
+///
+/// // 0 -> 1 -> 2 -> 3 -> 1
+/// let fg = FunctionalGraph::new(vec![1, 2, 3, 1], 1_000_000_000_000);
+///
+/// assert_eq!(3, fg.kth_successor(0, 3));
+/// assert_eq!(1, fg.kth_successor(0, 1_000_000_000_000));
+/// assert_eq!((1, 3), fg.find_cycle(0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FunctionalGraph {
+    // doubling[j][v] is the 2^j-th successor of v
+    doubling: Vec<Vec<usize>>,
+}
+
+impl FunctionalGraph {
+    /// Constructs a new graph where the successor of v is `succ[v]`,
+    /// answering `kth_successor` for k up to `max_k`.
+    /// Consumes O(n log max_k) time.
+    ///
+    /// # Panics
+    /// Panics if some successor is out of range.
+    pub fn new(succ: Vec<usize>, max_k: u64) -> Self {
+        let n = succ.len();
+        assert!(succ.iter().all(|&u| u < n));
+
+        let log = (64 - max_k.leading_zeros() as usize).max(1);
+        let mut doubling = Vec::with_capacity(log);
+        doubling.push(succ);
+        for j in 1..log {
+            let prev = &doubling[j - 1];
+            let next = prev.iter().map(|&u| prev[u]).collect();
+            doubling.push(next);
+        }
+
+        Self { doubling }
+    }
+
+    /// Returns the k-th successor of v.
+    /// Consumes O(log max_k) time.
+    ///
+    /// # Panics
+    /// Panics if k has more bits than the `max_k` given to `new`.
+    pub fn kth_successor(&self, v: usize, k: u64) -> usize {
+        let log = self.doubling.len();
+        assert!(
+            log == 64 || k >> log == 0,
+            "k exceeds the maximum of the queries"
+        );
+
+        (0..log)
+            .filter(|&j| k >> j & 1 == 1)
+            .fold(v, |v, j| self.doubling[j][v])
+    }
+
+    /// Returns the first vertex on the cycle reached from v, and the length of the cycle.
+    /// Consumes O(n) time.
+    pub fn find_cycle(&self, v: usize) -> (usize, usize) {
+        let succ = &self.doubling[0];
+
+        // Floyd's cycle detection
+        let mut slow = succ[v];
+        let mut fast = succ[succ[v]];
+        while slow != fast {
+            slow = succ[slow];
+            fast = succ[succ[fast]];
+        }
+
+        let mut entry = v;
+        while entry != slow {
+            entry = succ[entry];
+            slow = succ[slow];
+        }
+
+        let len = 1 + std::iter::successors(Some(succ[entry]), |&u| Some(succ[u]))
+            .take_while(|&u| u != entry)
+            .count();
+
+        (entry, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permutation() {
+        // cycles (0 3 5), (1), (2 4 6 7)
+        let succ = vec![3, 1, 4, 5, 6, 0, 7, 2];
+        let fg = FunctionalGraph::new(succ.clone(), std::u64::MAX);
+
+        for v in 0..succ.len() {
+            let mut u = v;
+            for k in 0..30 {
+                assert_eq!(u, fg.kth_successor(v, k));
+                u = succ[u];
+            }
+        }
+
+        assert_eq!((5, 3), fg.find_cycle(5));
+        assert_eq!((1, 1), fg.find_cycle(1));
+        assert_eq!((4, 4), fg.find_cycle(4));
+        assert_eq!(0, fg.kth_successor(0, 3_000_000_000_000_000_000));
+        assert_eq!(7, fg.kth_successor(2, std::u64::MAX));
+    }
+
+    #[test]
+    fn rho() {
+        // tails 8 -> 9 -> 0 and 10 -> 2 into a permutation's cycles
+        let succ = vec![3, 1, 4, 5, 6, 0, 7, 2, 9, 0, 2];
+        let fg = FunctionalGraph::new(succ, 4);
+
+        assert_eq!((0, 3), fg.find_cycle(8));
+        assert_eq!((2, 4), fg.find_cycle(10));
+        assert_eq!(5, fg.kth_successor(8, 4));
+    }
+    #[test]
+    fn small_table() {
+        let succ = vec![3, 1, 4, 5, 6, 0, 7, 2, 9, 0, 2];
+        let full = FunctionalGraph::new(succ.clone(), std::u64::MAX);
+        let fg = FunctionalGraph::new(succ.clone(), 100);
+
+        for v in 0..succ.len() {
+            for k in 0..128 {
+                assert_eq!(full.kth_successor(v, k), fg.kth_successor(v, k));
+            }
+        }
+        assert_eq!(0, FunctionalGraph::new(succ, 0).kth_successor(0, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn query_too_large() {
+        let fg = FunctionalGraph::new(vec![1, 2, 0], 7);
+        fg.kth_successor(0, 8);
+    }
+}
@@ -0,0 +1,324 @@
+//! Bipartite matching.
+
+pub mod kuhn;
+
+use std::collections::VecDeque;
+
+const NONE: usize = std::usize::MAX;
+
+/// Maximum bipartite matching, using the Hopcroft-Karp algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::matching::HopcroftKarp;
+///
+/// let mut hk = HopcroftKarp::new(3, 2);
+/// hk.add_edge(0, 0);
+/// hk.add_edge(1, 0);
+/// hk.add_edge(2, 0);
+/// hk.add_edge(2, 1);
+///
+/// assert_eq!(2, hk.maximum_matching());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HopcroftKarp {
+    adj: Vec<Vec<usize>>,
+    right: usize,
+    match_left: Vec<usize>,
+    match_right: Vec<usize>,
+}
+
+impl HopcroftKarp {
+    /// Constructs a new bipartite graph with no edges.
+    pub fn new(left: usize, right: usize) -> Self {
+        Self {
+            adj: vec![vec![]; left],
+            right,
+            match_left: vec![NONE; left],
+            match_right: vec![NONE; right],
+        }
+    }
+
+    /// Adds an edge between the left vertex u and the right vertex v.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        assert!(v < self.right);
+        self.adj[u].push(v);
+    }
+
+    /// Computes a maximum matching. Returns its size.
+    /// Consumes O(m √n) time.
+    pub fn maximum_matching(&mut self) -> usize {
+        loop {
+            let level = self.bfs();
+            let mut iter = vec![0; self.adj.len()];
+            let mut found = false;
+            for u in 0..self.adj.len() {
+                if self.match_left[u] == NONE && self.dfs(u, &level, &mut iter) {
+                    found = true;
+                }
+            }
+            if !found {
+                break;
+            }
+        }
+
+        self.match_left.iter().filter(|&&v| v != NONE).count()
+    }
+
+    /// Returns the right vertex matched with the left vertex u.
+    pub fn matched(&self, u: usize) -> Option<usize> {
+        Some(self.match_left[u]).filter(|&v| v != NONE)
+    }
+
+    // the distances of the left vertices from the free left vertices in the residual graph
+    fn bfs(&self) -> Vec<usize> {
+        let mut level = vec![NONE; self.adj.len()];
+        let mut queue = VecDeque::new();
+        for u in (0..self.adj.len()).filter(|&u| self.match_left[u] == NONE) {
+            level[u] = 0;
+            queue.push_back(u);
+        }
+
+        while let Some(u) = queue.pop_front() {
+            for &v in self.adj[u].iter() {
+                let w = self.match_right[v];
+                if w != NONE && level[w] == NONE {
+                    level[w] = level[u] + 1;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        level
+    }
+
+    fn dfs(&mut self, u: usize, level: &[usize], iter: &mut [usize]) -> bool {
+        while iter[u] < self.adj[u].len() {
+            let v = self.adj[u][iter[u]];
+            iter[u] += 1;
+
+            let w = self.match_right[v];
+            if w == NONE || (level[w] == level[u] + 1 && self.dfs(w, level, iter)) {
+                self.match_left[u] = v;
+                self.match_right[v] = u;
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Solves the assignment problem, using the Hungarian algorithm with potentials.
+/// Each row is assigned a distinct column minimizing the total cost.
+/// Returns the total cost and the column assigned to each row.
+/// Consumes O(n^2 m) time for n rows and m columns.
+///
+/// # Panics
+/// Panics if the rows have different lengths, or there are more rows than columns.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::matching::hungarian;
+///
+/// let cost = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+/// assert_eq!((5, vec![1, 0, 2]), hungarian(&cost));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn hungarian(cost: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let n = cost.len();
+    let m = cost.first().map_or(0, Vec::len);
+    assert!(cost.iter().all(|row| row.len() == m));
+    assert!(n <= m, "There are more rows than columns");
+
+    // 1-indexed, with the column 0 as the sentinel
+    let inf = std::i64::MAX / 2;
+    let mut u = vec![0; n + 1];
+    let mut v = vec![0; m + 1];
+    let mut row_of = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![inf; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = inf;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let cur = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if cur < min_v[j] {
+                    min_v[j] = cur;
+                    way[j] = j0;
+                }
+                if min_v[j] < delta {
+                    delta = min_v[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+
+        // augment along the alternating path
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            assignment[row_of[j] - 1] = j - 1;
+        }
+    }
+    let total = assignment
+        .iter()
+        .enumerate()
+        .map(|(i, &j)| cost[i][j])
+        .sum();
+
+    (total, assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn complete() {
+        for left in 0..6 {
+            for right in 0..6 {
+                let mut hk = HopcroftKarp::new(left, right);
+                for u in 0..left {
+                    for v in 0..right {
+                        hk.add_edge(u, v);
+                    }
+                }
+                assert_eq!(left.min(right), hk.maximum_matching());
+            }
+        }
+    }
+
+    #[test]
+    fn isolated() {
+        let mut hk = HopcroftKarp::new(4, 4);
+        hk.add_edge(0, 1);
+        hk.add_edge(2, 1);
+        hk.add_edge(2, 3);
+
+        assert_eq!(2, hk.maximum_matching());
+        assert_eq!(None, hk.matched(1));
+        assert_eq!(None, hk.matched(3));
+        assert_eq!(Some(1), hk.matched(0));
+        assert_eq!(Some(3), hk.matched(2));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(11);
+        let mut rand = |m: usize| rng.next_range(0, m as u64) as usize;
+
+        for _ in 0..100 {
+            let (left, right) = (rand(20) + 1, rand(20) + 1);
+            let edges: Vec<_> = (0..rand(60)).map(|_| (rand(left), rand(right))).collect();
+
+            let mut hk = HopcroftKarp::new(left, right);
+            edges.iter().for_each(|&(u, v)| hk.add_edge(u, v));
+            let size = hk.maximum_matching();
+            let mut adj = vec![vec![]; left];
+            edges.iter().for_each(|&(u, v)| adj[u].push(v));
+            assert_eq!(kuhn::maximum_matching(left, right, &adj).0, size);
+
+            // the matching is valid
+            let mut matched: Vec<_> = (0..left)
+                .filter_map(|u| hk.matched(u).map(|v| (u, v)))
+                .collect();
+            assert_eq!(size, matched.len());
+            assert!(matched.iter().all(|e| edges.contains(e)));
+            matched.sort_by_key(|&(_, v)| v);
+            matched.dedup_by_key(|&mut (_, v)| v);
+            assert_eq!(size, matched.len());
+        }
+    }
+
+    fn brute_force(cost: &[Vec<i64>]) -> i64 {
+        let mut perm: Vec<usize> = (0..cost.len()).collect();
+        let mut best = std::i64::MAX;
+        loop {
+            best = best.min(perm.iter().enumerate().map(|(i, &j)| cost[i][j]).sum());
+            if !crate::utils::perm::next_permutation(&mut perm) {
+                break;
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn hungarian_classic() {
+        let cost = vec![
+            vec![250, 400, 350],
+            vec![400, 600, 350],
+            vec![200, 400, 250],
+        ];
+        assert_eq!((950, vec![1, 2, 0]), hungarian(&cost));
+
+        let identity: Vec<Vec<i64>> = (0..4)
+            .map(|i| (0..4).map(|j| if i == j { 1 } else { 0 }).collect())
+            .collect();
+        let (total, assignment) = hungarian(&identity);
+        assert_eq!(0, total);
+        assert!(assignment.iter().enumerate().all(|(i, &j)| i != j));
+
+        assert_eq!((0, vec![]), hungarian(&[]));
+    }
+
+    #[test]
+    fn hungarian_random() {
+        let mut rng = XorShift64::new(5);
+        let mut rand = || rng.next_range(0, 201) as i64 - 100;
+
+        for n in 1..=5 {
+            for _ in 0..20 {
+                let cost: Vec<Vec<i64>> =
+                    (0..n).map(|_| (0..n).map(|_| rand()).collect()).collect();
+                let (total, assignment) = hungarian(&cost);
+
+                assert_eq!(brute_force(&cost), total);
+                let mut cols = assignment.clone();
+                cols.sort();
+                assert_eq!((0..n).collect::<Vec<_>>(), cols);
+            }
+        }
+    }
+
+    #[test]
+    fn hungarian_rectangular() {
+        let cost = vec![vec![5, 1, 9, 2], vec![4, 1, 7, 8]];
+        assert_eq!((3, vec![3, 1]), hungarian(&cost));
+    }
+}
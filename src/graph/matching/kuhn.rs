@@ -0,0 +1,120 @@
+//! Bipartite matching by Kuhn's algorithm.
+
+/// Computes a maximum bipartite matching, using Kuhn's augmenting path algorithm.
+/// `adj[u]` lists the right vertices adjacent to the left vertex u.
+/// Returns the size of the matching and the mate of each left vertex.
+/// Simpler but slower than `HopcroftKarp`, and suited for small inputs.
+/// Consumes O(nm) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::matching::kuhn::maximum_matching;
+///
+/// let adj = vec![vec![0], vec![0], vec![0, 1]];
+///
+/// let (size, mate) = maximum_matching(3, 2, &adj);
+/// assert_eq!(2, size);
+/// assert_eq!(Some(1), mate[2]);
+/// ```
+pub fn maximum_matching(
+    left: usize,
+    right: usize,
+    adj: &[Vec<usize>],
+) -> (usize, Vec<Option<usize>>) {
+    assert_eq!(left, adj.len());
+    assert!(adj.iter().flatten().all(|&v| v < right));
+
+    // Tries to match u, reassigning the mates along an augmenting path.
+    fn augment(
+        u: usize,
+        adj: &[Vec<usize>],
+        visited: &mut [bool],
+        mate_left: &mut [Option<usize>],
+        mate_right: &mut [Option<usize>],
+    ) -> bool {
+        for &v in &adj[u] {
+            if visited[v] {
+                continue;
+            }
+            visited[v] = true;
+            if mate_right[v].map_or(true, |w| augment(w, adj, visited, mate_left, mate_right)) {
+                mate_left[u] = Some(v);
+                mate_right[v] = Some(u);
+                return true;
+            }
+        }
+        false
+    }
+
+    let mut mate_left = vec![None; left];
+    let mut mate_right = vec![None; right];
+    let mut size = 0;
+    for u in 0..left {
+        let mut visited = vec![false; right];
+        if augment(u, adj, &mut visited, &mut mate_left, &mut mate_right) {
+            size += 1;
+        }
+    }
+
+    (size, mate_left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::matching::HopcroftKarp;
+    use crate::utils::random::XorShift64;
+
+    fn check(left: usize, right: usize, adj: &[Vec<usize>]) {
+        let (size, mate) = maximum_matching(left, right, adj);
+
+        let mut hk = HopcroftKarp::new(left, right);
+        for (u, vs) in adj.iter().enumerate() {
+            for &v in vs {
+                hk.add_edge(u, v);
+            }
+        }
+        assert_eq!(hk.maximum_matching(), size);
+
+        // the mates form a matching along the edges
+        assert_eq!(size, mate.iter().flatten().count());
+        let mut used = vec![false; right];
+        for (u, &v) in mate.iter().enumerate() {
+            if let Some(v) = v {
+                assert!(adj[u].contains(&v));
+                assert!(!used[v]);
+                used[v] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn small() {
+        check(3, 2, &[vec![0], vec![0], vec![0, 1]]);
+        check(0, 0, &[]);
+        check(2, 3, &[vec![], vec![]]);
+
+        // a perfect matching needs the path 0-0, 1-1, 2-2 to be rerouted
+        let adj = vec![vec![0, 1], vec![0], vec![1, 2]];
+        assert_eq!(3, maximum_matching(3, 3, &adj).0);
+        check(3, 3, &adj);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(398);
+        for _ in 0..200 {
+            let left = rng.next_range(0, 12) as usize;
+            let right = rng.next_range(1, 12) as usize;
+            let adj: Vec<Vec<usize>> = (0..left)
+                .map(|_| {
+                    (0..rng.next_range(0, 4))
+                        .map(|_| rng.next_range(0, right as u64) as usize)
+                        .collect()
+                })
+                .collect();
+            check(left, right, &adj);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Minimum cost flow.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+struct Edge {
+    to: usize,
+    cap: i64,
+    cost: i64,
+    rev: usize,
+}
+
+/// Minimum cost flow, using successive shortest paths with SPFA.
+/// Costs may be negative as long as there is no negative cycle.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::mcmf::MCMF;
+///
+/// let mut g = MCMF::new(4);
+/// g.add_edge(0, 1, 2, 1);
+/// g.add_edge(0, 2, 1, 2);
+/// g.add_edge(1, 3, 1, 3);
+/// g.add_edge(1, 2, 1, 1);
+/// g.add_edge(2, 3, 2, 1);
+///
+/// assert_eq!((3, 10), g.min_cost_flow(0, 3, 10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MCMF {
+    graph: Vec<Vec<Edge>>,
+}
+
+impl MCMF {
+    /// Constructs a new network with n vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            graph: vec![vec![]; n],
+        }
+    }
+
+    /// Adds a directed edge from u to v with the capacity and the cost per unit flow.
+    pub fn add_edge(&mut self, u: usize, v: usize, cap: i64, cost: i64) {
+        assert!(cap >= 0);
+
+        let rev_u = self.graph[v].len() + if u == v { 1 } else { 0 };
+        let rev_v = self.graph[u].len();
+        self.graph[u].push(Edge {
+            to: v,
+            cap,
+            cost,
+            rev: rev_u,
+        });
+        self.graph[v].push(Edge {
+            to: u,
+            cap: 0,
+            cost: -cost,
+            rev: rev_v,
+        });
+    }
+
+    /// Sends flow from s to t up to `flow_limit` with the minimum cost.
+    /// Returns the amount of the flow and the total cost.
+    /// Consumes O(F n m) time in the worst case where F is the amount of the flow.
+    pub fn min_cost_flow(&mut self, s: usize, t: usize, flow_limit: i64) -> (i64, i64) {
+        let n = self.graph.len();
+        let mut flow = 0;
+        let mut cost = 0;
+
+        while flow < flow_limit {
+            // the shortest path tree by SPFA
+            let mut dist = vec![std::i64::MAX; n];
+            let mut prev = vec![None; n];
+            let mut in_queue = vec![false; n];
+            let mut queue = VecDeque::new();
+            dist[s] = 0;
+            queue.push_back(s);
+
+            while let Some(v) = queue.pop_front() {
+                in_queue[v] = false;
+                for (i, e) in self.graph[v].iter().enumerate() {
+                    if e.cap > 0 && dist[v] + e.cost < dist[e.to] {
+                        dist[e.to] = dist[v] + e.cost;
+                        prev[e.to] = Some((v, i));
+                        if !in_queue[e.to] {
+                            in_queue[e.to] = true;
+                            queue.push_back(e.to);
+                        }
+                    }
+                }
+            }
+
+            if dist[t] == std::i64::MAX {
+                break;
+            }
+
+            let path: Vec<(usize, usize)> =
+                std::iter::successors(prev[t], |&(v, _)| prev[v]).collect();
+            let f = path
+                .iter()
+                .map(|&(v, i)| self.graph[v][i].cap)
+                .fold(flow_limit - flow, i64::min);
+
+            for &(v, i) in path.iter() {
+                self.graph[v][i].cap -= f;
+                let Edge { to, rev, .. } = self.graph[v][i];
+                self.graph[to][rev].cap += f;
+            }
+
+            flow += f;
+            cost += f * dist[t];
+        }
+
+        (flow, cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::perm::next_permutation;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn assignment() {
+        let mut rng = XorShift64::new(1);
+        for n in 1..=6 {
+            let costs: Vec<Vec<i64>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.next_range(0, 100) as i64).collect())
+                .collect();
+
+            // workers 0..n, jobs n..2n, source 2n, sink 2n+1
+            let (s, t) = (2 * n, 2 * n + 1);
+            let mut g = MCMF::new(2 * n + 2);
+            for i in 0..n {
+                g.add_edge(s, i, 1, 0);
+                g.add_edge(n + i, t, 1, 0);
+            }
+            for (i, row) in costs.iter().enumerate() {
+                for (j, &c) in row.iter().enumerate() {
+                    g.add_edge(i, n + j, 1, c);
+                }
+            }
+
+            let mut perm: Vec<usize> = (0..n).collect();
+            let mut naive = std::i64::MAX;
+            loop {
+                naive = naive.min((0..n).map(|i| costs[i][perm[i]]).sum());
+                if !next_permutation(&mut perm) {
+                    break;
+                }
+            }
+
+            assert_eq!((n as i64, naive), g.min_cost_flow(s, t, std::i64::MAX));
+        }
+    }
+
+    #[test]
+    fn flow_limit() {
+        let build = || {
+            let mut g = MCMF::new(3);
+            g.add_edge(0, 1, 5, 1);
+            g.add_edge(0, 1, 5, 3);
+            g.add_edge(1, 2, 7, 2);
+            g
+        };
+
+        assert_eq!((0, 0), build().min_cost_flow(0, 2, 0));
+        assert_eq!((4, 12), build().min_cost_flow(0, 2, 4));
+        assert_eq!((7, 25), build().min_cost_flow(0, 2, 7));
+        assert_eq!((7, 25), build().min_cost_flow(0, 2, 100));
+        assert_eq!((0, 0), build().min_cost_flow(2, 0, 100));
+    }
+
+    #[test]
+    fn negative_cost() {
+        let mut g = MCMF::new(4);
+        g.add_edge(0, 1, 1, 1);
+        g.add_edge(0, 2, 1, 4);
+        g.add_edge(2, 1, 1, -3);
+        g.add_edge(1, 3, 2, 0);
+
+        assert_eq!((2, 2), g.min_cost_flow(0, 3, 2));
+    }
+}
@@ -0,0 +1,198 @@
+//! Minimum spanning tree.
+
+use crate::data_structures::union_find::UnionFind;
+use std::ops::Add;
+
+/// Computes a minimum spanning forest of an undirected graph, using Prim's algorithm
+/// with a linear scan instead of a heap, which suits dense graphs.
+/// `adj` holds both directions of each edge.
+/// Returns the total weight and the parent of each vertex, None for the roots.
+/// Consumes O(n^2 + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::mst::prim;
+///
+/// // 0 -1- 1 -2- 2, 0 -5- 2
+/// let adj = vec![vec![(1, 1), (2, 5)], vec![(0, 1), (2, 2)], vec![(1, 2), (0, 5)]];
+///
+/// assert_eq!((3, vec![None, Some(0), Some(1)]), prim(3, &adj));
+/// ```
+pub fn prim(n: usize, adj: &[Vec<(usize, i64)>]) -> (i64, Vec<Option<usize>>) {
+    assert_eq!(n, adj.len());
+
+    let mut dist = vec![std::i64::MAX; n];
+    let mut parent = vec![None; n];
+    let mut used = vec![false; n];
+    let mut total = 0;
+
+    for _ in 0..n {
+        // the closest vertex to the tree, or a new root
+        let v = (0..n)
+            .filter(|&v| !used[v])
+            .min_by_key(|&v| (dist[v], v))
+            .unwrap();
+        used[v] = true;
+        if parent[v].is_some() {
+            total += dist[v];
+        }
+
+        for &(u, w) in adj[v].iter() {
+            if !used[u] && w < dist[u] {
+                dist[u] = w;
+                parent[u] = Some(v);
+            }
+        }
+    }
+
+    (total, parent)
+}
+
+/// Computes a minimum spanning forest of an undirected graph, using Kruskal's algorithm,
+/// which suits sparse graphs.
+/// `edges` holds each edge `(u, v, w)` once.
+/// Returns the total weight and the edges of the forest in ascending order of weight.
+/// Consumes O(m log m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::mst::kruskal;
+///
+/// let edges = [(0, 1, 1), (1, 2, 2), (0, 2, 5)];
+/// assert_eq!((3, vec![(0, 1, 1), (1, 2, 2)]), kruskal(3, &edges));
+/// ```
+pub fn kruskal<W>(n: usize, edges: &[(usize, usize, W)]) -> (W, Vec<(usize, usize, W)>)
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, w)| w);
+
+    let mut uf = UnionFind::new(n);
+    let mut total = W::default();
+    let mut forest = vec![];
+    for (u, v, w) in sorted {
+        if uf.unite(u, v) {
+            total = total + w;
+            forest.push((u, v, w));
+        }
+    }
+
+    (total, forest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn to_adj(n: usize, edges: &[(usize, usize, i64)]) -> Vec<Vec<(usize, i64)>> {
+        let mut adj = vec![vec![]; n];
+        for &(u, v, w) in edges.iter() {
+            adj[u].push((v, w));
+            adj[v].push((u, w));
+        }
+        adj
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(3);
+        let mut rand = |m: usize| rng.next_range(0, m as u64) as usize;
+
+        for _ in 0..100 {
+            let n = rand(12) + 1;
+            let edges: Vec<_> = (0..rand(40))
+                .map(|_| (rand(n), rand(n), rand(201) as i64 - 100))
+                .collect();
+
+            let (total, parent) = prim(n, &to_adj(n, &edges));
+            let (kruskal_total, forest) = kruskal(n, &edges);
+            assert_eq!(kruskal_total, total);
+            assert_eq!(parent.iter().filter(|p| p.is_some()).count(), forest.len());
+            assert_eq!(total, forest.iter().map(|&(_, _, w)| w).sum::<i64>());
+
+            // the parents form a forest of the edges with the same weight
+            let mut sum = 0;
+            for (v, p) in parent.iter().enumerate() {
+                if let Some(p) = *p {
+                    sum += edges
+                        .iter()
+                        .filter(|&&(a, b, _)| (a, b) == (v, p) || (a, b) == (p, v))
+                        .map(|&(_, _, w)| w)
+                        .min()
+                        .unwrap();
+                }
+            }
+            assert_eq!(total, sum);
+        }
+    }
+
+    #[test]
+    fn complete() {
+        // K5 with all weights equal
+        let edges: Vec<_> = (0..5)
+            .flat_map(|u| (u + 1..5).map(move |v| (u, v, 7)))
+            .collect();
+        let (total, parent) = prim(5, &to_adj(5, &edges));
+
+        assert_eq!(28, total);
+        assert_eq!(4, parent.iter().filter(|p| p.is_some()).count());
+    }
+
+    #[test]
+    fn forest() {
+        let edges = [(0, 1, 3), (2, 3, 4)];
+        assert_eq!(
+            (7, vec![None, Some(0), None, Some(2)]),
+            prim(4, &to_adj(4, &edges))
+        );
+        assert_eq!((0, vec![]), prim(0, &[]));
+    }
+
+    #[test]
+    fn kruskal_known() {
+        // the example graph with vertices A..G of the usual textbook illustration
+        let edges = [
+            (0, 1, 7),
+            (0, 3, 5),
+            (1, 2, 8),
+            (1, 3, 9),
+            (1, 4, 7),
+            (2, 4, 5),
+            (3, 4, 15),
+            (3, 5, 6),
+            (4, 5, 8),
+            (4, 6, 9),
+            (5, 6, 11),
+        ];
+        let (total, forest) = kruskal(7, &edges);
+        assert_eq!(39, total);
+        assert_eq!(6, forest.len());
+        assert_eq!(
+            vec![
+                (0, 3, 5),
+                (2, 4, 5),
+                (3, 5, 6),
+                (0, 1, 7),
+                (1, 4, 7),
+                (4, 6, 9)
+            ],
+            forest
+        );
+    }
+
+    #[test]
+    fn kruskal_small() {
+        assert_eq!((0u64, vec![]), kruskal(1, &[]));
+        assert_eq!((0u64, vec![]), kruskal(1, &[(0, 0, 5)]));
+        assert_eq!((0i64, vec![]), kruskal(0, &[]));
+
+        // a forest of two trees
+        let (total, forest) = kruskal(4, &[(0, 1, 3u32), (2, 3, 4), (3, 2, 1)]);
+        assert_eq!(4, total);
+        assert_eq!(vec![(3, 2, 1), (0, 1, 3)], forest);
+    }
+}
@@ -0,0 +1,163 @@
+//! Rerooting DP.
+
+/// Computes a tree DP for every vertex as the root.
+/// The value of a subtree rooted at v is `finalize(&acc, v)`,
+/// where acc folds `merge` over the values of the children starting from `identity`.
+/// `merge` is required to be associative and commutative.
+/// Consumes O(n) time, calling `merge` and `finalize` O(n) times.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::rerooting::reroot;
+///
+/// // the height of the tree from each root
+/// // 0 - 1 - 2
+/// //     |
+/// //     3 - 4
+/// let tree = vec![vec![1], vec![0, 2, 3], vec![1], vec![1, 4], vec![3]];
+/// let height = reroot(&tree, -1, |a: &i64, b: &i64| *a.max(b), |a, _| a + 1);
+///
+/// assert_eq!(vec![3, 2, 3, 2, 3], height);
+/// ```
+pub fn reroot<T, Merge, Finalize>(
+    tree: &[Vec<usize>],
+    identity: T,
+    merge: Merge,
+    finalize: Finalize,
+) -> Vec<T>
+where
+    T: Clone,
+    Merge: Fn(&T, &T) -> T,
+    Finalize: Fn(&T, usize) -> T,
+{
+    let n = tree.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    // the DFS order from the vertex 0
+    let mut parent = vec![std::usize::MAX; n];
+    let mut order = Vec::with_capacity(n);
+    let mut stack = vec![0];
+    parent[0] = 0;
+    while let Some(v) = stack.pop() {
+        order.push(v);
+        for &u in tree[v].iter() {
+            if parent[u] == std::usize::MAX {
+                parent[u] = v;
+                stack.push(u);
+            }
+        }
+    }
+
+    let is_child = |v: usize, u: usize| u != 0 && parent[u] == v;
+
+    // down[v]: the value of the subtree of v
+    let mut down = vec![identity.clone(); n];
+    for &v in order.iter().rev() {
+        let acc = tree[v]
+            .iter()
+            .filter(|&&u| is_child(v, u))
+            .fold(identity.clone(), |acc, &u| merge(&acc, &down[u]));
+        down[v] = finalize(&acc, v);
+    }
+
+    // up[v]: the value of the rest of the tree as a subtree of the parent of v
+    let mut up = vec![identity.clone(); n];
+    let mut res = vec![identity.clone(); n];
+    for &v in order.iter() {
+        let values: Vec<T> = tree[v]
+            .iter()
+            .map(|&u| {
+                if is_child(v, u) {
+                    down[u].clone()
+                } else {
+                    up[v].clone()
+                }
+            })
+            .collect();
+
+        // suffix[i] is the product of values[i..]
+        let mut suffix = vec![identity.clone(); values.len() + 1];
+        for i in (0..values.len()).rev() {
+            suffix[i] = merge(&values[i], &suffix[i + 1]);
+        }
+        res[v] = finalize(&suffix[0], v);
+
+        let mut prefix = identity.clone();
+        for (i, &u) in tree[v].iter().enumerate() {
+            if is_child(v, u) {
+                up[u] = finalize(&merge(&prefix, &suffix[i + 1]), v);
+            }
+            prefix = merge(&prefix, &values[i]);
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn distances(tree: &[Vec<usize>], s: usize) -> Vec<usize> {
+        let mut dist = vec![std::usize::MAX; tree.len()];
+        dist[s] = 0;
+        let mut stack = vec![s];
+        while let Some(v) = stack.pop() {
+            for &u in tree[v].iter() {
+                if dist[u] == std::usize::MAX {
+                    dist[u] = dist[v] + 1;
+                    stack.push(u);
+                }
+            }
+        }
+        dist
+    }
+
+    fn random_tree(n: usize, seed: u64) -> Vec<Vec<usize>> {
+        let mut rng = XorShift64::new(seed);
+        let mut tree = vec![vec![]; n];
+        for v in 1..n {
+            let p = rng.next_range(0, v as u64) as usize;
+            tree[v].push(p);
+            tree[p].push(v);
+        }
+        tree
+    }
+
+    #[test]
+    fn sum_of_distances() {
+        for n in 1..40 {
+            let tree = random_tree(n, n as u64);
+
+            // (the number of vertices, the sum of the distances to them from one edge above)
+            let res = reroot(
+                &tree,
+                (0, 0),
+                |a: &(usize, usize), b: &(usize, usize)| (a.0 + b.0, a.1 + b.1),
+                |a, _| (a.0 + 1, a.1 + a.0 + 1),
+            );
+
+            for (v, &(size, sum)) in res.iter().enumerate() {
+                assert_eq!(n, size);
+                assert_eq!(distances(&tree, v).iter().sum::<usize>(), sum - n);
+            }
+        }
+    }
+
+    #[test]
+    fn eccentricity() {
+        for n in 1..40 {
+            let tree = random_tree(n, 100 + n as u64);
+            let res = reroot(&tree, -1, |a: &i64, b: &i64| *a.max(b), |a, _| a + 1);
+
+            for (v, &h) in res.iter().enumerate() {
+                assert_eq!(*distances(&tree, v).iter().max().unwrap() as i64, h);
+            }
+        }
+        assert!(reroot(&[], 0, |a: &i32, b: &i32| a + b, |a, _| *a).is_empty());
+    }
+}
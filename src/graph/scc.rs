@@ -0,0 +1,224 @@
+//! Strongly connected components.
+
+use super::Graph;
+
+const NONE: usize = std::usize::MAX;
+
+/// Decomposes a directed graph into strongly connected components, using Tarjan's algorithm.
+/// Returns the number of components and the component id of each vertex.
+/// The ids are in topological order: every edge goes from a lower id to a higher or equal one.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{scc::scc, Graph};
+///
+/// let mut g = Graph::new(4);
+/// g.add_edge(1, 2, ());
+/// g.add_edge(2, 1, ());
+/// g.add_edge(2, 3, ());
+/// g.add_edge(0, 1, ());
+///
+/// assert_eq!((3, vec![0, 1, 1, 2]), scc(&g));
+/// ```
+pub fn scc(graph: &Graph<()>) -> (usize, Vec<usize>) {
+    let n = graph.len();
+    let mut ord = vec![NONE; n];
+    let mut low = vec![0; n];
+    let mut comp = vec![NONE; n];
+    let mut stack = vec![];
+    let mut count = 0;
+    let mut comps = 0;
+
+    for s in 0..n {
+        if ord[s] != NONE {
+            continue;
+        }
+
+        // (vertex, index of the next edge)
+        let mut dfs = vec![(s, 0)];
+        ord[s] = count;
+        low[s] = count;
+        count += 1;
+        stack.push(s);
+
+        while let Some(&mut (v, ref mut e)) = dfs.last_mut() {
+            if let Some(&(u, ())) = graph.neighbors(v).get(*e) {
+                *e += 1;
+                if ord[u] == NONE {
+                    ord[u] = count;
+                    low[u] = count;
+                    count += 1;
+                    stack.push(u);
+                    dfs.push((u, 0));
+                } else if comp[u] == NONE {
+                    low[v] = low[v].min(ord[u]);
+                }
+                continue;
+            }
+
+            dfs.pop();
+            if let Some(&(p, _)) = dfs.last() {
+                low[p] = low[p].min(low[v]);
+            }
+            if low[v] == ord[v] {
+                loop {
+                    let u = stack.pop().unwrap();
+                    comp[u] = comps;
+                    if u == v {
+                        break;
+                    }
+                }
+                comps += 1;
+            }
+        }
+    }
+
+    // Tarjan's algorithm finds the components in reverse topological order
+    (comps, comp.into_iter().map(|c| comps - 1 - c).collect())
+}
+
+/// Builds the condensation, the DAG with a vertex per component of [`scc`].
+/// Each edge between components appears once, and there are no self-loops.
+/// Consumes O(n + m log m) time.
+///
+/// [`scc`]: fn.scc.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{scc::condensation, Graph};
+///
+/// let mut g = Graph::new(4);
+/// g.add_edge(1, 2, ());
+/// g.add_edge(2, 1, ());
+/// g.add_edge(2, 3, ());
+/// g.add_edge(1, 3, ());
+/// g.add_edge(0, 2, ());
+///
+/// let dag = condensation(&g);
+/// assert_eq!(3, dag.len());
+/// assert_eq!(&[(2, ())], dag.neighbors(1));
+/// ```
+pub fn condensation(graph: &Graph<()>) -> Graph<()> {
+    let (comps, comp) = scc(graph);
+    let mut edges = vec![];
+    for v in 0..graph.len() {
+        for &(u, ()) in graph.neighbors(v) {
+            if comp[v] != comp[u] {
+                edges.push((comp[v], comp[u]));
+            }
+        }
+    }
+    edges.sort();
+    edges.dedup();
+
+    let mut dag = Graph::new(comps);
+    for (a, b) in edges {
+        dag.add_edge(a, b, ());
+    }
+    dag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn from_edges(n: usize, edges: &[(usize, usize)]) -> Graph<()> {
+        let mut g = Graph::new(n);
+        for &(u, v) in edges {
+            g.add_edge(u, v, ());
+        }
+        g
+    }
+
+    #[test]
+    fn tarjan_example() {
+        // the example of eight vertices, 0-indexed
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (3, 1),
+            (3, 2),
+            (3, 4),
+            (4, 3),
+            (4, 5),
+            (5, 2),
+            (5, 6),
+            (6, 5),
+            (7, 4),
+            (7, 6),
+            (7, 7),
+        ];
+        let g = from_edges(8, &edges);
+
+        assert_eq!((4, vec![3, 3, 3, 1, 1, 2, 2, 0]), scc(&g));
+
+        let dag = condensation(&g);
+        let dag_edges: Vec<Vec<usize>> = (0..4)
+            .map(|c| dag.neighbors(c).iter().map(|&(d, ())| d).collect())
+            .collect();
+        assert_eq!(vec![vec![1, 2], vec![2, 3], vec![3], vec![]], dag_edges);
+    }
+
+    #[test]
+    fn dag() {
+        let g = from_edges(5, &[(0, 2), (2, 1), (1, 4), (0, 3), (3, 4)]);
+        let (comps, comp) = scc(&g);
+        assert_eq!(5, comps);
+        let mut sorted = comp.clone();
+        sorted.sort();
+        assert_eq!(vec![0, 1, 2, 3, 4], sorted);
+        for v in 0..5 {
+            assert!(g.neighbors(v).iter().all(|&(u, ())| comp[v] < comp[u]));
+        }
+        assert_eq!(5, condensation(&g).len());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(526);
+        for _ in 0..100 {
+            let n = rng.next_range(1, 10) as usize;
+            let edges: Vec<_> = (0..rng.next_range(0, 20))
+                .map(|_| {
+                    (
+                        rng.next_range(0, n as u64) as usize,
+                        rng.next_range(0, n as u64) as usize,
+                    )
+                })
+                .collect();
+            let g = from_edges(n, &edges);
+
+            // reach[u][v] by transitive closure
+            let mut reach = vec![vec![false; n]; n];
+            for (v, row) in reach.iter_mut().enumerate() {
+                row[v] = true;
+            }
+            for &(u, v) in &edges {
+                reach[u][v] = true;
+            }
+            for k in 0..n {
+                for i in 0..n {
+                    for j in 0..n {
+                        reach[i][j] = reach[i][j] || (reach[i][k] && reach[k][j]);
+                    }
+                }
+            }
+
+            let (comps, comp) = scc(&g);
+            assert!(comp.iter().all(|&c| c < comps));
+            for u in 0..n {
+                for v in 0..n {
+                    assert_eq!(reach[u][v] && reach[v][u], comp[u] == comp[v]);
+                }
+            }
+            for &(u, v) in &edges {
+                assert!(comp[u] <= comp[v]);
+            }
+        }
+    }
+}
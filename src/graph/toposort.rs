@@ -0,0 +1,134 @@
+//! Topological sort.
+
+use super::Graph;
+use std::collections::VecDeque;
+
+/// Sorts the vertices of a directed graph topologically, using Kahn's algorithm.
+/// Returns None if the graph has a cycle.
+/// Consumes O(n + m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::{toposort::toposort, Graph};
+///
+/// let mut g = Graph::new(3);
+/// g.add_edge(2, 0, ());
+/// g.add_edge(0, 1, ());
+/// assert_eq!(Some(vec![2, 0, 1]), toposort(&g));
+///
+/// g.add_edge(1, 2, ());
+/// assert_eq!(None, toposort(&g));
+/// ```
+pub fn toposort(graph: &Graph<()>) -> Option<Vec<usize>> {
+    let n = graph.len();
+    let mut indeg = vec![0; n];
+    for v in 0..n {
+        for &(u, ()) in graph.neighbors(v) {
+            indeg[u] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| indeg[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(v) = queue.pop_front() {
+        order.push(v);
+        for &(u, ()) in graph.neighbors(v) {
+            indeg[u] -= 1;
+            if indeg[u] == 0 {
+                queue.push_back(u);
+            }
+        }
+    }
+
+    // the vertices on or after a cycle never reach in-degree 0
+    if order.len() == n {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the directed graph has a cycle, using [`toposort`].
+/// Consumes O(n + m) time.
+///
+/// [`toposort`]: fn.toposort.html
+pub fn has_cycle(graph: &Graph<()>) -> bool {
+    toposort(graph).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn from_edges(n: usize, edges: &[(usize, usize)]) -> Graph<()> {
+        let mut g = Graph::new(n);
+        for &(u, v) in edges {
+            g.add_edge(u, v, ());
+        }
+        g
+    }
+
+    fn is_topological(g: &Graph<()>, order: &[usize]) -> bool {
+        let mut pos = vec![0; g.len()];
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        (0..g.len()).all(|v| g.neighbors(v).iter().all(|&(u, ())| pos[v] < pos[u]))
+    }
+
+    #[test]
+    fn chain() {
+        let g = from_edges(5, &[(3, 4), (2, 3), (1, 2), (0, 1)]);
+        assert_eq!(Some(vec![0, 1, 2, 3, 4]), toposort(&g));
+        assert!(!has_cycle(&g));
+    }
+
+    #[test]
+    fn branching() {
+        let g = from_edges(6, &[(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]);
+        let order = toposort(&g).unwrap();
+        assert_eq!(6, order.len());
+        assert!(is_topological(&g, &order));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Some(vec![]), toposort(&Graph::new(0)));
+        assert_eq!(Some(vec![0, 1, 2]), toposort(&Graph::new(3)));
+    }
+
+    #[test]
+    fn cycles() {
+        assert!(has_cycle(&from_edges(1, &[(0, 0)])));
+        assert!(has_cycle(&from_edges(3, &[(0, 1), (1, 1), (1, 2)])));
+
+        // a strongly connected component of 50 vertices behind a DAG prefix
+        let mut edges: Vec<_> = (0..50).map(|i| (10 + i, 10 + (i + 1) % 50)).collect();
+        edges.extend((0..10).map(|i| (i, i + 1)));
+        edges.push((25, 3));
+        let g = from_edges(60, &edges);
+        assert!(has_cycle(&g));
+    }
+
+    #[test]
+    fn random_dag() {
+        let mut rng = XorShift64::new(525);
+        for _ in 0..50 {
+            // edges go forward in a hidden permutation
+            let n = rng.next_range(1, 20) as usize;
+            let perm = rng.random_permutation(n);
+            let edges: Vec<_> = (0..rng.next_range(0, 40))
+                .map(|_| {
+                    let a = rng.next_range(0, n as u64) as usize;
+                    let b = rng.next_range(0, n as u64) as usize;
+                    (perm[a.min(b)], perm[a.max(b)])
+                })
+                .filter(|&(u, v)| u != v)
+                .collect();
+            let g = from_edges(n, &edges);
+            assert!(is_topological(&g, &toposort(&g).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,508 @@
+//! Tree algorithms.
+
+use crate::data_structures::segtree::{Monoid, SegTree};
+
+const NONE: usize = std::usize::MAX;
+
+/// Computes the diameter of a tree, using DFS twice as `weighted_diameter` with unit weights.
+/// Returns the number of edges on the diameter and its endpoints.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::tree::diameter;
+///
+/// // 0 - 1 - 2
+/// //     |
+/// //     3 - 4
+/// let adj = vec![vec![1], vec![0, 2, 3], vec![1], vec![1, 4], vec![3]];
+///
+/// assert_eq!((3, 4, 0), diameter(5, &adj));
+/// ```
+pub fn diameter(n: usize, adj: &[Vec<usize>]) -> (usize, usize, usize) {
+    let weighted: Vec<Vec<(usize, i64)>> = adj
+        .iter()
+        .map(|vs| vs.iter().map(|&v| (v, 1)).collect())
+        .collect();
+    let (len, u, v) = weighted_diameter(n, &weighted);
+    (len as usize, u, v)
+}
+
+/// Computes the diameter of a tree with non-negative edge weights, using DFS twice.
+/// Returns the length of the diameter and its endpoints.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::tree::weighted_diameter;
+///
+/// // 0 -5- 1 -2- 2
+/// //       |
+/// //       4
+/// //       |
+/// //       3
+/// let adj = vec![vec![(1, 5)], vec![(0, 5), (2, 2), (3, 4)], vec![(1, 2)], vec![(1, 4)]];
+///
+/// assert_eq!((9, 3, 0), weighted_diameter(4, &adj));
+/// ```
+pub fn weighted_diameter(n: usize, adj: &[Vec<(usize, i64)>]) -> (i64, usize, usize) {
+    assert!(n > 0 && adj.len() == n);
+
+    let u = farthest(adj, 0).1;
+    let (len, v) = farthest(adj, u);
+    (len, u, v)
+}
+
+// Returns the distance to the farthest vertex from s, and the vertex.
+fn farthest(adj: &[Vec<(usize, i64)>], s: usize) -> (i64, usize) {
+    let mut dist = vec![None; adj.len()];
+    dist[s] = Some(0);
+
+    let mut stack = vec![s];
+    while let Some(v) = stack.pop() {
+        let d = dist[v].unwrap();
+        for &(u, w) in adj[v].iter() {
+            if dist[u].is_none() {
+                dist[u] = Some(d + w);
+                stack.push(u);
+            }
+        }
+    }
+
+    dist.into_iter()
+        .enumerate()
+        .filter_map(|(v, d)| d.map(|d| (d, v)))
+        .fold((0, s), |acc, x| if x.0 > acc.0 { x } else { acc })
+}
+
+/// Finds a centroid of a tree, a vertex whose removal leaves components of at most n / 2 vertices.
+/// If there are two centroids, returns one of them.
+/// Consumes O(n) time.
+///
+/// # Panics
+/// Panics if the tree is empty.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::tree::centroid;
+///
+/// // 0 - 1 - 2 - 3 - 4
+/// let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+///
+/// assert_eq!(2, centroid(5, &adj));
+/// ```
+pub fn centroid(n: usize, adj: &[Vec<usize>]) -> usize {
+    assert!(n > 0 && adj.len() == n);
+
+    // preorder from 0
+    let mut parent = vec![NONE; n];
+    let mut order = Vec::with_capacity(n);
+    let mut stack = vec![0];
+    while let Some(v) = stack.pop() {
+        order.push(v);
+        for &u in &adj[v] {
+            if u != parent[v] {
+                parent[u] = v;
+                stack.push(u);
+            }
+        }
+    }
+
+    let mut size = vec![1; n];
+    for &v in order.iter().rev() {
+        if parent[v] != NONE {
+            size[parent[v]] += size[v];
+        }
+    }
+
+    // the first vertex in preorder whose subtrees are all small enough
+    *order
+        .iter()
+        .find(|&&v| {
+            n - size[v] <= n / 2 && adj[v].iter().all(|&u| u == parent[v] || size[u] <= n / 2)
+        })
+        .unwrap()
+}
+
+/// Path queries on a tree, using the heavy-light decomposition and segment trees.
+/// Each vertex and each edge holds a value, initially the identity.
+/// The operation is required to be commutative, since a path is aggregated out of order.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::data_structures::segtree::SumMonoidI64;
+/// use tklib::graph::tree::TreePathQuery;
+///
+/// // 0 - 1 - 2
+/// //     |
+/// //     3
+/// let tree = vec![vec![1], vec![0, 2, 3], vec![1], vec![1]];
+/// let mut tpq = TreePathQuery::<SumMonoidI64>::new(&tree, 0);
+///
+/// for v in 0..4 {
+///     tpq.update_vertex(v, 10 * v as i64);
+/// }
+/// tpq.update_edge(1, 2, 1);
+/// tpq.update_edge(3, 1, 2);
+///
+/// assert_eq!(1, tpq.lca(2, 3));
+/// assert_eq!(10 + 20 + 30 + 1 + 2, tpq.query_path(2, 3));
+/// assert_eq!(10, tpq.query_path(0, 1));
+/// ```
+#[derive(Clone)]
+pub struct TreePathQuery<M: Monoid> {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    pos: Vec<usize>,
+    vertex_values: SegTree<M>,
+    // the value of an edge is stored at its deeper endpoint
+    edge_values: SegTree<M>,
+}
+
+impl<M: Monoid> TreePathQuery<M> {
+    /// Decomposes a tree rooted at `root`.
+    /// `tree` contains each edge in both directions.
+    /// Consumes O(n) time.
+    pub fn new(tree: &[Vec<usize>], root: usize) -> Self {
+        let n = tree.len();
+        let mut parent = vec![NONE; n];
+        let mut depth = vec![0; n];
+
+        // preorder
+        let mut order = Vec::with_capacity(n);
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            order.push(v);
+            for &u in &tree[v] {
+                if u != parent[v] {
+                    parent[u] = v;
+                    depth[u] = depth[v] + 1;
+                    stack.push(u);
+                }
+            }
+        }
+        assert_eq!(n, order.len(), "The graph is required to be a tree");
+
+        let mut size = vec![1; n];
+        let mut heavy = vec![NONE; n];
+        for &v in order.iter().rev() {
+            let p = parent[v];
+            if p != NONE {
+                size[p] += size[v];
+                if heavy[p] == NONE || size[v] > size[heavy[p]] {
+                    heavy[p] = v;
+                }
+            }
+        }
+
+        // numbers the vertices so that every heavy path is contiguous
+        let mut head = vec![root; n];
+        let mut pos = vec![0; n];
+        let mut count = 0;
+        let mut stack = vec![root];
+        while let Some(h) = stack.pop() {
+            let mut v = h;
+            loop {
+                pos[v] = count;
+                count += 1;
+                for &u in &tree[v] {
+                    if u != parent[v] && u != heavy[v] {
+                        head[u] = u;
+                        stack.push(u);
+                    }
+                }
+                if heavy[v] == NONE {
+                    break;
+                }
+                head[heavy[v]] = head[v];
+                v = heavy[v];
+            }
+        }
+
+        Self {
+            parent,
+            depth,
+            head,
+            pos,
+            vertex_values: SegTree::new(n),
+            edge_values: SegTree::new(n),
+        }
+    }
+
+    /// Returns the lowest common ancestor of u and v.
+    /// Consumes O(log n) time.
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]];
+        }
+        if self.depth[u] < self.depth[v] {
+            u
+        } else {
+            v
+        }
+    }
+
+    /// Sets the value of the vertex v.
+    /// Consumes O(log n) time.
+    pub fn update_vertex(&mut self, v: usize, val: M::S) {
+        self.vertex_values.set(self.pos[v], val);
+    }
+
+    /// Sets the value of the edge between u and v.
+    /// Consumes O(log n) time.
+    ///
+    /// # Panics
+    /// Panics if u and v are not adjacent.
+    pub fn update_edge(&mut self, u: usize, v: usize, val: M::S) {
+        let child = if self.parent[v] == u {
+            v
+        } else {
+            assert_eq!(v, self.parent[u], "Not an edge");
+            u
+        };
+        self.edge_values.set(self.pos[child], val);
+    }
+
+    /// Returns the product of the values of the vertices and the edges on the path between u and v.
+    /// Consumes O(log^2 n) time.
+    pub fn query_path(&self, mut u: usize, mut v: usize) -> M::S {
+        let mut acc = M::identity();
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            let (l, r) = (self.pos[self.head[u]], self.pos[u] + 1);
+            acc = M::op(&acc, &self.vertex_values.prod(l, r));
+            acc = M::op(&acc, &self.edge_values.prod(l, r));
+            u = self.parent[self.head[u]];
+        }
+
+        let (l, r) = if self.pos[u] < self.pos[v] {
+            (self.pos[u], self.pos[v] + 1)
+        } else {
+            (self.pos[v], self.pos[u] + 1)
+        };
+        acc = M::op(&acc, &self.vertex_values.prod(l, r));
+        M::op(&acc, &self.edge_values.prod(l + 1, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn to_adj(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+        let mut adj = vec![vec![]; n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        adj
+    }
+
+    #[test]
+    fn path() {
+        for n in 1..10 {
+            let edges: Vec<_> = (1..n).map(|v| (v - 1, v)).collect();
+            let (len, u, v) = diameter(n, &to_adj(n, &edges));
+
+            assert_eq!(n - 1, len);
+            assert_eq!((0, n - 1), (u.min(v), u.max(v)));
+        }
+    }
+
+    #[test]
+    fn star() {
+        let n = 8;
+        let edges: Vec<_> = (1..n).map(|v| (0, v)).collect();
+        let (len, u, v) = diameter(n, &to_adj(n, &edges));
+
+        assert_eq!(2, len);
+        assert!(u != 0 && v != 0 && u != v);
+    }
+
+    #[test]
+    fn binary_tree() {
+        // complete binary tree of depth 4, rooted at 0
+        let n = 31;
+        let edges: Vec<_> = (1..n).map(|v| ((v - 1) / 2, v)).collect();
+        let (len, u, v) = diameter(n, &to_adj(n, &edges));
+
+        assert_eq!(8, len);
+        assert!(u >= 15 && v >= 15);
+    }
+
+    #[test]
+    fn weighted() {
+        // brute force over all pairs on a random tree
+        let n = 40;
+        let mut rng = XorShift64::new(9);
+        let mut adj = vec![vec![]; n];
+        for v in 1..n {
+            let p = rng.next_range(0, v as u64) as usize;
+            let w = rng.next_range(0, 100) as i64;
+            adj[v].push((p, w));
+            adj[p].push((v, w));
+        }
+
+        let naive = (0..n).map(|s| farthest(&adj, s).0).max().unwrap();
+        let (len, u, v) = weighted_diameter(n, &adj);
+
+        assert_eq!(naive, len);
+        assert_eq!((len, v), farthest(&adj, u));
+    }
+
+    // Returns the vertices and the edges (as the deeper endpoints) on the path, by walking up.
+    fn naive_path(
+        parent: &[usize],
+        depth: &[usize],
+        mut u: usize,
+        mut v: usize,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let (mut vertices, mut edges) = (vec![], vec![]);
+        while u != v {
+            if depth[u] < depth[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            vertices.push(u);
+            edges.push(u);
+            u = parent[u];
+        }
+        vertices.push(u);
+        (vertices, edges)
+    }
+
+    #[test]
+    fn path_queries() {
+        use crate::data_structures::segtree::{MaxMonoidI64, SumMonoidI64};
+
+        let mut rng = XorShift64::new(400);
+        for &n in &[1, 2, 10, 60] {
+            // a random tree rooted at 0, with the parents preceding the children
+            let mut parent = vec![NONE; n];
+            let mut depth = vec![0; n];
+            let mut tree = vec![vec![]; n];
+            for v in 1..n {
+                let p = rng.next_range(0, v as u64) as usize;
+                parent[v] = p;
+                depth[v] = depth[p] + 1;
+                tree[v].push(p);
+                tree[p].push(v);
+            }
+            let root = rng.next_range(0, n as u64) as usize;
+
+            // the result does not depend on the root except for the LCA
+            let mut sum = TreePathQuery::<SumMonoidI64>::new(&tree, 0);
+            let mut max = TreePathQuery::<MaxMonoidI64>::new(&tree, root);
+            let mut vertex_values = vec![0; n];
+            let mut edge_values = vec![0; n];
+            for v in 0..n {
+                vertex_values[v] = rng.next_range(0, 100) as i64;
+                sum.update_vertex(v, vertex_values[v]);
+                max.update_vertex(v, vertex_values[v]);
+                if v > 0 {
+                    edge_values[v] = rng.next_range(0, 100) as i64;
+                    sum.update_edge(parent[v], v, edge_values[v]);
+                    max.update_edge(parent[v], v, edge_values[v]);
+                }
+            }
+            for _ in 0..300 {
+                let val = rng.next_range(0, 2001) as i64 - 1000;
+                match rng.next_range(0, 3) {
+                    0 => {
+                        let v = rng.next_range(0, n as u64) as usize;
+                        vertex_values[v] = val;
+                        sum.update_vertex(v, val);
+                        max.update_vertex(v, val);
+                    }
+                    1 if n > 1 => {
+                        let v = rng.next_range(1, n as u64) as usize;
+                        edge_values[v] = val;
+                        sum.update_edge(v, parent[v], val);
+                        max.update_edge(parent[v], v, val);
+                    }
+                    _ => {
+                        let u = rng.next_range(0, n as u64) as usize;
+                        let v = rng.next_range(0, n as u64) as usize;
+                        let (vs, es) = naive_path(&parent, &depth, u, v);
+                        let values = vs
+                            .iter()
+                            .map(|&x| vertex_values[x])
+                            .chain(es.iter().map(|&x| edge_values[x]));
+
+                        assert_eq!(values.clone().sum::<i64>(), sum.query_path(u, v));
+                        assert_eq!(values.max().unwrap(), max.query_path(u, v));
+                        assert_eq!(*vs.last().unwrap(), sum.lca(u, v));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn centroid_shapes() {
+        // path: the middle vertex
+        for n in 1..12 {
+            let edges: Vec<_> = (1..n).map(|v| (v - 1, v)).collect();
+            let c = centroid(n, &to_adj(n, &edges));
+            assert!(c == n / 2 || c + 1 == n - n / 2, "{} {}", n, c);
+        }
+
+        // star: the center
+        let edges: Vec<_> = (1..8).map(|v| (v, 0)).collect();
+        assert_eq!(0, centroid(8, &to_adj(8, &edges)));
+        let edges: Vec<_> = (0..7).map(|v| (v, 7)).collect();
+        assert_eq!(7, centroid(8, &to_adj(8, &edges)));
+
+        // bamboo with shuffled labels: the median along the path
+        let labels = [4, 8, 0, 6, 2, 7, 1, 3, 5];
+        let edges: Vec<_> = labels.windows(2).map(|w| (w[0], w[1])).collect();
+        assert_eq!(labels[4], centroid(9, &to_adj(9, &edges)));
+    }
+
+    #[test]
+    fn centroid_random() {
+        let mut rng = XorShift64::new(404);
+        for _ in 0..100 {
+            let n = rng.next_range(1, 30) as usize;
+            let edges: Vec<_> = (1..n)
+                .map(|v| (rng.next_range(0, v as u64) as usize, v))
+                .collect();
+            let adj = to_adj(n, &edges);
+            let c = centroid(n, &adj);
+
+            // the largest component after removing c, by a search from each neighbor
+            let largest = adj[c]
+                .iter()
+                .map(|&s| {
+                    let mut visited = vec![false; n];
+                    visited[c] = true;
+                    visited[s] = true;
+                    let mut stack = vec![s];
+                    let mut count = 0;
+                    while let Some(v) = stack.pop() {
+                        count += 1;
+                        for &u in &adj[v] {
+                            if !visited[u] {
+                                visited[u] = true;
+                                stack.push(u);
+                            }
+                        }
+                    }
+                    count
+                })
+                .max()
+                .unwrap_or(0);
+            assert!(largest <= n / 2);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! 2-SAT.
+
+use super::scc::scc;
+use super::Graph;
+
+/// 2-SAT solver, using strongly connected components of the implication graph.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::graph::two_sat::TwoSat;
+///
+/// // (x0 or x1) and (!x0 or x1) and (!x1 or !x0)
+/// let mut ts = TwoSat::new(2);
+/// ts.add_clause(0, true, 1, true);
+/// ts.add_clause(0, false, 1, true);
+/// ts.add_clause(1, false, 0, false);
+///
+/// assert_eq!(Some(vec![false, true]), ts.solve());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TwoSat {
+    n: usize,
+    graph: Graph<()>,
+}
+
+impl TwoSat {
+    /// Constructs a new instance with n variables.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            graph: Graph::new(2 * n),
+        }
+    }
+
+    // the vertex of the literal (x_i == f)
+    fn literal(i: usize, f: bool) -> usize {
+        2 * i + if f { 0 } else { 1 }
+    }
+
+    /// Adds the clause `(x_i == fi) or (x_j == fj)`.
+    pub fn add_clause(&mut self, i: usize, fi: bool, j: usize, fj: bool) {
+        assert!(i < self.n && j < self.n);
+
+        self.graph
+            .add_edge(Self::literal(i, !fi), Self::literal(j, fj), ());
+        self.graph
+            .add_edge(Self::literal(j, !fj), Self::literal(i, fi), ());
+    }
+
+    /// Returns an assignment satisfying all the clauses, or None if there is none.
+    /// Consumes O(n + m) time.
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        let (_, comp) = scc(&self.graph);
+
+        (0..self.n)
+            .map(|i| {
+                let (t, f) = (comp[Self::literal(i, true)], comp[Self::literal(i, false)]);
+                if t == f {
+                    None
+                } else {
+                    Some(t > f)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn satisfies(clauses: &[(usize, bool, usize, bool)], x: &[bool]) -> bool {
+        clauses
+            .iter()
+            .all(|&(i, fi, j, fj)| x[i] == fi || x[j] == fj)
+    }
+
+    #[test]
+    fn satisfiable() {
+        // (x0 or !x1) and (!x0 or x2) and (x1 or x2) and (!x2 or x1) and (x1 or x1)
+        let clauses = [
+            (0, true, 1, false),
+            (0, false, 2, true),
+            (1, true, 2, true),
+            (2, false, 1, true),
+            (1, true, 1, true),
+        ];
+
+        let mut ts = TwoSat::new(3);
+        clauses
+            .iter()
+            .for_each(|&(i, fi, j, fj)| ts.add_clause(i, fi, j, fj));
+
+        let x = ts.solve().unwrap();
+        assert!(satisfies(&clauses, &x));
+        assert_eq!(vec![true, true, true], x);
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        // x and !x
+        let mut ts = TwoSat::new(1);
+        ts.add_clause(0, true, 0, true);
+        ts.add_clause(0, false, 0, false);
+
+        assert_eq!(None, ts.solve());
+    }
+
+    #[test]
+    fn brute_force() {
+        let mut rng = XorShift64::new(5);
+        let mut rand = |m: u64| rng.next_range(0, m);
+
+        for _ in 0..200 {
+            let n = rand(5) as usize + 1;
+            let clauses: Vec<_> = (0..rand(12))
+                .map(|_| {
+                    let i = rand(n as u64) as usize;
+                    let j = rand(n as u64) as usize;
+                    (i, rand(2) == 0, j, rand(2) == 0)
+                })
+                .collect();
+
+            let mut ts = TwoSat::new(n);
+            clauses
+                .iter()
+                .for_each(|&(i, fi, j, fj)| ts.add_clause(i, fi, j, fj));
+
+            let exists = (0..1 << n).any(|mask: usize| {
+                let x: Vec<bool> = (0..n).map(|i| mask >> i & 1 == 1).collect();
+                satisfies(&clauses, &x)
+            });
+            match ts.solve() {
+                Some(x) => assert!(satisfies(&clauses, &x)),
+                None => assert!(!exists),
+            }
+        }
+    }
+}
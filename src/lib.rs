@@ -1,5 +1,10 @@
 //! Competitive Programming Library.
 
+#![forbid(unsafe_code)]
+
 pub mod data_structures;
+pub mod geometry;
+pub mod graph;
 pub mod math;
+pub mod string;
 pub mod utils;
@@ -1,2 +1,30 @@
+pub(crate) mod arith;
+
+pub mod berlekamp_massey;
+pub mod bostan_mori;
 pub mod combination;
+pub mod combinatorics;
+pub mod conv;
+pub mod crt;
+pub mod discrete_log;
+pub mod dynmodint;
+pub mod factor;
+pub mod fibonacci;
+pub mod gcd;
+pub mod interpolation;
+pub mod iroot;
+pub mod linalg;
+pub mod lucas;
+pub mod matrix;
+pub mod mobius;
 pub mod modint;
+pub mod ntt;
+pub mod num;
+pub mod partition;
+pub mod poly;
+pub mod power_sum;
+pub mod prime;
+pub mod sieve;
+pub mod stirling;
+pub mod totient;
+pub mod util;
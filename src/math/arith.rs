@@ -0,0 +1,18 @@
+//! Integer arithmetic shared within the crate.
+
+/// Computes a * b mod m without overflow.
+pub(crate) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul() {
+        let m = std::u64::MAX - 58;
+        assert_eq!(1, mul_mod(m - 1, m - 1, m));
+        assert_eq!(0, mul_mod(m, 12_345, m));
+    }
+}
@@ -0,0 +1,132 @@
+//! Berlekamp-Massey algorithm.
+
+use super::modint::{ModInt, ModTrait};
+
+/// Finds the shortest linear recurrence generating `seq`.
+/// Returns `[c_1, ..., c_k]` such that `seq[i] = c_1 * seq[i - 1] + ... + c_k * seq[i - k]`.
+/// Consumes O(n^2) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{berlekamp_massey::berlekamp_massey, modint::ModInt998244353 as Mint};
+///
+/// let fib: Vec<Mint> = [0, 1, 1, 2, 3, 5, 8].iter().map(|&x| Mint::new(x)).collect();
+/// assert_eq!(vec![Mint::new(1), Mint::new(1)], berlekamp_massey(&fib));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn berlekamp_massey<Mod: ModTrait>(seq: &[ModInt<Mod>]) -> Vec<ModInt<Mod>> {
+    let n = seq.len();
+    let zero = ModInt::new(0);
+
+    let mut c = vec![zero; n + 1];
+    let mut b = vec![zero; n + 1];
+    c[0] = ModInt::new(1);
+    b[0] = ModInt::new(1);
+
+    let mut len = 0;
+    let mut shift = 0;
+    let mut last = ModInt::new(1);
+
+    for i in 0..n {
+        shift += 1;
+
+        let d = (1..=len).fold(seq[i], |acc, j| acc + c[j] * seq[i - j]);
+        if d == zero {
+            continue;
+        }
+
+        let t = c.clone();
+        let coef = d / last;
+        for j in shift..=n {
+            c[j] -= coef * b[j - shift];
+        }
+
+        if 2 * len > i {
+            continue;
+        }
+
+        len = i + 1 - len;
+        b = t;
+        last = d;
+        shift = 0;
+    }
+
+    c[1..=len].iter().map(|&x| -x).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::ModInt998244353;
+    use crate::utils::random::XorShift64;
+
+    type Mint = ModInt998244353;
+
+    fn extend(seq: &[Mint], rec: &[Mint], n: usize) -> Vec<Mint> {
+        let mut v = seq[..rec.len()].to_vec();
+        while v.len() < n {
+            let i = v.len();
+            let x = rec
+                .iter()
+                .enumerate()
+                .fold(Mint::new(0), |acc, (j, &c)| acc + c * v[i - 1 - j]);
+            v.push(x);
+        }
+        v
+    }
+
+    #[test]
+    fn fibonacci() {
+        let fib: Vec<Mint> = std::iter::successors(Some((0, 1)), |&(a, b)| Some((b, a + b)))
+            .map(|(a, _)| Mint::new(a))
+            .take(20)
+            .collect();
+
+        let rec = berlekamp_massey(&fib);
+        assert_eq!(vec![Mint::new(1), Mint::new(1)], rec);
+        assert_eq!(fib, extend(&fib, &rec, 20));
+    }
+
+    #[test]
+    fn geometric() {
+        let r = Mint::new(5);
+        let seq: Vec<Mint> = (0..10).map(|i| Mint::new(3) * r.pow(i)).collect();
+
+        assert_eq!(vec![r], berlekamp_massey(&seq));
+    }
+
+    #[test]
+    fn degree_three() {
+        // a_i = 2 a_{i-1} + 0 a_{i-2} + 7 a_{i-3}
+        let rec = vec![Mint::new(2), Mint::new(0), Mint::new(7)];
+        let init = vec![Mint::new(1), Mint::new(4), Mint::new(9)];
+        let seq = extend(&init, &rec, 30);
+
+        let found = berlekamp_massey(&seq[..10]);
+        assert_eq!(rec, found);
+        assert_eq!(seq, extend(&seq, &found, 30));
+    }
+
+    #[test]
+    fn zeros() {
+        assert!(berlekamp_massey::<crate::math::modint::Mod998244353>(&[]).is_empty());
+        assert!(berlekamp_massey(&[Mint::new(0); 5]).is_empty());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(11);
+        for _ in 0..50 {
+            let k = rng.next_range(1, 12) as usize;
+            let rec: Vec<Mint> = (0..k).map(|_| Mint::new(rng.next())).collect();
+            let init: Vec<Mint> = (0..k).map(|_| Mint::new(rng.next())).collect();
+            let seq = extend(&init, &rec, 100);
+
+            // 2k terms determine a recurrence of order at most k
+            let found = berlekamp_massey(&seq[..2 * k]);
+            assert!(found.len() <= k);
+            assert_eq!(seq, extend(&seq, &found, 100));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Bostan-Mori algorithm.
+
+use super::modint::{ModInt, ModTrait};
+use super::poly::Polynomial;
+
+/// Computes the coefficient of x^n in the formal power series P(x) / Q(x),
+/// halving n by multiplying both sides by Q(-x).
+/// Consumes O(d log d log n) time where d is the larger degree of P and Q.
+///
+/// # Panics
+/// Panics if the constant term of Q is 0.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{bostan_mori::nth_term, modint::ModInt998244353 as Mint, poly::Polynomial};
+///
+/// // the Fibonacci numbers x / (1 - x - x^2)
+/// let p = Polynomial::from_coefficients(vec![Mint::new(0), Mint::new(1)]);
+/// let q = Polynomial::from_coefficients(vec![Mint::new(1), -Mint::new(1), -Mint::new(1)]);
+///
+/// assert_eq!(Mint::new(55), nth_term(&p, &q, 10));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn nth_term<Mod: ModTrait>(
+    p: &Polynomial<Mod>,
+    q: &Polynomial<Mod>,
+    mut n: u64,
+) -> ModInt<Mod> {
+    assert_ne!(
+        ModInt::new(0),
+        q.coef(0),
+        "The constant term of Q is required to be nonzero"
+    );
+
+    let mut p = p.clone();
+    let mut q = q.clone();
+
+    while n > 0 {
+        let q_neg = Polynomial::from_coefficients(
+            q.coefficients()
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| if i % 2 == 0 { c } else { -c })
+                .collect(),
+        );
+
+        // P(x) Q(-x) / Q(x) Q(-x), where the denominator is even
+        let u = &p * &q_neg;
+        let v = &q * &q_neg;
+        p = take_every_other(&u, (n % 2) as usize);
+        q = take_every_other(&v, 0);
+        n >>= 1;
+    }
+
+    p.coef(0) / q.coef(0)
+}
+
+fn take_every_other<Mod: ModTrait>(f: &Polynomial<Mod>, start: usize) -> Polynomial<Mod> {
+    Polynomial::from_coefficients(
+        f.coefficients()
+            .iter()
+            .skip(start)
+            .step_by(2)
+            .copied()
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::berlekamp_massey::berlekamp_massey;
+    use crate::math::matrix::Matrix;
+    use crate::math::modint::ModInt998244353;
+    use crate::utils::random::XorShift64;
+
+    type Mint = ModInt998244353;
+
+    fn poly(v: &[u64]) -> Polynomial<crate::math::modint::Mod998244353> {
+        Polynomial::from_coefficients(v.iter().map(|&x| Mint::new(x)).collect())
+    }
+
+    #[test]
+    fn fibonacci() {
+        let p = poly(&[0, 1]);
+        let q = &poly(&[1]) - &poly(&[0, 1, 1]);
+
+        let a = Matrix::from_vec(vec![
+            vec![Mint::new(1), Mint::new(1)],
+            vec![Mint::new(1), Mint::new(0)],
+        ]);
+        for &n in &[0, 1, 2, 10, 1000, 1_000_000_007, 1_000_000_000_000_000_000] {
+            assert_eq!(a.pow(n)[(0, 1)], nth_term(&p, &q, n));
+        }
+    }
+
+    #[test]
+    fn catalan() {
+        let catalan = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+
+        // the Catalan numbers themselves, and their prefix sums
+        let p = poly(&catalan);
+        let mut acc = 0;
+        for (n, &c) in catalan.iter().enumerate() {
+            acc += c;
+            assert_eq!(Mint::new(c), nth_term(&p, &poly(&[1]), n as u64));
+            assert_eq!(
+                Mint::new(acc),
+                nth_term(&p, &(&poly(&[1]) - &poly(&[0, 1])), n as u64)
+            );
+        }
+        assert_eq!(Mint::new(0), nth_term(&p, &poly(&[1]), 100));
+        assert_eq!(
+            Mint::new(acc),
+            nth_term(&p, &(&poly(&[1]) - &poly(&[0, 1])), 100)
+        );
+    }
+
+    #[test]
+    fn linear_recurrence() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = || Mint::new(rng.next_range(0, 1_000_000));
+
+        for k in 1..=8 {
+            let rec: Vec<Mint> = (0..k).map(|_| rand()).collect();
+            let mut seq: Vec<Mint> = (0..k).map(|_| rand()).collect();
+            while seq.len() < 200 {
+                let i = seq.len();
+                let next = (0..k).fold(Mint::new(0), |acc, j| acc + rec[j] * seq[i - 1 - j]);
+                seq.push(next);
+            }
+
+            // Q = 1 - c_1 x - ... - c_k x^k, P = (a Q) mod x^k
+            let found = berlekamp_massey(&seq);
+            let mut q = vec![Mint::new(1)];
+            q.extend(found.iter().map(|&c| -c));
+            let q = Polynomial::from_coefficients(q);
+            let p = (&Polynomial::from_coefficients(seq[..k].to_vec()) * &q).truncate(k);
+
+            for (n, &a) in seq.iter().enumerate() {
+                assert_eq!(a, nth_term(&p, &q, n as u64));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_constant_term() {
+        nth_term(&poly(&[1]), &poly(&[0, 1]), 3);
+    }
+}
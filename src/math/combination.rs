@@ -1,3 +1,6 @@
+use super::modint::{ModInt, ModTrait};
+use super::util::pow_mod;
+
 /// Calculates combinations mod p.
 ///
 /// # Examples
@@ -42,7 +45,7 @@ impl Combination {
             }))
             .collect();
 
-        let fac_maximum_inv = Self::inv(fac[maximum as usize], modulus);
+        let fac_maximum_inv = pow_mod(fac[maximum as usize], modulus - 2, modulus);
 
         let mut facinv: Vec<u64> = std::iter::once(fac_maximum_inv)
             .chain((1..=maximum).rev().scan(fac_maximum_inv, |state, x| {
@@ -62,7 +65,7 @@ impl Combination {
         }
     }
 
-    /// Returns nCm.
+    /// Returns nCm, which is 0 if m > n.
     ///
     /// # Examples
     ///
@@ -72,12 +75,109 @@ impl Combination {
     /// let comb = Combination::new(5_000_000, 1_000_000_007);
     ///
     /// assert_eq!(6, comb.com(4, 2));
+    /// assert_eq!(0, comb.com(2, 4));
     /// ```
     pub fn com(&self, n: u64, m: u64) -> u64 {
-        assert!(n >= m);
+        if m > n {
+            return 0;
+        }
         (self.fac(n) * self.facinv(m) % self.modulus) * self.facinv(n - m) % self.modulus
     }
 
+    /// Returns nPm, the number of ordered choices of m items out of n, which is 0 if m > n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::Combination;
+    ///
+    /// let comb = Combination::new(5_000_000, 1_000_000_007);
+    ///
+    /// assert_eq!(20, comb.perm(5, 2));
+    /// assert_eq!(0, comb.perm(2, 5));
+    /// ```
+    pub fn perm(&self, n: u64, m: u64) -> u64 {
+        if m > n {
+            return 0;
+        }
+        self.fac(n) * self.facinv(n - m) % self.modulus
+    }
+
+    /// Returns n^m, the number of sequences of length m over n kinds.
+    /// Does not use the table, so n may exceed the maximum.
+    /// Consumes O(log m) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::Combination;
+    ///
+    /// let comb = Combination::new(5_000_000, 1_000_000_007);
+    ///
+    /// assert_eq!(8, comb.perm_rep(2, 3));
+    /// assert_eq!(1, comb.perm_rep(0, 0));
+    /// ```
+    pub fn perm_rep(&self, n: u64, m: u64) -> u64 {
+        pow_mod(n, m, self.modulus)
+    }
+
+    /// Returns nHm = (n + m - 1)Cm, the number of multisets of size m from n kinds.
+    /// `n + m - 1` is required to be at most the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::Combination;
+    ///
+    /// let comb = Combination::new(5_000_000, 1_000_000_007);
+    ///
+    /// assert_eq!(6, comb.multi_com(3, 2));
+    /// assert_eq!(1, comb.multi_com(0, 0));
+    /// ```
+    pub fn multi_com(&self, n: u64, m: u64) -> u64 {
+        if n == 0 {
+            return if m == 0 { 1 } else { 0 };
+        }
+        self.com(n + m - 1, m)
+    }
+
+    /// Returns nCm for any n, using Lucas's theorem on the base-p digits,
+    /// where p is the modulus and is required to be a prime.
+    /// The nonzero digits are looked up in the table,
+    /// so they are required to be at most the maximum,
+    /// which always holds if the maximum is p - 1.
+    /// Consumes O(log n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::Combination;
+    ///
+    /// let comb = Combination::new(12, 13);
+    ///
+    /// assert_eq!(0, comb.com_lucas(13, 1));
+    /// assert_eq!(6, comb.com_lucas(13 * 4 + 1, 13 * 2));
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn com_lucas(&self, mut n: u64, mut m: u64) -> u64 {
+        let p = self.modulus;
+        let mut digits = vec![];
+        while m > 0 {
+            let (a, b) = (n % p, m % p);
+            if b > a {
+                return 0;
+            }
+            digits.push((a, b));
+            n /= p;
+            m /= p;
+        }
+
+        digits
+            .into_iter()
+            .filter(|&(_, b)| b > 0)
+            .fold(1 % p, |acc, (a, b)| acc * self.com(a, b) % p)
+    }
+
     /// Returns n!.
     ///
     /// # Examples
@@ -113,27 +213,109 @@ impl Combination {
         assert!(n < self.fac.len());
         self.facinv[n]
     }
+}
 
-    fn inv(a: u64, p: u64) -> u64 {
-        let mut acc = 1;
-        let mut base = a;
-        let mut exp = p - 2;
+/// Calculates combinations as ModInt.
+/// The modulus is taken from `Mod` and is required to be a prime.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::combination::CombinationModInt;
+/// use tklib::math::modint::{Mod998244353, ModInt998244353 as Mint};
+///
+/// let comb = CombinationModInt::<Mod998244353>::new(100);
+///
+/// assert_eq!(Mint::new(6), comb.com(4, 2));
+/// assert_eq!(Mint::new(1), comb.fac(5) * comb.facinv(5));
+/// ```
+pub struct CombinationModInt<Mod: ModTrait> {
+    fac: Vec<ModInt<Mod>>,
+    facinv: Vec<ModInt<Mod>>,
+}
 
-        while exp > 0 {
-            if (exp & 1) == 1 {
-                acc *= base;
-                if acc >= p {
-                    acc %= p
-                }
-            }
-            exp >>= 1;
-            base = base * base;
-            if base >= p {
-                base %= p
-            }
+impl<Mod: ModTrait> CombinationModInt<Mod> {
+    /// Construction.
+    /// Consumes O(maximum + log(modulus)) time and O(maximum) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::CombinationModInt;
+    /// use tklib::math::modint::Mod1000000007;
+    ///
+    /// let comb = CombinationModInt::<Mod1000000007>::new(5_000_000);
+    /// ```
+    pub fn new(maximum: u64) -> Self {
+        assert!(maximum < Mod::MOD);
+
+        let mut fac = vec![ModInt::new(1); maximum as usize + 1];
+        for i in 1..fac.len() {
+            fac[i] = fac[i - 1] * ModInt::new(i as u64);
+        }
+
+        let mut facinv = vec![fac[maximum as usize].inv(); maximum as usize + 1];
+        for i in (1..facinv.len()).rev() {
+            facinv[i - 1] = facinv[i] * ModInt::new(i as u64);
+        }
+
+        Self { fac, facinv }
+    }
+
+    /// Returns nCm, which is 0 if m > n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::CombinationModInt;
+    /// use tklib::math::modint::{Mod998244353, ModInt998244353 as Mint};
+    ///
+    /// let comb = CombinationModInt::<Mod998244353>::new(100);
+    ///
+    /// assert_eq!(Mint::new(6), comb.com(4, 2));
+    /// assert_eq!(Mint::new(0), comb.com(2, 4));
+    /// ```
+    pub fn com(&self, n: u64, m: u64) -> ModInt<Mod> {
+        if m > n {
+            return ModInt::new(0);
         }
+        self.fac(n) * self.facinv(m) * self.facinv(n - m)
+    }
 
-        acc
+    /// Returns n!.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::CombinationModInt;
+    /// use tklib::math::modint::{Mod998244353, ModInt998244353 as Mint};
+    ///
+    /// let comb = CombinationModInt::<Mod998244353>::new(100);
+    ///
+    /// assert_eq!(Mint::new(120), comb.fac(5));
+    /// ```
+    pub fn fac(&self, n: u64) -> ModInt<Mod> {
+        let n = n as usize;
+        assert!(n < self.fac.len());
+        self.fac[n]
+    }
+
+    /// Returns the inverse of n!.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::combination::CombinationModInt;
+    /// use tklib::math::modint::{Mod998244353, ModInt998244353 as Mint};
+    ///
+    /// let comb = CombinationModInt::<Mod998244353>::new(100);
+    ///
+    /// assert_eq!(Mint::new(120).inv(), comb.facinv(5));
+    /// ```
+    pub fn facinv(&self, n: u64) -> ModInt<Mod> {
+        let n = n as usize;
+        assert!(n < self.facinv.len());
+        self.facinv[n]
     }
 }
 
@@ -150,4 +332,150 @@ mod tests {
         assert_eq!(6, comb.com(4, 2));
         assert_eq!(828_782_236, comb.com(5_000_000, 2_500_000));
     }
+
+    #[test]
+    fn com_out_of_range() {
+        let comb = Combination::new(100, 1_000_000_007);
+
+        assert_eq!(0, comb.com(0, 1));
+        assert_eq!(0, comb.com(3, 4));
+        assert_eq!(0, comb.com(50, 100));
+        assert_eq!(1, comb.com(0, 0));
+    }
+
+    #[test]
+    fn perm_rep() {
+        const P: u64 = 1_000_000_007;
+        let comb = Combination::new(10, P);
+
+        assert_eq!(8, comb.perm_rep(2, 3));
+        assert_eq!(1, comb.perm_rep(5, 0));
+        assert_eq!(0, comb.perm_rep(0, 5));
+        assert_eq!(1, comb.perm_rep(0, 0));
+        assert_eq!(1, comb.perm_rep(P + 1, 1_000_000_000_000));
+        for n in 0..8u64 {
+            for m in 0..8 {
+                assert_eq!(n.pow(m as u32), comb.perm_rep(n, m));
+            }
+        }
+    }
+
+    #[test]
+    fn perm() {
+        let comb = Combination::new(100, 1_000_000_007);
+
+        assert_eq!(20, comb.perm(5, 2));
+        assert_eq!(1, comb.perm(0, 0));
+        assert_eq!(1, comb.perm(7, 0));
+        assert_eq!(120, comb.perm(5, 5));
+        for n in 0..20 {
+            for m in 0..=n {
+                assert_eq!(
+                    comb.com(n, m) * comb.fac(m) % 1_000_000_007,
+                    comb.perm(n, m)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn perm_out_of_range() {
+        let comb = Combination::new(100, 1_000_000_007);
+
+        assert_eq!(0, comb.perm(0, 1));
+        assert_eq!(0, comb.perm(3, 4));
+        assert_eq!(0, comb.perm(50, 100));
+    }
+
+    #[test]
+    fn com_lucas() {
+        const P: u64 = 1_000_000_007;
+        let comb = Combination::new(100, P);
+
+        // 10^18 = 999999993 p + 49 and 10^9 < p has the digit 10^9 > 49
+        assert_eq!(999_999_993 * P + 49, 1_000_000_000_000_000_000);
+        assert_eq!(0, comb.com_lucas(1_000_000_000_000_000_000, 1_000_000_000));
+
+        // C(3, 1) C(5, 2)
+        assert_eq!(30, comb.com_lucas(5 * P + 3, 2 * P + 1));
+        assert_eq!(1, comb.com_lucas(1_000_000_000_000_000_000, 0));
+        assert_eq!(comb.com(50, 20), comb.com_lucas(50, 20));
+
+        let comb = Combination::new(12, 13);
+        let mut pascal = vec![vec![1u64]];
+        for i in 1..200 {
+            let prev = &pascal[i - 1];
+            let row: Vec<u64> = (0..=i)
+                .map(|j| {
+                    let left = if j > 0 { prev[j - 1] } else { 0 };
+                    (left + prev.get(j).copied().unwrap_or(0)) % 13
+                })
+                .collect();
+            pascal.push(row);
+        }
+        for (n, row) in pascal.iter().enumerate() {
+            for (m, &c) in row.iter().enumerate() {
+                assert_eq!(c, comb.com_lucas(n as u64, m as u64));
+            }
+            assert_eq!(0, comb.com_lucas(n as u64, n as u64 + 1));
+        }
+        for &(n, m) in &[(1u64 << 60, 1u64 << 30), (123_456_789_012, 345_678)] {
+            assert_eq!(crate::math::lucas::lucas(n, m, 13), comb.com_lucas(n, m));
+        }
+    }
+
+    #[test]
+    fn multi_com() {
+        let comb = Combination::new(100, 1_000_000_007);
+
+        assert_eq!(6, comb.multi_com(3, 2));
+        assert_eq!(1, comb.multi_com(0, 0));
+        assert_eq!(0, comb.multi_com(0, 3));
+        assert_eq!(1, comb.multi_com(4, 0));
+        assert_eq!(1, comb.multi_com(1, 50));
+
+        // counts the non-decreasing sequences of length m over n values
+        fn count(n: u64, m: u64, lo: u64) -> u64 {
+            if m == 0 {
+                return 1;
+            }
+            (lo..n).map(|x| count(n, m - 1, x)).sum()
+        }
+        for n in 0..6 {
+            for m in 0..6 {
+                assert_eq!(count(n, m, 0), comb.multi_com(n, m));
+            }
+        }
+    }
+
+    #[test]
+    fn modint() {
+        use crate::math::modint::{Mod998244353, ModInt998244353 as Mint};
+
+        const P: u64 = 998_244_353;
+        let comb = CombinationModInt::<Mod998244353>::new(1000);
+        let raw = Combination::new(1000, P);
+        for n in 0..=1000 {
+            assert_eq!(Mint::new(raw.fac(n)), comb.fac(n));
+            assert_eq!(Mint::new(1), comb.fac(n) * comb.facinv(n));
+        }
+        for n in 0..50 {
+            for m in 0..=n {
+                assert_eq!(Mint::new(raw.com(n, m)), comb.com(n, m));
+            }
+        }
+
+        // the row sums of Pascal's triangle and Vandermonde's identity
+        let n = 1000;
+        let sum: Mint = (0..=n).map(|m| comb.com(n, m)).sum();
+        assert_eq!(Mint::new(2).pow(n), sum);
+        let vandermonde: Mint = (0..=30)
+            .map(|k| comb.com(40, k) * comb.com(50, 30 - k))
+            .sum();
+        assert_eq!(comb.com(90, 30), vandermonde);
+
+        let comb = CombinationModInt::<Mod998244353>::new(0);
+        assert_eq!(Mint::new(1), comb.com(0, 0));
+        assert_eq!(Mint::new(0), comb.com(0, 1));
+    }
 }
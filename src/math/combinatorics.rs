@@ -0,0 +1,125 @@
+//! Combinatorial counting.
+
+use super::arith::mul_mod;
+use super::factor::factorize;
+use super::util::pow_mod;
+
+/// Counts the necklaces of n beads with k colors up to rotation, modulo a prime,
+/// using Burnside's lemma: (1/n) Σ_{d|n} φ(d) k^{n/d}.
+/// The modulus is required to be a prime not dividing n.
+/// Consumes O(n^(1/4) + d(n) log n) expected time, where d(n) is the number of the divisors.
+///
+/// # Panics
+/// Panics if n is 0 or divisible by the modulus.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::combinatorics::necklace_count;
+///
+/// assert_eq!(4, necklace_count(3, 2, 1_000_000_007));
+/// assert_eq!(24, necklace_count(4, 3, 1_000_000_007));
+/// ```
+pub fn necklace_count(n: u64, k: u64, modulus: u64) -> u64 {
+    assert!(n > 0 && n % modulus != 0);
+
+    // pairs of a divisor d and φ(d), built from the prime powers
+    let mut divisors = vec![(1, 1)];
+    for (p, e) in factorize(n) {
+        let len = divisors.len();
+        let (mut pk, mut phi) = (1, 1);
+        for _ in 0..e {
+            phi = if pk == 1 { p - 1 } else { phi * p };
+            pk *= p;
+            for i in 0..len {
+                let (d, f) = divisors[i];
+                divisors.push((d * pk, f * phi));
+            }
+        }
+    }
+
+    let sum = divisors.into_iter().fold(0, |acc, (d, phi)| {
+        (acc + mul_mod(phi % modulus, pow_mod(k, n / d, modulus), modulus)) % modulus
+    });
+    mul_mod(sum, pow_mod(n, modulus - 2, modulus), modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = 1_000_000_007;
+
+    // Counts the sequences which are lexicographically smallest among their rotations.
+    fn naive(n: usize, k: usize) -> u64 {
+        let is_smallest = |seq: &[usize]| {
+            (1..n).all(|r| {
+                let rotated: Vec<_> = seq[r..].iter().chain(&seq[..r]).copied().collect();
+                seq <= &rotated[..]
+            })
+        };
+
+        let mut count = 0;
+        let mut seq = vec![0; n];
+        loop {
+            if is_smallest(&seq) {
+                count += 1;
+            }
+
+            // the next sequence in base k
+            match seq.iter().rposition(|&c| c + 1 < k) {
+                Some(i) => {
+                    seq[i] += 1;
+                    seq[i + 1..].iter_mut().for_each(|c| *c = 0);
+                }
+                None => return count,
+            }
+        }
+    }
+
+    #[test]
+    fn small() {
+        assert_eq!(4, necklace_count(3, 2, MOD));
+        // 21 would count bracelets, which may also be flipped over
+        assert_eq!(24, necklace_count(4, 3, MOD));
+        assert_eq!(1, necklace_count(1, 1, MOD));
+        assert_eq!(0, necklace_count(5, 0, MOD));
+
+        for n in 1..=8 {
+            for k in 1..=4 {
+                assert_eq!(naive(n, k), necklace_count(n as u64, k as u64, MOD));
+            }
+        }
+    }
+
+    #[test]
+    fn prime_length() {
+        // k + (k^n - k) / n, since only the identity fixes a non-constant necklace
+        for &n in &[2, 3, 5, 7, 13, 1_000_003] {
+            for &k in &[1, 2, 10, 123_456_789] {
+                let rest = (pow_mod(k, n, MOD) + MOD - k) % MOD;
+                let expected = (k + mul_mod(rest, pow_mod(n, MOD - 2, MOD), MOD)) % MOD;
+                assert_eq!(expected, necklace_count(n, k, MOD));
+            }
+        }
+    }
+
+    #[test]
+    fn prime_power_length() {
+        // 2^(2^e - e) + Σ_{0 ≤ i < e} 2^(2^i - i - 1) for n = 2^e and k = 2
+        for e in 1..=12 {
+            let n = 1 << e;
+            let expected = (0..e).fold(pow_mod(2, n - e, MOD), |acc, i| {
+                (acc + pow_mod(2, (1 << i) - i - 1, MOD)) % MOD
+            });
+            assert_eq!(expected, necklace_count(n, 2, MOD));
+        }
+        assert_eq!(1, necklace_count(1_000_000_000_000, 1, MOD));
+    }
+
+    #[test]
+    #[should_panic]
+    fn divisible_by_modulus() {
+        necklace_count(14, 2, 7);
+    }
+}
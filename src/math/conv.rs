@@ -0,0 +1,422 @@
+//! Convolutions.
+
+use super::modint::{ModInt, ModTrait};
+use super::ntt::{self, convolve_signed};
+use crate::utils::sos::{subset_sum, subset_sum_inv};
+
+fn log2(len: usize) -> usize {
+    assert!(
+        len.is_power_of_two(),
+        "The length is required to be a power of 2"
+    );
+    len.trailing_zeros() as usize
+}
+
+/// Performs the Walsh-Hadamard transform in place, or its inverse if `inverse` is true.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the length of `a` is not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::xor_convolution;
+///
+/// let mut a = vec![1, 2, 3, 4];
+/// xor_convolution(&mut a, false);
+/// assert_eq!(vec![10, -2, -4, 0], a);
+///
+/// xor_convolution(&mut a, true);
+/// assert_eq!(vec![1, 2, 3, 4], a);
+/// ```
+pub fn xor_convolution(a: &mut Vec<i64>, inverse: bool) {
+    let n = a.len();
+    log2(n);
+
+    let mut width = 1;
+    while width < n {
+        for i in (0..n).step_by(2 * width) {
+            for j in i..i + width {
+                let (x, y) = (a[j], a[j + width]);
+                a[j] = x + y;
+                a[j + width] = x - y;
+            }
+        }
+        width <<= 1;
+    }
+
+    if inverse {
+        a.iter_mut().for_each(|x| *x /= n as i64);
+    }
+}
+
+/// Performs the zeta transform over subsets in place, or its inverse if `inverse` is true.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the length of `a` is not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::or_convolution;
+///
+/// let mut a = vec![1, 2, 3, 4];
+/// or_convolution(&mut a, false);
+/// assert_eq!(vec![1, 3, 4, 10], a);
+/// ```
+pub fn or_convolution(a: &mut Vec<i64>, inverse: bool) {
+    let k = log2(a.len());
+    if inverse {
+        subset_sum_inv(a, k);
+    } else {
+        subset_sum(a, k);
+    }
+}
+
+/// Performs the zeta transform over supersets in place, or its inverse if `inverse` is true.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the length of `a` is not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::and_convolution;
+///
+/// let mut a = vec![1, 2, 3, 4];
+/// and_convolution(&mut a, false);
+/// assert_eq!(vec![10, 6, 7, 4], a);
+/// ```
+pub fn and_convolution(a: &mut Vec<i64>, inverse: bool) {
+    let k = log2(a.len());
+
+    for bit in 0..k {
+        for mask in 0..a.len() {
+            if mask >> bit & 1 == 0 {
+                let sup = a[mask | 1 << bit];
+                if inverse {
+                    a[mask] -= sup;
+                } else {
+                    a[mask] += sup;
+                }
+            }
+        }
+    }
+}
+
+fn convolve_with(a: &[i64], b: &[i64], transform: fn(&mut Vec<i64>, bool)) -> Vec<i64> {
+    assert_eq!(a.len(), b.len());
+
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    transform(&mut a, false);
+    transform(&mut b, false);
+    a.iter_mut().zip(&b).for_each(|(x, y)| *x *= y);
+    transform(&mut a, true);
+    a
+}
+
+/// Returns c with `c[k]` the sum of `a[i] * b[j]` over `i ^ j == k`.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the lengths differ or are not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::xor_convolve;
+///
+/// assert_eq!(vec![13, 11, 9, 7], xor_convolve(&[1, 2, 0, 1], &[3, 4, 1, 2]));
+/// ```
+pub fn xor_convolve(a: &[i64], b: &[i64]) -> Vec<i64> {
+    convolve_with(a, b, xor_convolution)
+}
+
+/// Returns c with `c[k]` the sum of `a[i] * b[j]` over `i | j == k`.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the lengths differ or are not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::or_convolve;
+///
+/// assert_eq!(vec![3, 18, 1, 18], or_convolve(&[1, 2, 0, 1], &[3, 4, 1, 2]));
+/// ```
+pub fn or_convolve(a: &[i64], b: &[i64]) -> Vec<i64> {
+    convolve_with(a, b, or_convolution)
+}
+
+/// Returns c with `c[k]` the sum of `a[i] * b[j]` over `i & j == k`.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the lengths differ or are not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::and_convolve;
+///
+/// assert_eq!(vec![21, 16, 1, 2], and_convolve(&[1, 2, 0, 1], &[3, 4, 1, 2]));
+/// ```
+pub fn and_convolve(a: &[i64], b: &[i64]) -> Vec<i64> {
+    convolve_with(a, b, and_convolution)
+}
+
+/// Returns c with `c[s]` the sum of `a[t] * b[s \ t]` over the submasks t of s,
+/// using the zeta transform ranked by the number of set bits.
+/// Consumes O(n log^2 n) time.
+///
+/// # Panics
+/// Panics if the lengths differ or are not a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::subset_convolution;
+///
+/// assert_eq!(vec![3, 10, 1, 7], subset_convolution(&[1, 2, 0, 1], &[3, 4, 1, 2]));
+/// ```
+pub fn subset_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    assert_eq!(a.len(), b.len());
+    let k = log2(a.len());
+
+    let ranked = |f: &[i64]| {
+        let mut g = vec![vec![0; f.len()]; k + 1];
+        for (mask, &x) in f.iter().enumerate() {
+            g[mask.count_ones() as usize][mask] = x;
+        }
+        g.iter_mut().for_each(|h| or_convolution(h, false));
+        g
+    };
+    let fa = ranked(a);
+    let fb = ranked(b);
+
+    let mut h = vec![vec![0; a.len()]; k + 1];
+    for mask in 0..a.len() {
+        for c in 0..=k {
+            h[c][mask] = (0..=c).map(|i| fa[i][mask] * fb[c - i][mask]).sum();
+        }
+    }
+    h.iter_mut().for_each(|g| or_convolution(g, true));
+
+    (0..a.len())
+        .map(|mask| h[mask.count_ones() as usize][mask])
+        .collect()
+}
+
+/// Multiplies two polynomials with integer coefficients exactly,
+/// convolving modulo three NTT-friendly primes and recovering the signed result.
+/// The coefficients are required to be at most about 10^9 in absolute value
+/// for lengths up to 2^24, and the result is required to fit in i64.
+/// Consumes O((n + m) log(n + m)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::conv::add_convolve;
+///
+/// assert_eq!(vec![1, 3, 3, 1], add_convolve(&[1, 1], &[1, 2, 1]));
+/// assert_eq!(vec![-2, 5, -3], add_convolve(&[1, -1], &[-2, 3]));
+/// ```
+pub fn add_convolve(a: &[i64], b: &[i64]) -> Vec<i64> {
+    convolve_signed(a, b)
+        .into_iter()
+        .map(|x| x as i64)
+        .collect()
+}
+
+/// Computes the cyclic convolution `c[k] = Σ_{i + j ≡ k (mod n)} a[i] b[j]`,
+/// the product modulo x^n - 1, over an NTT-friendly prime modulus.
+/// Transforms with length n directly if n is a power of 2,
+/// and otherwise wraps around the linear convolution.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if the lengths differ.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{conv::cyclic_convolution, modint::ModInt998244353 as Mint};
+///
+/// let a: Vec<Mint> = [1, 2, 3].iter().map(|&x| Mint::new(x)).collect();
+/// let b: Vec<Mint> = [0, 1, 0].iter().map(|&x| Mint::new(x)).collect();
+///
+/// // multiplying by x shifts cyclically
+/// let c: Vec<Mint> = [3, 1, 2].iter().map(|&x| Mint::new(x)).collect();
+/// assert_eq!(c, cyclic_convolution(&a, &b));
+/// ```
+pub fn cyclic_convolution<Mod: ModTrait>(a: &[ModInt<Mod>], b: &[ModInt<Mod>]) -> Vec<ModInt<Mod>> {
+    assert_eq!(a.len(), b.len(), "The lengths are required to be equal");
+    let n = a.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    if n.is_power_of_two() {
+        let mut fa = a.to_vec();
+        let mut fb = b.to_vec();
+        ntt::ntt(&mut fa, false);
+        ntt::ntt(&mut fb, false);
+        fa.iter_mut().zip(&fb).for_each(|(x, &y)| *x *= y);
+        ntt::ntt(&mut fa, true);
+        return fa;
+    }
+
+    let mut c = ntt::convolve(a, b);
+    let high = c.split_off(n);
+    for (i, x) in high.into_iter().enumerate() {
+        c[i] += x;
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, 2001) as i64 - 1000)
+            .collect()
+    }
+
+    fn naive(a: &[i64], b: &[i64], op: fn(usize, usize) -> usize) -> Vec<i64> {
+        let mut c = vec![0; a.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[op(i, j)] += x * y;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn convolve() {
+        for k in 0..8 {
+            let a = random_vec(1 << k, 2 * k as u64);
+            let b = random_vec(1 << k, 2 * k as u64 + 1);
+
+            assert_eq!(naive(&a, &b, |i, j| i ^ j), xor_convolve(&a, &b));
+            assert_eq!(naive(&a, &b, |i, j| i | j), or_convolve(&a, &b));
+            assert_eq!(naive(&a, &b, |i, j| i & j), and_convolve(&a, &b));
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        for k in 0..8 {
+            let a = random_vec(1 << k, k as u64);
+            for &transform in &[xor_convolution, or_convolution, and_convolution] {
+                let transform: fn(&mut Vec<i64>, bool) = transform;
+                let mut b = a.clone();
+                transform(&mut b, false);
+                transform(&mut b, true);
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn subset() {
+        for k in 0..=4 {
+            let a = random_vec(1 << k, 3 * k as u64);
+            let b = random_vec(1 << k, 3 * k as u64 + 1);
+
+            let mut naive = vec![0; 1 << k];
+            for (s, c) in naive.iter_mut().enumerate() {
+                for t in (0..1 << k).filter(|&t| t & s == t) {
+                    *c += a[t] * b[s ^ t];
+                }
+            }
+            assert_eq!(naive, subset_convolution(&a, &b));
+        }
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(vec![1, 3, 3, 1], add_convolve(&[1, 1], &[1, 2, 1]));
+        assert!(add_convolve(&[], &[1, 2]).is_empty());
+
+        for &(n, m) in &[(1, 1), (5, 8), (100, 37)] {
+            let a = random_vec(n, n as u64);
+            let b = random_vec(m, m as u64);
+
+            let mut naive = vec![0; n + m - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    naive[i + j] += x * y;
+                }
+            }
+            assert_eq!(naive, add_convolve(&a, &b));
+        }
+
+        // no precision loss at the magnitude 10^9
+        let big = 1_000_000_000;
+        let a = vec![big; 8];
+        let b = vec![big, -big, big, -big, big, -big, big, -big, big];
+        let mut naive = vec![0; 16];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                naive[i + j] += x * y;
+            }
+        }
+        assert_eq!(naive, add_convolve(&a, &b));
+        assert_eq!(vec![big * big], add_convolve(&[-big], &[-big]));
+    }
+
+    #[test]
+    fn cyclic() {
+        use crate::math::modint::ModInt998244353 as Mint;
+
+        let mints = |v: &[u64]| -> Vec<Mint> { v.iter().map(|&x| Mint::new(x)).collect() };
+
+        assert_eq!(
+            mints(&[0, 0, 1]),
+            cyclic_convolution(&mints(&[1, 0, 0]), &mints(&[0, 0, 1]))
+        );
+        assert_eq!(
+            mints(&[0, 0, 1]),
+            cyclic_convolution(&mints(&[0, 1, 0]), &mints(&[0, 1, 0]))
+        );
+        assert_eq!(
+            mints(&[4; 4]),
+            cyclic_convolution(&mints(&[1; 4]), &mints(&[1; 4]))
+        );
+        assert!(cyclic_convolution::<crate::math::modint::Mod998244353>(&[], &[]).is_empty());
+
+        let mut rng = XorShift64::new(406);
+        for &n in &[1, 2, 5, 8, 13, 64, 100] {
+            let a: Vec<Mint> = (0..n)
+                .map(|_| Mint::new(rng.next_range(0, 998_244_353)))
+                .collect();
+            let b: Vec<Mint> = (0..n)
+                .map(|_| Mint::new(rng.next_range(0, 998_244_353)))
+                .collect();
+
+            let mut naive = vec![Mint::new(0); n];
+            for i in 0..n {
+                for j in 0..n {
+                    naive[(i + j) % n] += a[i] * b[j];
+                }
+            }
+            assert_eq!(naive, cyclic_convolution(&a, &b));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_power_of_two() {
+        xor_convolve(&[1, 2, 3], &[4, 5, 6]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Chinese remainder theorem.
+
+/// Solves the system `x ≡ residues[i] (mod moduli[i])`.
+/// The moduli are not required to be coprime.
+/// Returns the solution x and the modulus lcm(moduli) as `(x, lcm)` with `x < lcm`,
+/// or None if there is no solution.
+/// The lcm is required to fit in u64.
+/// Consumes O(n log m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::crt::crt;
+///
+/// assert_eq!(Some((23, 105)), crt(&[2, 3, 2], &[3, 5, 7]));
+/// assert_eq!(Some((10, 12)), crt(&[4, 2], &[6, 4]));
+/// assert_eq!(None, crt(&[1, 2], &[4, 6]));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Option<(u64, u64)> {
+    assert_eq!(residues.len(), moduli.len());
+
+    let (mut x, mut m) = (0i128, 1i128);
+    for (&r, &mi) in residues.iter().zip(moduli) {
+        assert_ne!(0, mi);
+        let (r, mi) = ((r % mi) as i128, mi as i128);
+
+        // x + m t ≡ r (mod mi)
+        let (g, p, _) = ext_gcd(m, mi);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = mi / g;
+        let t = ((r - x) / g % step * p % step + step) % step;
+        x += m * t;
+        m *= step;
+        assert!(
+            m <= std::u64::MAX as i128,
+            "The lcm of the moduli overflows"
+        );
+    }
+
+    Some((x as u64, m as u64))
+}
+
+/// Reconstructs x modulo `output_mod` from `x mod moduli[i]`, using Garner's algorithm,
+/// where x is the solution less than the product of the moduli.
+/// The moduli are required to be pairwise coprime,
+/// while the product may exceed u64.
+/// Consumes O(n^2 + n log m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::crt::garner;
+///
+/// assert_eq!(23, garner(&[2, 3, 2], &[3, 5, 7], 1_000_000_007));
+/// assert_eq!(23 % 10, garner(&[2, 3, 2], &[3, 5, 7], 10));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn garner(residues: &[u64], moduli: &[u64], output_mod: u64) -> u64 {
+    assert_eq!(residues.len(), moduli.len());
+    assert_ne!(0, output_mod);
+
+    let n = moduli.len();
+    let mods: Vec<u128> = moduli
+        .iter()
+        .chain(std::iter::once(&output_mod))
+        .map(|&m| m as u128)
+        .collect();
+
+    // x = v_0 + v_1 m_0 + v_2 m_0 m_1 + ..., kept modulo each of the later moduli
+    let mut coeffs = vec![1; n + 1];
+    let mut constants = vec![0; n + 1];
+    for i in 0..n {
+        let m = mods[i];
+        let (g, inv, _) = ext_gcd((coeffs[i] % m) as i128, m as i128);
+        assert_eq!(1, g, "The moduli are required to be pairwise coprime");
+        let inv = (inv % m as i128 + m as i128) as u128 % m;
+
+        let r = residues[i] as u128 % m;
+        let v = (r + m - constants[i] % m) % m * inv % m;
+        for j in i + 1..=n {
+            constants[j] = (constants[j] + coeffs[j] * v) % mods[j];
+            coeffs[j] = coeffs[j] * m % mods[j];
+        }
+    }
+
+    constants[n] as u64
+}
+
+// Returns (g, x, y) with a x + b y = g.
+#[allow(clippy::many_single_char_names)]
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = ext_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crt_naive() {
+        for m1 in 1..15 {
+            for m2 in 1..15 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let lcm = (1..=m1 * m2).find(|&l| l % m1 == 0 && l % m2 == 0).unwrap();
+                        let naive = (0..lcm).find(|&x| x % m1 == r1 && x % m2 == r2);
+                        assert_eq!(naive.map(|x| (x, lcm)), crt(&[r1, r2], &[m1, m2]));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(Some((0, 1)), crt(&[], &[]));
+    }
+
+    #[test]
+    fn crt_systems() {
+        assert_eq!(Some((23, 105)), crt(&[2, 3, 2], &[3, 5, 7]));
+        assert_eq!(Some((23, 210)), crt(&[2, 3, 2, 23], &[3, 5, 7, 6]));
+        // x ≡ 2 (mod 4) contradicts x ≡ 1 (mod 6) only through the common factor 2
+        assert_eq!(None, crt(&[2, 3, 1], &[4, 9, 6]));
+        assert_eq!(None, crt(&[0, 1], &[1 << 32, 1 << 20]));
+        assert_eq!(Some((5, 12)), crt(&[5, 5, 17], &[12, 4, 6]));
+    }
+
+    #[test]
+    fn crt_large() {
+        let moduli = [999_999_937, 1_000_000_007, 998_244_353];
+        let x = 12_345_678_901_234_567_u64;
+        let residues: Vec<u64> = moduli.iter().map(|&m| x % m).collect();
+
+        assert_eq!(Some(x), crt(&residues[..2], &moduli[..2]).map(|(y, _)| y));
+        assert_eq!(
+            Some(x),
+            crt(&[x % (1 << 40), x % 3_000_017], &[1 << 40, 3_000_017]).map(|p| p.0)
+        );
+    }
+
+    #[test]
+    fn garner_matches_crt() {
+        let moduli = [3, 5, 7, 11, 13];
+        for x in 0..3 * 5 * 7 * 11 * 13 {
+            let residues: Vec<u64> = moduli.iter().map(|&m| x % m).collect();
+            assert_eq!(
+                crt(&residues, &moduli).unwrap().0,
+                garner(&residues, &moduli, std::u64::MAX)
+            );
+            assert_eq!(x % 97, garner(&residues, &moduli, 97));
+        }
+    }
+
+    #[test]
+    fn garner_three_primes() {
+        // x = 10^25 + 7 exceeds u64, but not the product of the primes
+        let moduli = [998_244_353, 1_000_000_007, 1_000_000_009];
+        let x: u128 = 10_000_000_000_000_000_000_000_007;
+        let residues: Vec<u64> = moduli.iter().map(|&m| (x % m as u128) as u64).collect();
+
+        for &out in &[1_000_000_007, 998_244_353, 1 << 61, 1] {
+            assert_eq!((x % out as u128) as u64, garner(&residues, &moduli, out));
+        }
+    }
+}
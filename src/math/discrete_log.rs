@@ -0,0 +1,118 @@
+//! Discrete logarithm.
+
+use super::arith::mul_mod;
+use super::gcd::gcd;
+use super::util::pow_mod;
+use std::collections::HashMap;
+
+/// Finds the smallest non-negative x with `base^x ≡ target (mod modulus)`,
+/// using the baby-step giant-step algorithm.
+/// The base and the modulus are not required to be coprime.
+/// Consumes O(√m) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::discrete_log::bsgs;
+///
+/// assert_eq!(Some(4), bsgs(2, 3, 13));
+/// assert_eq!(None, bsgs(2, 3, 7));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn bsgs(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    assert_ne!(0, modulus);
+
+    let mut m = modulus;
+    let a = base % m;
+    let mut b = target % m;
+
+    // reduces to the case where a and m are coprime: k a^(x - add) ≡ b (mod m)
+    let mut k = 1 % m;
+    let mut add = 0;
+    loop {
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b == k {
+            return Some(add);
+        }
+        if b % g != 0 {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        add += 1;
+        k = mul_mod(k, a / g, m);
+    }
+
+    let n = (m as f64).sqrt() as u64 + 1;
+
+    // baby steps: b a^q for q in [0, n]
+    let mut baby = HashMap::new();
+    let mut cur = b;
+    for q in 0..=n {
+        baby.insert(cur, q);
+        cur = mul_mod(cur, a, m);
+    }
+
+    // giant steps: k a^(np) for p in [1, n]
+    let an = pow_mod(a, n, m);
+    let mut cur = k;
+    for p in 1..=n {
+        cur = mul_mod(cur, an, m);
+        if let Some(&q) = baby.get(&cur) {
+            return Some(n * p - q + add);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bsgs_naive(base: u64, target: u64, modulus: u64) -> Option<u64> {
+        (0..=modulus).find(|&x| pow_mod(base, x, modulus) == target % modulus)
+    }
+
+    #[test]
+    fn mod13() {
+        for base in 0..13 {
+            for target in 0..13 {
+                assert_eq!(bsgs_naive(base, target, 13), bsgs(base, target, 13));
+            }
+        }
+        assert_eq!(Some(0), bsgs(5, 1, 13));
+        assert_eq!(None, bsgs(3, 0, 13));
+    }
+
+    #[test]
+    fn composite() {
+        for m in 1..40 {
+            for base in 0..m {
+                for target in 0..m {
+                    assert_eq!(bsgs_naive(base, target, m), bsgs(base, target, m));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_prime() {
+        const MOD: u64 = 998_244_353;
+
+        let mut x: u64 = 0;
+        let mut target = 1;
+        for _ in 0..123_456 {
+            target = target * 3 % MOD;
+            x += 1;
+        }
+        // 3 is a primitive root, so the logarithm is unique modulo MOD - 1
+        assert_eq!(Some(x), bsgs(3, target, MOD));
+        assert_eq!(Some(0), bsgs(3, 1, MOD));
+        assert_eq!(Some((MOD - 1) / 2), bsgs(3, MOD - 1, MOD));
+        assert_eq!(None, bsgs(3, 0, MOD));
+    }
+}
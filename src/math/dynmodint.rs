@@ -0,0 +1,308 @@
+//! Modular integer with a modulus set at runtime.
+
+use super::gcd::extended_gcd;
+use super::modint::ModValue;
+use super::util::pow_mod;
+use std::cell::Cell;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+thread_local! {
+    static MODULUS: Cell<ModValue> = Cell::new(0);
+}
+
+/// The F_p integer type with the modulus held by the current thread.
+/// The modulus is set with [`set_modulus`] before constructing values,
+/// and the values constructed under another modulus must not be used after changing it.
+///
+/// [`set_modulus`]: #method.set_modulus
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::dynmodint::DynModInt as Mint;
+///
+/// Mint::set_modulus(13);
+/// assert_eq!(Mint::new(5), Mint::new(2) - Mint::new(10));
+/// assert_eq!(Mint::new(7), Mint::new(2).inv());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct DynModInt {
+    value: ModValue,
+}
+
+impl DynModInt {
+    fn new_unchecked(value: ModValue) -> Self {
+        Self { value }
+    }
+
+    /// Sets the modulus of the current thread.
+    ///
+    /// # Panics
+    /// Panics if `p` is not in `[1, 2^32)`, so that products fit in ModValue.
+    pub fn set_modulus(p: ModValue) {
+        assert!(
+            p >= 1 && p <= std::u32::MAX as ModValue,
+            "The modulus is required to be in [1, 2^32)"
+        );
+        MODULUS.with(|m| m.set(p));
+    }
+
+    /// Returns the modulus of the current thread.
+    ///
+    /// # Panics
+    /// Panics if the modulus is not set.
+    pub fn modulus() -> ModValue {
+        let p = MODULUS.with(Cell::get);
+        assert_ne!(p, 0, "The modulus is not set");
+        p
+    }
+
+    /// Constructs a new DynModInt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::dynmodint::DynModInt as Mint;
+    ///
+    /// Mint::set_modulus(998244353);
+    /// assert_eq!(Mint::new(2), Mint::new(998244354) + Mint::new(1));
+    /// ```
+    pub fn new(value: ModValue) -> Self {
+        Self::new_unchecked(value % Self::modulus())
+    }
+
+    /// Returns the raw value.
+    pub fn value(self) -> ModValue {
+        self.value
+    }
+
+    /// Takes the inverse of self, using [`extended_gcd`].
+    /// The greatest common divisor of `self.value()`
+    /// and the modulus is required to be 1.
+    ///
+    /// [`extended_gcd`]: ../gcd/fn.extended_gcd.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::dynmodint::DynModInt as Mint;
+    ///
+    /// Mint::set_modulus(1_000_000_007);
+    /// assert_eq!(Mint::new(500000004), Mint::new(2).inv());
+    /// ```
+    pub fn inv(self) -> Self {
+        assert_ne!(self.value, 0, "Attempted to take the inverse of 0");
+
+        let p = Self::modulus() as i64;
+        let (g, x, _) = extended_gcd(self.value as i64, p);
+        assert_eq!(g, 1, "The value is not coprime to the modulus");
+
+        Self::new_unchecked(x.rem_euclid(p) as ModValue)
+    }
+
+    /// Raises self to the power of exp, using exponentiation by squaring.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::dynmodint::DynModInt as Mint;
+    ///
+    /// Mint::set_modulus(998244353);
+    /// assert_eq!(Mint::new(926495343), Mint::new(3).pow(5_000_000_000_000_000));
+    /// ```
+    pub fn pow(self, exp: u64) -> Self {
+        Self::new_unchecked(pow_mod(self.value, exp, Self::modulus()))
+    }
+}
+
+//
+// Ops
+//
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Add for DynModInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let p = Self::modulus();
+        let out = self.value + rhs.value;
+        Self::new_unchecked(if out < p { out } else { out - p })
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div for DynModInt {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl Mul for DynModInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.value * rhs.value)
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new_unchecked(if self.value == 0 {
+            0
+        } else {
+            Self::modulus() - self.value
+        })
+    }
+}
+
+impl Sub for DynModInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new_unchecked(if self.value < rhs.value {
+            (Self::modulus() + self.value) - rhs.value
+        } else {
+            self.value - rhs.value
+        })
+    }
+}
+
+macro_rules! op_assign_impl {
+    ($($trait: ident, $op_assign: ident, $op: ident)+) => {
+        $(
+            impl $trait for DynModInt {
+                fn $op_assign(&mut self, rhs: Self) {
+                    *self = self.$op(rhs);
+                }
+            }
+        )+
+    };
+}
+
+op_assign_impl! {
+    AddAssign, add_assign, add
+    DivAssign, div_assign, div
+    MulAssign, mul_assign, mul
+    SubAssign, sub_assign, sub
+}
+
+impl Default for DynModInt {
+    fn default() -> Self {
+        Self::new_unchecked(0)
+    }
+}
+
+impl fmt::Display for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl PartialEq for DynModInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for DynModInt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Mint = DynModInt;
+
+    #[test]
+    fn add() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(2), Mint::new(1) + Mint::new(1));
+
+        let mut n = Mint::new(2);
+        n += Mint::new(998_244_352);
+        assert_eq!(n, Mint::new(1));
+    }
+
+    #[test]
+    fn div() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(3), Mint::new(6) / Mint::new(2));
+
+        let mut n = Mint::new(3);
+        n /= Mint::new(499_122_177);
+        assert_eq!(n, Mint::new(6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn inv() {
+        Mint::set_modulus(6);
+        let _ = Mint::new(3).inv();
+    }
+
+    #[test]
+    fn mul() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(6), Mint::new(2) * Mint::new(3));
+
+        let mut n = Mint::new(998_244_352);
+        n *= Mint::new(998_244_352);
+        assert_eq!(n, Mint::new(1));
+    }
+
+    #[test]
+    fn neg() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(1), -Mint::new(998_244_352));
+        assert_eq!(Mint::new(998_244_352), -Mint::new(1));
+        assert_eq!(Mint::new(0), -Mint::new(0));
+    }
+
+    #[test]
+    fn sub() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(1), Mint::new(2) - Mint::new(1));
+
+        let mut n = Mint::new(1);
+        n -= Mint::new(2);
+        assert_eq!(n, Mint::new(998_244_352));
+    }
+
+    #[test]
+    fn pow() {
+        Mint::set_modulus(998_244_353);
+        assert_eq!(Mint::new(4), Mint::new(2).pow(2));
+        assert_eq!(
+            Mint::new(926_495_343),
+            Mint::new(3).pow(5_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn change_modulus() {
+        Mint::set_modulus(7);
+        assert_eq!(Mint::new(5), Mint::new(3).inv());
+        assert_eq!("2", Mint::new(16).to_string());
+
+        Mint::set_modulus(4_294_967_291);
+        assert_eq!(
+            Mint::new(1),
+            Mint::new(4_294_967_290) * Mint::new(4_294_967_290)
+        );
+        assert_eq!(Mint::new(1), Mint::new(123_456_789).pow(4_294_967_290));
+    }
+
+    #[test]
+    fn separate_threads() {
+        Mint::set_modulus(5);
+        let handle = std::thread::spawn(|| {
+            Mint::set_modulus(11);
+            Mint::new(3).inv().value()
+        });
+        assert_eq!(4, handle.join().unwrap());
+        assert_eq!(2, Mint::new(3).inv().value());
+    }
+}
@@ -0,0 +1,245 @@
+//! Prime factorization and divisors.
+
+use super::arith::mul_mod;
+use super::gcd::gcd;
+use super::prime::is_prime;
+
+/// Factorizes n into primes, using the Miller-Rabin test and Pollard's rho algorithm.
+/// Returns pairs of a prime and its exponent, sorted by the prime.
+/// Consumes O(n^(1/4)) expected time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::factor::factorize;
+///
+/// assert_eq!(vec![(2, 2), (3, 1), (5, 2)], factorize(300));
+/// assert!(factorize(1).is_empty());
+/// ```
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut primes = vec![];
+    if n == 0 {
+        return vec![];
+    }
+
+    // small factors by trial division
+    for p in 2..64 {
+        while n % p == 0 {
+            n /= p;
+            primes.push(p);
+        }
+    }
+
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+        } else {
+            let d = find_factor(m);
+            stack.push(d);
+            stack.push(m / d);
+        }
+    }
+
+    primes.sort();
+    let mut factors: Vec<(u64, u32)> = vec![];
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+// Finds a nontrivial factor of an odd composite n, using Pollard's rho with Brent's cycle detection.
+#[allow(clippy::many_single_char_names)]
+fn find_factor(n: u64) -> u64 {
+    const BATCH: u64 = 128;
+    let diff = |a: u64, b: u64| if a > b { a - b } else { b - a };
+
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+
+        let (mut x, mut y, mut ys) = (0, 2, 2);
+        let (mut r, mut q, mut g) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, diff(x, y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        // the batch overshot, so step back one by one
+        if g == n {
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = gcd(diff(x, ys), n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Returns the divisors of n in increasing order.
+/// Consumes O(n^(1/4)) expected time besides the number of the divisors.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::factor::divisors;
+///
+/// assert_eq!(vec![1, 2, 3, 4, 6, 12], divisors(12));
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    assert_ne!(0, n);
+
+    let mut res = vec![1];
+    for (p, e) in factorize(n) {
+        let len = res.len();
+        let mut pk = 1;
+        for _ in 0..e {
+            pk *= p;
+            for i in 0..len {
+                res.push(res[i] * pk);
+            }
+        }
+    }
+
+    res.sort();
+    res
+}
+
+/// Returns the number of the divisors of n.
+/// Consumes O(n^(1/4)) expected time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::factor::num_divisors;
+///
+/// assert_eq!(6, num_divisors(12));
+/// ```
+pub fn num_divisors(n: u64) -> u64 {
+    assert_ne!(0, n);
+    factorize(n).iter().map(|&(_, e)| e as u64 + 1).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorize_small() {
+        for n in 1..1000 {
+            let factors = factorize(n);
+            assert_eq!(n, factors.iter().map(|&(p, e)| p.pow(e)).product());
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(factors.iter().all(|&(p, _)| (2..p).all(|d| p % d != 0)));
+        }
+    }
+
+    #[test]
+    fn factorize_large() {
+        let p = 9_223_372_036_854_775_783; // the largest prime below 2^63
+        assert_eq!(vec![(p, 1)], factorize(p));
+        assert_eq!(
+            vec![(18_446_744_073_709_551_557, 1)],
+            factorize(18_446_744_073_709_551_557)
+        );
+        assert_eq!(vec![(2, 63)], factorize(1 << 63));
+        assert!(factorize(0).is_empty());
+        assert_eq!(
+            vec![(4_294_967_279, 1), (4_294_967_291, 1)],
+            factorize(4_294_967_279 * 4_294_967_291)
+        );
+        assert_eq!(
+            vec![(3, 2), (1_000_000_007, 2)],
+            factorize(9 * 1_000_000_007 * 1_000_000_007)
+        );
+        assert_eq!(
+            vec![(101, 3), (998_244_353, 1)],
+            factorize(101 * 101 * 101 * 998_244_353)
+        );
+    }
+
+    #[test]
+    fn factorize_special() {
+        // the largest highly composite number below 10^18
+        assert_eq!(
+            vec![
+                (2, 8),
+                (3, 4),
+                (5, 2),
+                (7, 2),
+                (11, 1),
+                (13, 1),
+                (17, 1),
+                (19, 1),
+                (23, 1),
+                (29, 1),
+                (31, 1),
+                (37, 1)
+            ],
+            factorize(897_612_484_786_617_600)
+        );
+        assert_eq!(103_680, num_divisors(897_612_484_786_617_600));
+
+        // Carmichael numbers
+        assert_eq!(vec![(3, 1), (11, 1), (17, 1)], factorize(561));
+        assert_eq!(
+            vec![(5, 1), (7, 1), (17, 1), (19, 1), (73, 1)],
+            factorize(825_265)
+        );
+
+        // near 10^18
+        assert_eq!(
+            vec![(1_000_000_000_000_000_003, 1)],
+            factorize(1_000_000_000_000_000_003)
+        );
+        assert_eq!(
+            vec![(999_999_937, 1), (1_000_000_007, 1)],
+            factorize(999_999_943_999_999_559)
+        );
+    }
+
+    #[test]
+    fn divisors_of() {
+        assert_eq!(vec![1, 2, 3, 4, 6, 12], divisors(12));
+        assert_eq!(vec![1], divisors(1));
+        assert_eq!(vec![1, 1_000_000_007], divisors(1_000_000_007));
+        assert_eq!(vec![1, 3, 9, 27, 37, 111, 333, 999], divisors(999));
+
+        for n in 1..500 {
+            let naive: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(naive, divisors(n));
+            assert_eq!(naive.len() as u64, num_divisors(n));
+        }
+    }
+
+    #[test]
+    fn num_divisors_of() {
+        assert_eq!(1, num_divisors(1));
+        assert_eq!(2, num_divisors(998_244_353));
+        assert_eq!(6720, num_divisors(963_761_198_400));
+    }
+}
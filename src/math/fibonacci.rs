@@ -0,0 +1,93 @@
+//! Fibonacci numbers.
+
+/// Computes `(F(n), F(n + 1))` modulo `modulus`, using the fast doubling formulas
+/// `F(2k) = F(k) (2 F(k + 1) - F(k))` and `F(2k + 1) = F(k)^2 + F(k + 1)^2`.
+/// Consumes O(log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::fibonacci::fibonacci_pair;
+///
+/// assert_eq!((55, 89), fibonacci_pair(10, 1_000_000_007));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn fibonacci_pair(n: u64, modulus: u64) -> (u64, u64) {
+    let m = modulus as u128;
+    let (mut a, mut b) = (0, 1 % m);
+
+    for k in (0..64 - n.leading_zeros()).rev() {
+        let c = a * ((2 * b + m - a) % m) % m;
+        let d = (a * a % m + b * b % m) % m;
+        if n >> k & 1 == 1 {
+            a = d;
+            b = (c + d) % m;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+
+    (a as u64, b as u64)
+}
+
+/// Computes the n-th Fibonacci number modulo `modulus`, where `F(0) = 0` and `F(1) = 1`.
+/// Consumes O(log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::fibonacci::fibonacci;
+///
+/// assert_eq!(6765, fibonacci(20, 1_000_000_007));
+/// ```
+pub fn fibonacci(n: u64, modulus: u64) -> u64 {
+    fibonacci_pair(n, modulus).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::matrix::Matrix;
+    use crate::math::modint::ModInt998244353;
+
+    #[test]
+    fn small() {
+        let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610];
+        for (n, &f) in expected.iter().enumerate() {
+            assert_eq!(f, fibonacci(n as u64, 1_000_000_007));
+        }
+        assert_eq!(610 % 7, fibonacci(15, 7));
+        assert_eq!(0, fibonacci(15, 1));
+    }
+
+    #[test]
+    fn large() {
+        type Mint = ModInt998244353;
+
+        let a = Matrix::from_vec(vec![
+            vec![Mint::new(1), Mint::new(1)],
+            vec![Mint::new(1), Mint::new(0)],
+        ]);
+        for &n in &[1000, 998_244_353, 1_000_000_000_000_000_000, std::u64::MAX] {
+            assert_eq!(a.pow(n)[(0, 1)].value(), fibonacci(n, 998_244_353));
+        }
+
+        assert_eq!(
+            209_783_453,
+            fibonacci(1_000_000_000_000_000_000, 1_000_000_007)
+        );
+    }
+
+    #[test]
+    fn pair() {
+        let m = std::u64::MAX - 58;
+        for &n in &[0, 1, 2, 100, 12_345_678_901, 1 << 62] {
+            let (a, b) = fibonacci_pair(n, m);
+            let (c, d) = fibonacci_pair(n + 1, m);
+
+            assert_eq!(b, c);
+            assert_eq!((a as u128 + b as u128) % m as u128, d as u128);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Greatest common divisor.
+
+/// Computes the greatest common divisor, using the binary GCD algorithm.
+/// gcd(0, 0) is 0.
+/// Consumes O(log max(a, b)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::gcd::gcd;
+///
+/// assert_eq!(6, gcd(12, 18));
+/// assert_eq!(5, gcd(0, 5));
+/// ```
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Computes the least common multiple.
+/// lcm(0, b) is 0.
+///
+/// # Panics
+/// Panics if the result overflows.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::gcd::lcm;
+///
+/// assert_eq!(12, lcm(4, 6));
+/// assert_eq!(0, lcm(0, 6));
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)).checked_mul(b).expect("The lcm overflows")
+}
+
+/// Returns `(g, x, y)` with `a x + b y = g`, where g is the non-negative gcd of a and b,
+/// using the extended Euclidean algorithm.
+/// If a and b are nonzero, `|x| <= |b| / g` and `|y| <= |a| / g`.
+/// Consumes O(log max(|a|, |b|)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::gcd::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46);
+/// assert_eq!(2, g);
+/// assert_eq!(2, 240 * x + 46 * y);
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        let r2 = r0 - q * r1;
+        r0 = r1;
+        r1 = r2;
+        let x2 = x0 - q * x1;
+        x0 = x1;
+        x1 = x2;
+        let y2 = y0 - q * y1;
+        y0 = y1;
+        y1 = y2;
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn gcd_of() {
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(5, gcd(5, 0));
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(1, gcd(998_244_353, 1_000_000_007));
+        assert_eq!(1 << 40, gcd(3 << 40, 1 << 41));
+        assert_eq!(std::u64::MAX, gcd(std::u64::MAX, std::u64::MAX));
+
+        for a in 0..100u64 {
+            for b in 0..100u64 {
+                let naive = (1..=a.max(b)).filter(|d| a % d == 0 && b % d == 0).max();
+                assert_eq!(naive.unwrap_or(0), gcd(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn lcm_of() {
+        assert_eq!(12, lcm(4, 6));
+        assert_eq!(7, lcm(7, 7));
+        assert_eq!(0, lcm(5, 0));
+        assert_eq!(998_244_353 * 1_000_000_007, lcm(998_244_353, 1_000_000_007));
+        assert_eq!(std::u64::MAX, lcm(std::u64::MAX, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn lcm_overflow() {
+        lcm(std::u64::MAX, std::u64::MAX - 1);
+    }
+
+    #[test]
+    fn bezout() {
+        let check = |a: i64, b: i64| {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(gcd(a.abs() as u64, b.abs() as u64), g as u64);
+            assert_eq!(g as i128, a as i128 * x as i128 + b as i128 * y as i128);
+            if a != 0 && b != 0 {
+                assert!(x.abs() <= b.abs() / g && y.abs() <= a.abs() / g);
+            }
+        };
+
+        check(0, 0);
+        check(0, 7);
+        check(-7, 0);
+        check(240, 46);
+        check(-240, 46);
+        check(240, -46);
+        check(998_244_353, 1_000_000_007);
+        check(std::i64::MAX, std::i64::MAX - 1);
+
+        let mut rng = XorShift64::new(520);
+        for _ in 0..1000 {
+            let a = rng.next() as i64 >> rng.next_range(0, 63);
+            let b = rng.next() as i64 >> rng.next_range(0, 63);
+            check(a, b);
+        }
+    }
+}
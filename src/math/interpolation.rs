@@ -0,0 +1,155 @@
+//! Lagrange interpolation.
+
+use super::arith::mul_mod;
+use super::util::pow_mod;
+
+/// Evaluates at x the polynomial of degree less than n passing through `(xs[i], ys[i])`.
+/// The modulus is required to be a prime, and the nodes to be distinct modulo it.
+/// Consumes O(n^2) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::interpolation::lagrange_eval;
+///
+/// // f(x) = x^2 + 1
+/// assert_eq!(26, lagrange_eval(&[1, 3, 4], &[2, 10, 17], 5, 1_000_000_007));
+/// ```
+pub fn lagrange_eval(xs: &[u64], ys: &[u64], x: u64, modulus: u64) -> u64 {
+    assert_eq!(xs.len(), ys.len());
+
+    let m = modulus;
+    let sub = |a: u64, b: u64| (a % m + m - b % m) % m;
+
+    let mut res = 0;
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        let mut num = yi % m;
+        let mut den = 1;
+        for (j, &xj) in xs.iter().enumerate() {
+            if i != j {
+                num = mul_mod(num, sub(x, xj), m);
+                den = mul_mod(den, sub(xi, xj), m);
+            }
+        }
+        res = (res + mul_mod(num, pow_mod(den, m - 2, m), m)) % m;
+    }
+
+    res
+}
+
+/// Evaluates at x the polynomial of degree less than n passing through `(i, ys[i])`.
+/// The modulus is required to be a prime greater than n.
+/// Consumes O(n + log(modulus)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::interpolation::lagrange_eval_consecutive;
+///
+/// // f(x) = x^2 + 1
+/// assert_eq!(26, lagrange_eval_consecutive(&[1, 2, 5], 5, 1_000_000_007));
+/// ```
+pub fn lagrange_eval_consecutive(ys: &[u64], x: u64, modulus: u64) -> u64 {
+    let n = ys.len();
+    let m = modulus;
+    if (x as usize) < n && x < m {
+        return ys[x as usize] % m;
+    }
+
+    let x = x % m;
+    let sub = |a: u64, b: u64| (a + m - b % m) % m;
+
+    // prefix[i] = (x - 0) ... (x - (i - 1)), suffix[i] = (x - i) ... (x - (n - 1))
+    let mut prefix = vec![1; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = mul_mod(prefix[i], sub(x, i as u64), m);
+    }
+    let mut suffix = vec![1; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = mul_mod(suffix[i + 1], sub(x, i as u64), m);
+    }
+
+    let mut fact = vec![1; n];
+    for i in 1..n {
+        fact[i] = mul_mod(fact[i - 1], i as u64, m);
+    }
+    let mut fact_inv = vec![1; n];
+    if n > 0 {
+        fact_inv[n - 1] = pow_mod(fact[n - 1], m - 2, m);
+        for i in (1..n).rev() {
+            fact_inv[i - 1] = mul_mod(fact_inv[i], i as u64, m);
+        }
+    }
+
+    let mut res = 0;
+    for (i, &y) in ys.iter().enumerate() {
+        // the denominator is i! (n - 1 - i)! (-1)^(n - 1 - i)
+        let term = mul_mod(
+            mul_mod(y % m, mul_mod(prefix[i], suffix[i + 1], m), m),
+            mul_mod(fact_inv[i], fact_inv[n - 1 - i], m),
+            m,
+        );
+        res = if (n - 1 - i) % 2 == 0 {
+            (res + term) % m
+        } else {
+            sub(res, term)
+        };
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = 998_244_353;
+
+    // f(x) = 3x^3 - 2x^2 + 7x + 5 mod MOD
+    fn f(x: u64) -> u64 {
+        let x = x % MOD;
+        let x2 = x * x % MOD;
+        let x3 = x2 * x % MOD;
+        (3 * x3 + (MOD - 2) * x2 % MOD + 7 * x + 5) % MOD
+    }
+
+    #[test]
+    fn cubic() {
+        let xs = [2, 5, 11, 100];
+        let ys: Vec<u64> = xs.iter().map(|&x| f(x)).collect();
+
+        for &x in &[0, 1, 3, 42, 1_000_000, MOD - 1, 123_456_789_012] {
+            assert_eq!(f(x), lagrange_eval(&xs, &ys, x, MOD));
+        }
+    }
+
+    #[test]
+    fn at_nodes() {
+        let xs = [2, 5, 11, 100];
+        let ys: Vec<u64> = xs.iter().map(|&x| f(x)).collect();
+
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert_eq!(y, lagrange_eval(&xs, &ys, x, MOD));
+        }
+
+        let ys: Vec<u64> = (0..4).map(f).collect();
+        for x in 0..4 {
+            assert_eq!(ys[x as usize], lagrange_eval_consecutive(&ys, x, MOD));
+        }
+    }
+
+    #[test]
+    fn consecutive() {
+        let xs: Vec<u64> = (0..6).collect();
+        let ys: Vec<u64> = xs.iter().map(|&x| (x * x * x * x + 17) % 13).collect();
+
+        for x in 0..50 {
+            assert_eq!(
+                lagrange_eval(&xs, &ys, x, MOD),
+                lagrange_eval_consecutive(&ys, x, MOD)
+            );
+        }
+        assert_eq!(0, lagrange_eval_consecutive(&[], 3, MOD));
+        assert_eq!(7, lagrange_eval_consecutive(&[7], 3, MOD));
+    }
+}
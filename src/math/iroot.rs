@@ -0,0 +1,136 @@
+//! Integer roots.
+
+/// Computes ⌊√n⌋ exactly, using Newton's method.
+/// Consumes O(log log n) iterations.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::iroot::isqrt;
+///
+/// assert_eq!(3, isqrt(15));
+/// assert_eq!(4, isqrt(16));
+/// assert_eq!(4_294_967_295, isqrt(std::u64::MAX));
+/// ```
+pub fn isqrt(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    // an initial value not less than √n
+    let bits = 64 - n.leading_zeros();
+    let mut x = 1u64 << ((bits + 1) / 2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Returns true if n is a perfect square.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::iroot::is_perfect_square;
+///
+/// assert!(is_perfect_square(49));
+/// assert!(!is_perfect_square(50));
+/// ```
+pub fn is_perfect_square(n: u64) -> bool {
+    let r = isqrt(n);
+    r * r == n
+}
+
+/// Computes ⌊∛n⌋ exactly, using Newton's method.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::iroot::icbrt;
+///
+/// assert_eq!(2, icbrt(26));
+/// assert_eq!(3, icbrt(27));
+/// assert_eq!(2_642_245, icbrt(std::u64::MAX));
+/// ```
+pub fn icbrt(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    // an initial value not less than ∛n
+    let bits = 64 - n.leading_zeros();
+    let mut x = 1u64 << ((bits + 2) / 3);
+    loop {
+        let y = (2 * x + n / (x * x)) / 3;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_sqrt(n: u64) {
+        let r = isqrt(n) as u128;
+        assert!(
+            r * r <= n as u128 && (n as u128) < (r + 1) * (r + 1),
+            "{}",
+            n
+        );
+    }
+
+    fn check_cbrt(n: u64) {
+        let r = icbrt(n) as u128;
+        assert!(
+            r * r * r <= n as u128 && (n as u128) < (r + 1) * (r + 1) * (r + 1),
+            "{}",
+            n
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!((1 << 32) - 1, isqrt(((1 << 32) - 1) * ((1 << 32) - 1)));
+        assert_eq!(1 << 31, isqrt(1 << 62));
+        assert_eq!(3_037_000_499, isqrt((1 << 63) - 1));
+        assert_eq!(0, isqrt(0));
+        assert_eq!(1, isqrt(1));
+
+        for n in 0..10000 {
+            check_sqrt(n);
+        }
+        for r in (1u64..(1 << 32))
+            .step_by(99_991)
+            .chain(std::iter::once((1 << 32) - 1))
+        {
+            for n in &[r * r - 1, r * r, r * r + 1, r * r + 2 * r] {
+                check_sqrt(*n);
+            }
+            assert!(is_perfect_square(r * r));
+            assert!(r == 1 || !is_perfect_square(r * r - 1));
+        }
+        check_sqrt(std::u64::MAX);
+    }
+
+    #[test]
+    fn cbrt() {
+        for n in 0..10000 {
+            check_cbrt(n);
+        }
+        for r in (1u64..2_642_246)
+            .step_by(9973)
+            .chain(std::iter::once(2_642_245))
+        {
+            for n in &[r * r * r - 1, r * r * r, r * r * r + 1] {
+                check_cbrt(*n);
+            }
+        }
+        check_cbrt(std::u64::MAX);
+    }
+}
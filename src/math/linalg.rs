@@ -0,0 +1,236 @@
+//! Linear algebra over fields.
+
+use super::modint::{ModInt, ModTrait};
+use super::num::{One, Zero};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Types forming a field.
+pub trait Field:
+    Clone
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Returns true if self should be treated as zero during elimination.
+    fn is_negligible(&self) -> bool {
+        self.is_zero()
+    }
+
+    /// Returns true if self should be preferred to other as a pivot.
+    /// By default the first non-negligible element is taken.
+    fn is_better_pivot(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Field for f64 {
+    fn is_negligible(&self) -> bool {
+        self.abs() < 1e-9
+    }
+
+    // partial pivoting: the largest absolute value is the most stable
+    fn is_better_pivot(&self, other: &Self) -> bool {
+        self.abs() > other.abs()
+    }
+}
+
+impl<Mod: ModTrait> Field for ModInt<Mod> {}
+
+/// Solves Ax = b, using Gaussian elimination.
+/// Returns None if the system has no solution or infinitely many solutions.
+/// After the call, `a` and `b` hold the reduced row echelon form of the system.
+/// Consumes O(n^2 m) time for an n by m matrix.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::linalg::gaussian_elimination;
+///
+/// // x + y = 3, x - y = 1
+/// let mut a = vec![vec![1.0, 1.0], vec![1.0, -1.0]];
+/// let mut b = vec![3.0, 1.0];
+///
+/// let x = gaussian_elimination(&mut a, &mut b).unwrap();
+/// assert!((x[0] - 2.0_f64).abs() < 1e-9);
+/// assert!((x[1] - 1.0_f64).abs() < 1e-9);
+/// ```
+pub fn gaussian_elimination<T: Field>(a: &mut Vec<Vec<T>>, b: &mut Vec<T>) -> Option<Vec<T>> {
+    assert_eq!(a.len(), b.len());
+
+    let m = a.first().map_or(0, Vec::len);
+    a.iter_mut()
+        .zip(b.iter())
+        .for_each(|(row, x)| row.push(x.clone()));
+
+    let pivots = reduce(a, m);
+
+    for (row, x) in a.iter_mut().zip(b.iter_mut()) {
+        *x = row.pop().unwrap();
+    }
+
+    if b[pivots.len()..].iter().any(|x| !x.is_negligible()) || pivots.len() < m {
+        return None;
+    }
+
+    let mut x = vec![T::zero(); m];
+    for (&col, y) in pivots.iter().zip(b.iter()) {
+        x[col] = y.clone();
+    }
+    Some(x)
+}
+
+/// Returns the rank of the matrix.
+/// After the call, `a` holds its reduced row echelon form.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::linalg::rank;
+///
+/// let mut a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+/// assert_eq!(1, rank(&mut a));
+/// ```
+pub fn rank<T: Field>(a: &mut Vec<Vec<T>>) -> usize {
+    let m = a.first().map_or(0, Vec::len);
+    reduce(a, m).len()
+}
+
+#[allow(clippy::many_single_char_names)]
+fn reduce<T: Field>(a: &mut [Vec<T>], cols: usize) -> Vec<usize> {
+    let n = a.len();
+    let mut pivots = vec![];
+
+    for col in 0..cols {
+        let r = pivots.len();
+        let pivot =
+            (r..n)
+                .filter(|&i| !a[i][col].is_negligible())
+                .fold(None, |best: Option<usize>, i| match best {
+                    Some(b) if !a[i][col].is_better_pivot(&a[b][col]) => best,
+                    _ => Some(i),
+                });
+        let pivot = match pivot {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        a.swap(r, pivot);
+
+        let p = a[r][col].clone();
+        a[r].iter_mut().for_each(|x| *x = x.clone() / p.clone());
+
+        for i in (0..n).filter(|&i| i != r) {
+            let f = a[i][col].clone();
+            if f.is_negligible() {
+                continue;
+            }
+            for j in col..a[i].len() {
+                a[i][j] = a[i][j].clone() - f.clone() * a[r][j].clone();
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    pivots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::ModInt1000000007;
+
+    #[test]
+    fn solve_f64() {
+        let mut a = vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ];
+        let mut b = vec![8.0, -11.0, -3.0];
+
+        let x = gaussian_elimination(&mut a, &mut b).unwrap();
+        for (x, y) in x.iter().zip(&[2.0, 3.0, -1.0]) {
+            assert!((x - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn solve_f64_pivoting() {
+        // a tiny leading element amplifies the rounding errors without partial pivoting
+        let mut a = vec![vec![1e-8, 1.0], vec![1.0, 1.0]];
+        let mut b = vec![1.0, 2.0];
+
+        let x = gaussian_elimination(&mut a, &mut b).unwrap();
+        let expected = [1.0 / (1.0 - 1e-8), (1.0 - 2e-8) / (1.0 - 1e-8)];
+        for (x, y) in x.iter().zip(&expected) {
+            assert!((x - y).abs() < 1e-14);
+        }
+
+        // Hilbert matrix of order 6
+        let n = 6;
+        let mut a: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| 1.0 / (i + j + 1) as f64).collect())
+            .collect();
+        let mut b: Vec<f64> = a.iter().map(|row| row.iter().sum()).collect();
+        let x = gaussian_elimination(&mut a, &mut b).unwrap();
+        assert!(x.iter().all(|x| (x - 1.0).abs() < 1e-6));
+    }
+
+    #[test]
+    fn solve_modint() {
+        type Mint = ModInt1000000007;
+
+        let to_mint = |v: &[i64]| -> Vec<Mint> {
+            v.iter()
+                .map(|&x| Mint::new(x.rem_euclid(1_000_000_007) as u64))
+                .collect()
+        };
+
+        let mut a = vec![
+            to_mint(&[1, 1, 1]),
+            to_mint(&[0, 2, 5]),
+            to_mint(&[2, 5, -1]),
+        ];
+        let mut b = to_mint(&[6, -4, 27]);
+
+        assert_eq!(
+            Some(to_mint(&[5, 3, -2])),
+            gaussian_elimination(&mut a, &mut b)
+        );
+    }
+
+    #[test]
+    fn solve_singular() {
+        let mut a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        let mut b = vec![3.0, 6.0];
+        assert_eq!(None, gaussian_elimination(&mut a, &mut b));
+
+        let mut a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        let mut b = vec![3.0, 7.0];
+        assert_eq!(None, gaussian_elimination(&mut a, &mut b));
+    }
+
+    #[test]
+    fn rank_singular() {
+        let mut a = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+        assert_eq!(2, rank(&mut a));
+
+        let mut a = vec![vec![0.0; 3]; 3];
+        assert_eq!(0, rank(&mut a));
+
+        type Mint = ModInt1000000007;
+        let mut a = vec![
+            vec![Mint::new(1), Mint::new(2)],
+            vec![Mint::new(3), Mint::new(4)],
+            vec![Mint::new(5), Mint::new(6)],
+        ];
+        assert_eq!(2, rank(&mut a));
+    }
+}
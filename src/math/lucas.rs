@@ -0,0 +1,159 @@
+//! Lucas's theorem.
+
+use super::arith::mul_mod;
+use super::combination::Combination;
+use super::util::pow_mod;
+
+/// Computes `C(n, k)` modulo a prime p, using Lucas's theorem
+/// on the base-p digits of n and k.
+/// Each digit binomial is computed as a product of min(b, a - b) terms without tables,
+/// so that a large p costs nothing extra.
+/// Consumes O(min(k, p log_p n) + log p) time.
+/// Use [`Lucas`] to answer many queries with the same p.
+///
+/// [`Lucas`]: struct.Lucas.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::lucas::lucas;
+///
+/// assert_eq!(10 % 3, lucas(5, 2, 3));
+/// assert_eq!(0, lucas(1_000_000_000_000, 1, 2));
+/// assert_eq!(120, lucas(10, 3, 998_244_353));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn lucas(mut n: u64, mut k: u64, p: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+
+    let mut num = 1 % p;
+    let mut den = 1 % p;
+    while k > 0 {
+        let (a, b) = (n % p, k % p);
+        if b > a {
+            return 0;
+        }
+        for i in 0..b.min(a - b) {
+            num = mul_mod(num, a - i, p);
+            den = mul_mod(den, i + 1, p);
+        }
+        n /= p;
+        k /= p;
+    }
+
+    mul_mod(num, pow_mod(den, p - 2, p), p)
+}
+
+/// Computes `C(n, k)` modulo a prime p for many queries, using Lucas's theorem.
+/// Keeps the factorials below p.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::lucas::Lucas;
+///
+/// let lucas = Lucas::new(13);
+/// assert_eq!(0, lucas.com(13, 1));
+/// assert_eq!(6, lucas.com(13 * 4 + 1, 13 * 2));
+/// ```
+pub struct Lucas {
+    comb: Combination,
+}
+
+impl Lucas {
+    /// Constructs the factorial tables for a prime p.
+    /// Consumes O(p) time and space.
+    pub fn new(p: u64) -> Self {
+        Self {
+            comb: Combination::new(p - 1, p),
+        }
+    }
+
+    /// Returns `C(n, k)` modulo p, which is 0 if k > n.
+    /// Consumes O(log n) time.
+    pub fn com(&self, n: u64, k: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+        self.comb.com_lucas(n, k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::gcd::gcd;
+
+    // C(n, k) mod m for small k, cancelling k! against the numerator exactly
+    fn exact(n: u64, k: u64, m: u64) -> u64 {
+        let mut den: u64 = (1..=k).product();
+        let mut nums: Vec<u64> = (0..k).map(|i| n - i).collect();
+        for x in nums.iter_mut() {
+            let g = gcd(*x, den);
+            *x /= g;
+            den /= g;
+        }
+        assert_eq!(1, den);
+        nums.iter().fold(1 % m, |acc, &x| acc * (x % m) % m)
+    }
+
+    #[test]
+    fn small_prime() {
+        assert_eq!(exact(1_000_000_000, 5, 7), lucas(1_000_000_000, 5, 7));
+
+        for &p in &[2, 3, 5, 7, 13] {
+            for n in 0..40 {
+                for k in 0..=n {
+                    assert_eq!(exact(n, k.min(n - k), p), lucas(n, k, p));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_prime() {
+        let p = 10_007;
+        for &n in &[10_006, 10_007, 123_456_789, 1_000_000_000_000] {
+            for k in 0..=5 {
+                assert_eq!(exact(n, k, p), lucas(n, k, p));
+            }
+        }
+        assert_eq!(0, lucas(3, 5, p));
+    }
+
+    #[test]
+    fn prime_row() {
+        // C(p, k) is divisible by p for 0 < k < p
+        for p in (2..100).filter(|&p| (2..p).all(|d| p % d != 0)) {
+            assert_eq!(1, lucas(p, 0, p));
+            assert_eq!(0, lucas(p, 1, p));
+            assert_eq!(1, lucas(p, p, p));
+            assert_eq!(1, lucas(p + 1, 1, p));
+        }
+    }
+
+    #[test]
+    fn ntt_prime() {
+        let p = 998_244_353;
+        assert_eq!(120, lucas(10, 3, p));
+        assert_eq!(exact(123_456_789_012, 4, p), lucas(123_456_789_012, 4, p));
+        // C(p + 5, p + 2) = C(1, 1) C(5, 2)
+        assert_eq!(10, lucas(p + 5, p + 2, p));
+        assert_eq!(0, lucas(p + 1, 2, p));
+    }
+
+    #[test]
+    fn precomputed() {
+        for &p in &[2, 3, 13, 997, 10_007] {
+            let table = Lucas::new(p);
+            for &n in &[0, 1, 12, p - 1, p, p + 3, 123_456_789, 1_000_000_000_000] {
+                for k in 0..=5 {
+                    assert_eq!(lucas(n, k, p), table.com(n, k), "{} {} {}", n, k, p);
+                }
+                assert_eq!(0, table.com(n, n + 1));
+            }
+        }
+    }
+}
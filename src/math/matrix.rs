@@ -0,0 +1,352 @@
+//! Matrix.
+
+use super::num::One;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+/// Matrix over a ring. Elements are 0-indexed.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::matrix::Matrix;
+///
+/// let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+/// let b = Matrix::from_vec(vec![vec![5, 6], vec![7, 8]]);
+///
+/// let c = &a * &b;
+/// assert_eq!(19, c[(0, 0)]);
+/// assert_eq!(50, c[(1, 1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T: Clone + Default> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Vec<T>>,
+}
+
+impl<T: Clone + Default> Matrix<T> {
+    /// Constructs a new zero matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::matrix::Matrix;
+    ///
+    /// let a = Matrix::<i64>::new(2, 3);
+    /// assert_eq!(0, a[(1, 2)]);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![vec![T::default(); cols]; rows],
+        }
+    }
+
+    /// Constructs a new matrix from rows.
+    ///
+    /// # Panics
+    /// Panics if the rows have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!((2, 3), (a.rows(), a.cols()));
+    /// ```
+    pub fn from_vec(data: Vec<Vec<T>>) -> Self {
+        let rows = data.len();
+        let cols = data.first().map_or(0, Vec::len);
+        assert!(data.iter().all(|row| row.len() == cols));
+
+        Self { rows, cols, data }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+}
+
+impl<T: Clone + Default + One> Matrix<T> {
+    /// Constructs the identity matrix of size n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+    /// assert_eq!(a, &a * &Matrix::identity(2));
+    /// ```
+    pub fn identity(n: usize) -> Self {
+        let mut a = Self::new(n, n);
+        (0..n).for_each(|i| a[(i, i)] = T::one());
+        a
+    }
+}
+
+impl<T: Clone + Default + One + Add<Output = T> + Mul<Output = T>> Matrix<T> {
+    /// Raises self to the power of exp, using exponentiation by squaring.
+    /// Consumes O(k^3 log(exp)) time for a k by k matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![vec![1, 1], vec![1, 0]]);
+    /// let b = a.pow(10);
+    ///
+    /// assert_eq!(89, b[(0, 0)]);
+    /// assert_eq!(55, b[(0, 1)]);
+    /// ```
+    pub fn pow(&self, mut exp: u64) -> Self {
+        assert_eq!(self.rows, self.cols, "The matrix is required to be square");
+
+        let mut base = self.clone();
+        let mut acc = Self::identity(self.rows);
+
+        while exp > 0 {
+            if (exp & 1) == 1 {
+                acc = &acc * &base;
+            }
+            exp >>= 1;
+            base = &base * &base;
+        }
+
+        acc
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone
+        + Default
+        + PartialEq
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    /// Takes the inverse, using Gauss-Jordan elimination.
+    /// Returns None if the matrix is singular.
+    /// The elements are required to form a field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{matrix::Matrix, modint::ModInt998244353 as Mint};
+    ///
+    /// let a = Matrix::from_vec(vec![
+    ///     vec![Mint::new(1), Mint::new(2)],
+    ///     vec![Mint::new(3), Mint::new(4)],
+    /// ]);
+    /// assert_eq!(Matrix::identity(2), &a * &a.inv().unwrap());
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn inv(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols, "The matrix is required to be square");
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut b = Self::identity(n).data;
+
+        for col in 0..n {
+            let pivot = (col..n).find(|&row| a[row][col] != T::default())?;
+            a.swap(col, pivot);
+            b.swap(col, pivot);
+
+            let p = a[col][col].clone();
+            for j in 0..n {
+                a[col][j] = a[col][j].clone() / p.clone();
+                b[col][j] = b[col][j].clone() / p.clone();
+            }
+
+            for row in (0..n).filter(|&row| row != col) {
+                let f = a[row][col].clone();
+                if f == T::default() {
+                    continue;
+                }
+                for j in 0..n {
+                    a[row][j] = a[row][j].clone() - f.clone() * a[col][j].clone();
+                    b[row][j] = b[row][j].clone() - f.clone() * b[col][j].clone();
+                }
+            }
+        }
+
+        Some(Self::from_vec(b))
+    }
+}
+
+//
+// Ops
+//
+impl<T: Clone + Default> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i][j]
+    }
+}
+
+impl<T: Clone + Default> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i][j]
+    }
+}
+
+impl<'a, T: Clone + Default + Add<Output = T>> Add for &'a Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Matrix<T> {
+        assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols));
+
+        Matrix::from_vec(
+            self.data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| {
+                    a.iter()
+                        .zip(b)
+                        .map(|(x, y)| x.clone() + y.clone())
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<'a, T: Clone + Default + Add<Output = T> + Mul<Output = T>> Mul for &'a Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows);
+
+        let mut c = Matrix::<T>::new(self.rows, rhs.cols);
+        for (i, a) in self.data.iter().enumerate() {
+            for (x, b) in a.iter().zip(&rhs.data) {
+                for (j, y) in b.iter().enumerate() {
+                    c.data[i][j] = c.data[i][j].clone() + x.clone() * y.clone();
+                }
+            }
+        }
+        c
+    }
+}
+
+impl<T: Clone + Default + Add<Output = T>> Add for Matrix<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<T: Clone + Default + Add<Output = T> + Mul<Output = T>> Mul for Matrix<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::ModInt1000000007;
+
+    #[test]
+    fn mul() {
+        let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::from_vec(vec![vec![0, 1], vec![1, 0]]);
+
+        assert_eq!(Matrix::from_vec(vec![vec![2, 1], vec![4, 3]]), &a * &b);
+        assert_eq!(Matrix::from_vec(vec![vec![3, 4], vec![1, 2]]), &b * &a);
+    }
+
+    #[test]
+    fn mul_non_square() {
+        let a = Matrix::from_vec(vec![vec![1, 2, 3]]);
+        let b = Matrix::from_vec(vec![vec![4], vec![5], vec![6]]);
+
+        assert_eq!(Matrix::from_vec(vec![vec![32]]), &a * &b);
+        assert_eq!((3, 3), ((&b * &a).rows(), (b * a).cols()));
+    }
+
+    #[test]
+    fn add() {
+        let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(Matrix::from_vec(vec![vec![2, 4], vec![6, 8]]), &a + &a);
+    }
+
+    #[test]
+    fn identity() {
+        let a = Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        let e = Matrix::identity(3);
+
+        assert_eq!(a, &a * &e);
+        assert_eq!(a, e * a.clone());
+    }
+
+    #[test]
+    fn pow_fibonacci() {
+        type Mint = ModInt1000000007;
+
+        let fib = |n: u64| {
+            let a = Matrix::from_vec(vec![
+                vec![Mint::new(1), Mint::new(1)],
+                vec![Mint::new(1), Mint::new(0)],
+            ]);
+            let b = a.pow(n);
+            (b[(0, 0)], b[(0, 1)])
+        };
+
+        assert_eq!((Mint::new(1), Mint::new(0)), fib(0));
+        assert_eq!((Mint::new(1), Mint::new(1)), fib(1));
+        assert_eq!((Mint::new(10_946), Mint::new(6765)), fib(20));
+        assert_eq!((Mint::new(107_579_939), Mint::new(517_691_607)), fib(1000));
+        assert_eq!(
+            (Mint::new(680_057_396), Mint::new(209_783_453)),
+            fib(1_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn inv() {
+        type Mint = ModInt1000000007;
+
+        let a = Matrix::from_vec(
+            [[0, 2, 1], [3, 1, 4], [1, 5, 9]]
+                .iter()
+                .map(|row| row.iter().map(|&x| Mint::new(x)).collect())
+                .collect(),
+        );
+        let b = a.inv().unwrap();
+
+        assert_eq!(Matrix::identity(3), &a * &b);
+        assert_eq!(Matrix::identity(3), &b * &a);
+    }
+
+    #[test]
+    fn inv_singular() {
+        type Mint = ModInt1000000007;
+
+        let a = Matrix::from_vec(
+            [[1, 2], [2, 4]]
+                .iter()
+                .map(|row| row.iter().map(|&x| Mint::new(x)).collect())
+                .collect(),
+        );
+        assert_eq!(None, a.inv());
+    }
+}
@@ -0,0 +1,99 @@
+//! Möbius function.
+
+/// Computes μ(n) for all n in [0, limit], using a linear sieve. μ(0) is 0.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::mobius::mobius_sieve;
+///
+/// assert_eq!(vec![0, 1, -1, -1, 0, -1, 1], mobius_sieve(6));
+/// ```
+pub fn mobius_sieve(limit: usize) -> Vec<i8> {
+    let mut mu = vec![0; limit + 1];
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes = vec![];
+    if limit >= 1 {
+        mu[1] = 1;
+    }
+
+    for i in 2..=limit {
+        if !is_composite[i] {
+            primes.push(i);
+            mu[i] = -1;
+        }
+        for &p in primes.iter() {
+            if i * p > limit {
+                break;
+            }
+            is_composite[i * p] = true;
+            if i % p == 0 {
+                mu[i * p] = 0;
+                break;
+            }
+            mu[i * p] = -mu[i];
+        }
+    }
+
+    mu
+}
+
+/// Computes the Mertens function M(n) = μ(1) + ... + μ(n) for all n in [0, limit].
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::mobius::mertens_table;
+///
+/// assert_eq!(vec![0, 1, 0, -1, -1, -2, -1], mertens_table(6));
+/// ```
+pub fn mertens_table(limit: usize) -> Vec<i64> {
+    mobius_sieve(limit)
+        .into_iter()
+        .scan(0, |acc, mu| {
+            *acc += mu as i64;
+            Some(*acc)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let mu = mobius_sieve(1000);
+
+        assert_eq!(1, mu[1]);
+        assert_eq!(-1, mu[997]);
+        assert_eq!(0, mu[961]);
+        assert_eq!(-1, mu[30]);
+        assert_eq!(1, mu[6]);
+        assert_eq!(0, mu[12]);
+        assert_eq!(vec![0], mobius_sieve(0));
+    }
+
+    #[test]
+    fn sum_over_divisors() {
+        let mu = mobius_sieve(1000);
+        for n in 1..=1000 {
+            let sum: i64 = (1..=n).filter(|d| n % d == 0).map(|d| mu[d] as i64).sum();
+            assert_eq!(if n == 1 { 1 } else { 0 }, sum);
+        }
+    }
+
+    #[test]
+    fn mertens() {
+        let mu = mobius_sieve(1000);
+        let m = mertens_table(1000);
+
+        assert_eq!(2, m[1000]);
+        assert!(m
+            .windows(2)
+            .zip(&mu[1..])
+            .all(|(w, &x)| w[1] - w[0] == x as i64));
+    }
+}
@@ -1,6 +1,13 @@
-use std::cmp::{Eq, PartialEq};
-use std::fmt::Debug;
+use super::discrete_log::bsgs;
+use super::gcd::extended_gcd;
+use super::util::pow_mod;
+use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /// Type to hold a value in ModInt.
 pub type ModValue = u64;
@@ -84,9 +91,12 @@ impl<Mod: ModTrait> ModInt<Mod> {
         self.value
     }
 
-    /// Takes the inverse of self, using the extended Euclidean algorithm.
+    /// Takes the inverse of self, using [`extended_gcd`].
     /// The greatest common divisor of `self.value()`
-    /// and the modulus is required to be 1.
+    /// and the modulus is required to be 1,
+    /// and the modulus is required to fit in i64.
+    ///
+    /// [`extended_gcd`]: ../gcd/fn.extended_gcd.html
     ///
     /// # Examples
     ///
@@ -97,34 +107,13 @@ impl<Mod: ModTrait> ModInt<Mod> {
     ///
     /// assert_eq!(Mint::new(500000004), Mint::new(2).inv());
     /// ```
-    #[allow(clippy::many_single_char_names)]
     pub fn inv(self) -> Self {
-        use std::mem::swap;
-
-        assert_ne!(
-            self,
-            Self::new_unchecked(0),
-            "Attempted to take the inverse of 0"
-        );
-
-        let mut a = self.value();
-        let mut b = Mod::MOD;
-        let mut x = Self::new_unchecked(1);
-        let mut y = Self::new_unchecked(0);
-
-        while a != 0 {
-            let q = b / a;
-            b -= a * q;
-            y -= x * Self::new(q);
-            swap(&mut a, &mut b);
-            swap(&mut x, &mut y);
-        }
+        assert_ne!(self.value, 0, "Attempted to take the inverse of 0");
 
-        assert_eq!(a, 0);
-        assert_eq!(b, 1);
-        assert_eq!(x, Self::new_unchecked(0));
+        let (g, x, _) = extended_gcd(self.value as i64, Mod::MOD as i64);
+        assert_eq!(g, 1, "The value is not coprime to the modulus");
 
-        y
+        Self::from(x)
     }
 
     /// Raises self to the power of exp, using exponentiation by squaring.
@@ -142,19 +131,101 @@ impl<Mod: ModTrait> ModInt<Mod> {
     ///     Mint::new(3).pow(5_000_000_000_000_000)
     /// );
     /// ```
-    pub fn pow(self, mut exp: u64) -> Self {
-        let mut base = self;
-        let mut acc = Self::new_unchecked(1);
+    pub fn pow(self, exp: u64) -> Self {
+        Self::new_unchecked(pow_mod(self.value, exp, Mod::MOD))
+    }
 
-        while exp > 0 {
-            if (exp & 1) == 1 {
-                acc *= base;
-            }
-            exp >>= 1;
-            base = base * base;
+    /// Returns the smaller square root by raw value,
+    /// or None if self is a quadratic non-residue,
+    /// using the Tonelli-Shanks algorithm.
+    /// The modulus is required to be a prime.
+    /// Consumes O(log^2 p) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::modint;
+    ///
+    /// type Mint = modint::ModInt998244353;
+    ///
+    /// assert_eq!(Some(Mint::new(2)), Mint::new(4).sqrt());
+    /// assert_eq!(None, Mint::new(3).sqrt());
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn sqrt(self) -> Option<Self> {
+        let p = Mod::MOD;
+        let one = Self::new(1);
+        if self.value == 0 || p == 2 {
+            return Some(self);
         }
+        if self.pow((p - 1) / 2) != one {
+            return None;
+        }
+
+        let s = (p - 1).trailing_zeros();
+        let q = (p - 1) >> s;
+        let z = (2..)
+            .map(Self::new)
+            .find(|z| z.pow((p - 1) / 2) != one)
+            .unwrap();
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow((q + 1) / 2);
+        while t != one {
+            let i = (1..m).find(|&i| t.pow(1 << i) == one).unwrap();
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+
+        Some(if r.value <= p - r.value { r } else { -r })
+    }
 
-        acc
+    /// Finds the smallest non-negative k with `base.pow(k) == target`,
+    /// or returns None if there is no such k,
+    /// using the baby-step giant-step algorithm in [`bsgs`].
+    /// Consumes O(√p) time.
+    ///
+    /// [`bsgs`]: ../discrete_log/fn.bsgs.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::modint;
+    ///
+    /// type Mint = modint::ModInt998244353;
+    ///
+    /// assert_eq!(Some(10), Mint::dlog(Mint::new(3), Mint::new(59049)));
+    /// assert_eq!(None, Mint::dlog(Mint::new(1), Mint::new(2)));
+    /// ```
+    pub fn dlog(base: Self, target: Self) -> Option<u64> {
+        bsgs(base.value, target.value, Mod::MOD)
+    }
+
+    /// Returns the geometric sum `1 + self + self^2 + ... + self^(n - 1)`.
+    /// `self - 1` is required to be invertible unless self is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::modint;
+    ///
+    /// type Mint = modint::ModInt998244353;
+    ///
+    /// assert_eq!(Mint::new(1023), Mint::new(2).geometric_sum(10));
+    /// assert_eq!(Mint::new(5), Mint::new(1).geometric_sum(5));
+    /// ```
+    pub fn geometric_sum(self, n: u64) -> Self {
+        let one = Self::new_unchecked(1);
+        if self == one {
+            Self::new(n)
+        } else {
+            (self.pow(n) - one) / (self - one)
+        }
     }
 }
 
@@ -231,6 +302,109 @@ op_assign_impl! {
     SubAssign, sub_assign, sub
 }
 
+impl<Mod: ModTrait> Sum for ModInt<Mod> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new_unchecked(0), Add::add)
+    }
+}
+
+impl<'a, Mod: ModTrait + 'a> Sum<&'a Self> for ModInt<Mod> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<Mod: ModTrait> Product for ModInt<Mod> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new_unchecked(1), Mul::mul)
+    }
+}
+
+impl<'a, Mod: ModTrait + 'a> Product<&'a Self> for ModInt<Mod> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<Mod: ModTrait> Default for ModInt<Mod> {
+    fn default() -> Self {
+        Self::new_unchecked(0)
+    }
+}
+
+//
+// conversion
+//
+macro_rules! from_signed_impl {
+    ($($t: ty)+) => {
+        $(
+            impl<Mod: ModTrait> From<$t> for ModInt<Mod> {
+                fn from(value: $t) -> Self {
+                    Self::new_unchecked((value as i128).rem_euclid(Mod::MOD as i128) as ModValue)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! from_unsigned_impl {
+    ($($t: ty)+) => {
+        $(
+            impl<Mod: ModTrait> From<$t> for ModInt<Mod> {
+                fn from(value: $t) -> Self {
+                    Self::new_unchecked((value as u128 % Mod::MOD as u128) as ModValue)
+                }
+            }
+        )+
+    };
+}
+
+from_signed_impl!(i32 i64 i128);
+from_unsigned_impl!(u32 u128);
+
+/// The error returned when parsing a ModInt fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseModIntError(ParseIntError);
+
+impl Display for ParseModIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for ParseModIntError {}
+
+/// Parses a nonnegative decimal integer less than 2^64, and reduces it.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::modint::ModInt998244353 as Mint;
+/// use tklib::utils::scan::Scanner;
+///
+/// let mut sc = Scanner::new("998244354 5".as_bytes());
+/// let (a, b): (Mint, Mint) = (sc.read(), sc.read());
+/// assert_eq!((Mint::new(1), Mint::new(5)), (a, b));
+/// ```
+impl<Mod: ModTrait> FromStr for ModInt<Mod> {
+    type Err = ParseModIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ModValue>()
+            .map(Self::new)
+            .map_err(ParseModIntError)
+    }
+}
+
+//
+// formatting
+//
+impl<Mod: ModTrait> Display for ModInt<Mod> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
 //
 // comparison
 //
@@ -242,10 +416,30 @@ impl<Mod: ModTrait> PartialEq for ModInt<Mod> {
 
 impl<Mod: ModTrait> Eq for ModInt<Mod> {}
 
+/// Compares the representatives in [0, MOD), so that ModInt can be kept in ordered collections.
+impl<Mod: ModTrait> PartialOrd for ModInt<Mod> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Mod: ModTrait> Ord for ModInt<Mod> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<Mod: ModTrait> Hash for ModInt<Mod> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
 //
 //  Frequently used modulus
 //
 mod detail {
+    /// The modulus 1000000007.
     #[derive(Debug, Copy, Clone)]
     pub struct Mod1000000007 {}
 
@@ -253,6 +447,7 @@ mod detail {
         const MOD: super::ModValue = 1_000_000_007;
     }
 
+    /// The modulus 998244353.
     #[derive(Debug, Copy, Clone)]
     pub struct Mod998244353 {}
 
@@ -261,11 +456,13 @@ mod detail {
     }
 }
 
+pub use detail::{Mod1000000007, Mod998244353};
+
 /// The ModInt with modulus 1000000007.
-pub type ModInt1000000007 = ModInt<detail::Mod1000000007>;
+pub type ModInt1000000007 = ModInt<Mod1000000007>;
 
 /// The ModInt with modulus 998244353.
-pub type ModInt998244353 = ModInt<detail::Mod998244353>;
+pub type ModInt998244353 = ModInt<Mod998244353>;
 
 #[cfg(test)]
 mod tests {
@@ -333,6 +530,81 @@ mod tests {
         assert_eq!(n, Mint::new(998244352));
     }
 
+    #[test]
+    fn from_signed() {
+        type Mint = ModInt998244353;
+        assert_eq!(Mint::new(998244352), Mint::from(-1_i64));
+        assert_eq!(Mint::new(998244352), Mint::from(-1_i32));
+        assert_eq!(Mint::new(998244352), Mint::from(-1_i128));
+        assert_eq!(Mint::new(0), Mint::from(-998244353_i64));
+        assert_eq!(Mint::new(5), Mint::from(5_i32));
+        assert_eq!(Mint::new(1), Mint::from(998244354_i64));
+
+        assert_eq!(
+            -Mint::new(std::i64::MAX as u64 % 998244353) - Mint::new(1),
+            Mint::from(std::i64::MIN)
+        );
+        assert_eq!(-Mint::new(2147483648), Mint::from(std::i32::MIN));
+        let big = 1_i128 << 100;
+        assert_eq!(Mint::new(2).pow(100), Mint::from(big));
+        assert_eq!(-Mint::new(2).pow(100), Mint::from(-big));
+    }
+
+    #[test]
+    fn from_large_modulus() {
+        // the modulus exceeds the range of i64
+        #[derive(Debug, Copy, Clone)]
+        struct ModLarge {}
+
+        impl ModTrait for ModLarge {
+            const MOD: ModValue = (1 << 63) + 29;
+        }
+
+        type Mint = ModInt<ModLarge>;
+
+        assert_eq!((1 << 63) + 28, Mint::from(-1_i64).value());
+        assert_eq!(29, Mint::from(std::i64::MIN).value());
+        assert_eq!(std::i64::MAX as u64, Mint::from(std::i64::MAX).value());
+        assert_eq!(3363, Mint::from(std::u128::MAX).value());
+    }
+
+    #[test]
+    fn from_unsigned() {
+        type Mint = ModInt1000000007;
+        assert_eq!(Mint::new(7), Mint::from(7_u32));
+        assert_eq!(Mint::new(4294967295), Mint::from(std::u32::MAX));
+        assert_eq!(Mint::new(2).pow(100), Mint::from(1_u128 << 100));
+    }
+
+    #[test]
+    fn display() {
+        use std::io::Write;
+
+        type Mint = ModInt998244353;
+        assert_eq!("998244352", format!("{}", Mint::new(998244352)));
+        assert_eq!("0", Mint::from(-998244353_i64).to_string());
+        assert_eq!("[  3]", format!("[{:>3}]", Mint::new(3)));
+
+        let mut out = std::io::BufWriter::new(vec![]);
+        writeln!(out, "{} {}", Mint::new(1), -Mint::new(1)).unwrap();
+        assert_eq!(b"1 998244352\n".to_vec(), out.into_inner().unwrap());
+    }
+
+    #[test]
+    fn sum_product() {
+        type Mint = ModInt998244353;
+        assert_eq!(Mint::new(15), (0u64..=5).map(Mint::new).sum::<Mint>());
+        assert_eq!(Mint::new(120), (1u64..=5).map(Mint::new).product::<Mint>());
+        assert_eq!(Mint::new(0), (0u64..=5).map(Mint::new).product::<Mint>());
+
+        let v: Vec<Mint> = vec![Mint::new(998244352), Mint::new(2), Mint::new(3)];
+        assert_eq!(Mint::new(4), v.iter().sum::<Mint>());
+        assert_eq!(-Mint::new(6), v.iter().product::<Mint>());
+
+        assert_eq!(Mint::new(0), Vec::<Mint>::new().into_iter().sum::<Mint>());
+        assert_eq!(Mint::new(1), Vec::<Mint>::new().iter().product::<Mint>());
+    }
+
     #[test]
     fn pow() {
         type Mint = ModInt998244353;
@@ -342,4 +614,141 @@ mod tests {
             Mint::new(3).pow(5_000_000_000_000_000)
         );
     }
+
+    #[test]
+    fn sqrt() {
+        type Mint = ModInt998244353;
+        assert_eq!(Some(Mint::new(2)), Mint::new(4).sqrt());
+        assert_eq!(Some(Mint::new(0)), Mint::new(0).sqrt());
+        assert_eq!(Some(Mint::new(1)), Mint::new(1).sqrt());
+        // 3 is a primitive root
+        assert_eq!(None, Mint::new(3).sqrt());
+
+        for x in (0..1000).chain(998_243_000..998_244_353) {
+            let x = Mint::new(x);
+            let r = (x * x).sqrt().unwrap();
+            assert_eq!(x * x, r * r);
+            assert!(r.value() <= (-r).value());
+        }
+
+        // every residue of a small prime
+        #[derive(Debug, Copy, Clone)]
+        struct Mod17 {}
+
+        impl ModTrait for Mod17 {
+            const MOD: ModValue = 17;
+        }
+
+        type Mint17 = ModInt<Mod17>;
+        for a in 0..17 {
+            let root = (0..=8).map(Mint17::new).find(|r| *r * *r == Mint17::new(a));
+            assert_eq!(root, Mint17::new(a).sqrt());
+        }
+
+        #[derive(Debug, Copy, Clone)]
+        struct Mod2 {}
+
+        impl ModTrait for Mod2 {
+            const MOD: ModValue = 2;
+        }
+
+        assert_eq!(Some(ModInt::<Mod2>::new(1)), ModInt::<Mod2>::new(1).sqrt());
+    }
+
+    #[test]
+    fn dlog() {
+        #[derive(Debug, Copy, Clone)]
+        struct Mod13 {}
+
+        impl ModTrait for Mod13 {
+            const MOD: ModValue = 13;
+        }
+
+        type Mint = ModInt<Mod13>;
+
+        for b in 0..13 {
+            for t in 0..13 {
+                let (base, target) = (Mint::new(b), Mint::new(t));
+                let naive = (0..13).find(|&k| base.pow(k) == target);
+                assert_eq!(naive, Mint::dlog(base, target), "{} {}", b, t);
+            }
+        }
+
+        assert_eq!(Some(0), Mint::dlog(Mint::new(0), Mint::new(1)));
+        assert_eq!(Some(1), Mint::dlog(Mint::new(0), Mint::new(0)));
+        assert_eq!(Some(0), Mint::dlog(Mint::new(5), Mint::new(1)));
+        // 3 has order 3 and 2 is not a power of it
+        assert_eq!(None, Mint::dlog(Mint::new(3), Mint::new(2)));
+
+        type Mint1 = ModInt1000000007;
+        let base = Mint1::new(5);
+        let k = 123_456_789;
+        assert_eq!(Some(k), Mint1::dlog(base, base.pow(k)));
+    }
+
+    #[test]
+    fn geometric_sum() {
+        type Mint = ModInt998244353;
+        assert_eq!(Mint::new(1023), Mint::new(2).geometric_sum(10));
+        assert_eq!(Mint::new(0), Mint::new(2).geometric_sum(0));
+        assert_eq!(Mint::new(1), Mint::new(7).geometric_sum(1));
+        assert_eq!(Mint::new(0), Mint::new(1).geometric_sum(0));
+        assert_eq!(Mint::new(10), Mint::new(1).geometric_sum(998_244_363));
+        assert_eq!(Mint::new(0), Mint::new(0).geometric_sum(0));
+        assert_eq!(Mint::new(1), Mint::new(0).geometric_sum(3));
+
+        let r = Mint::new(998_244_352);
+        assert_eq!(Mint::new(0), r.geometric_sum(4));
+        assert_eq!(Mint::new(1), r.geometric_sum(5));
+
+        let r = Mint::new(12_345);
+        let naive = (0..100).fold(Mint::new(0), |acc, i| acc + r.pow(i));
+        assert_eq!(naive, r.geometric_sum(100));
+    }
+
+    #[test]
+    fn hash_map_key() {
+        use std::collections::HashMap;
+
+        type Mint = ModInt998244353;
+        let mut map = HashMap::new();
+        map.insert(Mint::new(5), 1);
+        map.insert(Mint::new(998_244_353 + 7), 2);
+
+        assert_eq!(Some(&1), map.get(&Mint::new(5)));
+        assert_eq!(Some(&2), map.get(&Mint::new(7)));
+        assert_eq!(Some(&2), map.get(&(Mint::new(3) + Mint::new(4))));
+        assert_eq!(None, map.get(&Mint::new(6)));
+    }
+
+    #[test]
+    fn from_str() {
+        type Mint = ModInt998244353;
+        let a: Mint = "998244352".parse().unwrap();
+        assert_eq!(Mint::new(998_244_352), a);
+        assert_eq!("998244352", a.to_string());
+        assert_eq!(Ok(Mint::new(0)), "998244353".parse());
+        assert_eq!(Ok(Mint::new(0)), "0".parse());
+
+        assert!("abc".parse::<Mint>().is_err());
+        assert!("-1".parse::<Mint>().is_err());
+        assert!("".parse::<Mint>().is_err());
+    }
+
+    #[test]
+    fn ord() {
+        use std::collections::BTreeSet;
+
+        type Mint = ModInt998244353;
+        assert_eq!(Mint::new(5), std::cmp::max(Mint::new(3), Mint::new(5)));
+        assert!(Mint::new(998_244_352) > Mint::new(1));
+        assert!(-Mint::new(1) > Mint::new(1));
+
+        let set: BTreeSet<Mint> = [5, 998_244_355, 1, 5, 0]
+            .iter()
+            .map(|&x| Mint::new(x))
+            .collect();
+        let values: Vec<u64> = set.iter().map(|x| x.value()).collect();
+        assert_eq!(vec![0, 1, 2, 5], values);
+    }
 }
@@ -0,0 +1,529 @@
+//! Number Theoretic Transform.
+
+use super::modint::{ModInt, ModTrait, ModValue};
+
+/// Performs the NTT in place.
+/// If `inverse` is true, performs the inverse transform instead.
+/// The modulus is required to be an NTT-friendly prime such as 998244353.
+///
+/// # Panics
+/// Panics if the length of `a` is not a power of 2,
+/// or if it does not divide `Mod::MOD - 1`.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{modint::ModInt998244353 as Mint, ntt};
+///
+/// let a: Vec<Mint> = (1..=4).map(Mint::new).collect();
+///
+/// let mut b = a.clone();
+/// ntt::ntt(&mut b, false);
+/// assert_eq!(Mint::new(10), b[0]);
+///
+/// ntt::ntt(&mut b, true);
+/// assert_eq!(a, b);
+/// ```
+pub fn ntt<Mod: ModTrait>(a: &mut Vec<ModInt<Mod>>, inverse: bool) {
+    transform(a, primitive_root(), inverse);
+}
+
+/// Performs the inverse NTT in place, the same as `ntt(a, true)`.
+///
+/// # Panics
+/// Panics under the same conditions as [`ntt`].
+///
+/// [`ntt`]: fn.ntt.html
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{modint::ModInt998244353 as Mint, ntt};
+///
+/// let a: Vec<Mint> = (1..=8).map(Mint::new).collect();
+///
+/// let mut b = a.clone();
+/// ntt::ntt(&mut b, false);
+/// ntt::intt(&mut b);
+/// assert_eq!(a, b);
+/// ```
+pub fn intt<Mod: ModTrait>(a: &mut Vec<ModInt<Mod>>) {
+    ntt(a, true);
+}
+
+/// Multiplies two polynomials over an NTT-friendly prime modulus such as 998244353.
+/// Consumes O((n + m) log(n + m)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{modint::ModInt998244353 as Mint, ntt};
+///
+/// let a: Vec<Mint> = vec![Mint::new(1), Mint::new(2)];
+/// let b: Vec<Mint> = vec![Mint::new(3), Mint::new(4)];
+///
+/// let c = ntt::convolve(&a, &b);
+/// assert_eq!(vec![Mint::new(3), Mint::new(10), Mint::new(8)], c);
+/// ```
+pub fn convolve<Mod: ModTrait>(a: &[ModInt<Mod>], b: &[ModInt<Mod>]) -> Vec<ModInt<Mod>> {
+    convolve_with_root(a, b, primitive_root())
+}
+
+/// Multiplies two polynomials over an arbitrary modulus.
+/// The coefficients are reduced modulo it first.
+/// Splits each coefficient into halves below s with s^2 >= modulus,
+/// convolves the halves modulo three NTT-friendly primes,
+/// and recovers the results with Garner's algorithm.
+///
+/// # Panics
+/// Panics if min(|a|, |b|) (s - 1)^2 reaches the product of the primes (about 5.9e25),
+/// which holds for any modulus up to 2^60 with length up to the NTT limit 2^24,
+/// and for any modulus with min(|a|, |b|) up to 2^21.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::ntt;
+///
+/// let a = [1_000_000_006, 2];
+/// let b = [1_000_000_006, 3];
+///
+/// let c = ntt::convolve_arbitrary(&a, &b, 1_000_000_007);
+/// assert_eq!(vec![1, 1_000_000_002, 6], c);
+/// ```
+pub fn convolve_arbitrary(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let bits = 64 - (modulus - 1).leading_zeros();
+    let s = 1u128 << ((bits + 1) / 2);
+    let len = a.len().min(b.len()) as u128;
+    assert!(
+        len * (s - 1) * (s - 1) < three_primes_product(),
+        "The modulus and the length are too large"
+    );
+
+    let split = |v: &[u64]| -> (Vec<u64>, Vec<u64>) {
+        v.iter()
+            .map(|&x| {
+                let x = (x % modulus) as u128;
+                ((x / s) as u64, (x % s) as u64)
+            })
+            .unzip()
+    };
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+
+    let m = modulus as u128;
+    let hi = convolve_exact(&a_hi, &b_hi);
+    let mid1 = convolve_exact(&a_hi, &b_lo);
+    let mid2 = convolve_exact(&a_lo, &b_hi);
+    let lo = convolve_exact(&a_lo, &b_lo);
+    let s = s % m;
+    let s2 = s * s % m;
+
+    (0..hi.len())
+        .map(|i| {
+            let mid = (mid1[i] % m + mid2[i] % m) % m;
+            ((hi[i] % m * s2 % m + mid * s % m + lo[i] % m) % m) as u64
+        })
+        .collect()
+}
+
+/// Multiplies two polynomials with non-negative integer coefficients exactly.
+/// Convolves modulo three NTT-friendly primes and recovers the result
+/// with Garner's algorithm.
+///
+/// The exact coefficients must be less than the product of the primes (about 5.9e25),
+/// which holds for coefficients up to about 10^9 and length up to 2^24.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::ntt;
+///
+/// let a = [1_000_000_000, 1];
+/// let b = [1_000_000_000, 2];
+///
+/// let c = ntt::convolve_exact(&a, &b);
+/// assert_eq!(vec![1_000_000_000_000_000_000, 3_000_000_000, 2], c);
+/// ```
+pub fn convolve_exact(a: &[u64], b: &[u64]) -> Vec<u128> {
+    convolve_three_primes(a, b, |&x, m| x % m)
+}
+
+/// Multiplies two polynomials with integer coefficients exactly,
+/// as `convolve_exact` with the results in (-P/2, P/2) for the product P of the primes.
+pub(crate) fn convolve_signed(a: &[i64], b: &[i64]) -> Vec<i128> {
+    let p = three_primes_product();
+    convolve_three_primes(a, b, |&x, m| x.rem_euclid(m as i64) as u64)
+        .into_iter()
+        .map(|x| {
+            if x > p / 2 {
+                x as i128 - p as i128
+            } else {
+                x as i128
+            }
+        })
+        .collect()
+}
+
+fn three_primes_product() -> u128 {
+    use detail::{Mod167772161, Mod469762049, Mod754974721};
+
+    (Mod167772161::MOD as u128 * Mod469762049::MOD as u128) * Mod754974721::MOD as u128
+}
+
+// Convolves modulo three NTT-friendly primes and recovers the result modulo their product
+// with Garner's algorithm. `residue(x, m)` reduces a coefficient modulo m.
+fn convolve_three_primes<T, F: Fn(&T, u64) -> u64>(a: &[T], b: &[T], residue: F) -> Vec<u128> {
+    use detail::{Mod167772161, Mod469762049, Mod754974721};
+
+    let c1 = convolve_residues::<Mod167772161, _, _>(a, b, &residue);
+    let c2 = convolve_residues::<Mod469762049, _, _>(a, b, &residue);
+    let c3 = convolve_residues::<Mod754974721, _, _>(a, b, &residue);
+
+    let m1 = Mod167772161::MOD;
+    let m2 = Mod469762049::MOD;
+
+    let m1_inv_m2 = ModInt::<Mod469762049>::new(m1).inv();
+    let m1m2_inv_m3 = ModInt::<Mod754974721>::new(m1 * m2).inv();
+
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((r1, r2), r3)| {
+            let t1 = ((r2 - ModInt::new(r1.value())) * m1_inv_m2).value();
+            let t2 = ((r3 - ModInt::new(r1.value()) - ModInt::new(m1) * ModInt::new(t1))
+                * m1m2_inv_m3)
+                .value();
+            (r1.value() + m1 * t1) as u128 + (m1 * m2) as u128 * t2 as u128
+        })
+        .collect()
+}
+
+fn convolve_residues<Mod: ModTrait, T, F: Fn(&T, u64) -> u64>(
+    a: &[T],
+    b: &[T],
+    residue: F,
+) -> Vec<ModInt<Mod>> {
+    let a: Vec<ModInt<Mod>> = a
+        .iter()
+        .map(|x| ModInt::new(residue(x, Mod::MOD)))
+        .collect();
+    let b: Vec<ModInt<Mod>> = b
+        .iter()
+        .map(|x| ModInt::new(residue(x, Mod::MOD)))
+        .collect();
+    convolve(&a, &b)
+}
+
+fn primitive_root<Mod: ModTrait>() -> ModInt<Mod> {
+    let g = match Mod::MOD {
+        167_772_161 | 469_762_049 | 998_244_353 => 3,
+        754_974_721 => 11,
+        m => {
+            let mut factors = vec![];
+            let mut n = m - 1;
+            let mut p = 2;
+            while p * p <= n {
+                if n % p == 0 {
+                    factors.push(p);
+                    while n % p == 0 {
+                        n /= p;
+                    }
+                }
+                p += 1;
+            }
+            if n > 1 {
+                factors.push(n);
+            }
+
+            (2..)
+                .find(|&g| {
+                    factors
+                        .iter()
+                        .all(|&q| ModInt::<Mod>::new(g).pow((m - 1) / q) != ModInt::new(1))
+                })
+                .unwrap()
+        }
+    };
+
+    ModInt::new(g)
+}
+
+fn convolve_with_root<Mod: ModTrait>(
+    a: &[ModInt<Mod>],
+    b: &[ModInt<Mod>],
+    g: ModInt<Mod>,
+) -> Vec<ModInt<Mod>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+
+    let mut fa = a.to_vec();
+    fa.resize(n, ModInt::new(0));
+    let mut fb = b.to_vec();
+    fb.resize(n, ModInt::new(0));
+
+    transform(&mut fa, g, false);
+    transform(&mut fb, g, false);
+    fa.iter_mut().zip(&fb).for_each(|(x, &y)| *x *= y);
+    transform(&mut fa, g, true);
+
+    fa.truncate(len);
+    fa
+}
+
+#[allow(clippy::many_single_char_names)]
+fn transform<Mod: ModTrait>(a: &mut [ModInt<Mod>], g: ModInt<Mod>, inverse: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two(), "The length must be a power of 2");
+    assert_eq!(
+        0,
+        (Mod::MOD - 1) % n as u64,
+        "The length is too long for the modulus"
+    );
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = g.pow((Mod::MOD - 1) / len as u64);
+        let w = if inverse { w.inv() } else { w };
+
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wk = ModInt::new(1);
+            for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                let u = *x;
+                let v = *y * wk;
+                *x = u + v;
+                *y = u - v;
+                wk *= w;
+            }
+        }
+
+        len <<= 1;
+    }
+
+    if inverse {
+        let n_inv = ModInt::new(n as u64).inv();
+        a.iter_mut().for_each(|x| *x *= n_inv);
+    }
+}
+
+mod detail {
+    use super::{ModTrait, ModValue};
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct Mod167772161 {}
+
+    impl ModTrait for Mod167772161 {
+        const MOD: ModValue = 167_772_161;
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct Mod469762049 {}
+
+    impl ModTrait for Mod469762049 {
+        const MOD: ModValue = 469_762_049;
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct Mod754974721 {}
+
+    impl ModTrait for Mod754974721 {
+        const MOD: ModValue = 754_974_721;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    type Mint = crate::math::modint::ModInt998244353;
+
+    #[test]
+    fn convolve_small() {
+        let a = vec![Mint::new(1); 2];
+        let b = vec![Mint::new(1); 3];
+
+        let c = convolve(&a, &b);
+        assert_eq!(
+            vec![Mint::new(1), Mint::new(2), Mint::new(2), Mint::new(1)],
+            c
+        );
+    }
+
+    #[test]
+    fn convolve_ones() {
+        let a = vec![Mint::new(1); 3];
+        let c: Vec<Mint> = [1, 2, 3, 2, 1].iter().map(|&x| Mint::new(x)).collect();
+        assert_eq!(c, convolve(&a, &a));
+    }
+
+    #[test]
+    fn convolve_large() {
+        // the product has length 2^20; checks it at a point
+        let n = 1 << 19;
+        let a: Vec<Mint> = random_vec(n, 1, 998_244_353)
+            .into_iter()
+            .map(Mint::new)
+            .collect();
+        let b: Vec<Mint> = random_vec(n, 2, 998_244_353)
+            .into_iter()
+            .map(Mint::new)
+            .collect();
+        let c = convolve(&a, &b);
+        assert_eq!(2 * n - 1, c.len());
+
+        let eval = |f: &[Mint], x: Mint| f.iter().rev().fold(Mint::new(0), |acc, &c| acc * x + c);
+        for &x in &[2, 12_345, 998_244_352] {
+            let x = Mint::new(x);
+            assert_eq!(eval(&a, x) * eval(&b, x), eval(&c, x));
+        }
+    }
+
+    #[test]
+    fn convolve_empty() {
+        assert!(convolve(&[], &[Mint::new(1)]).is_empty());
+    }
+
+    #[test]
+    fn ntt_roundtrip() {
+        let a: Vec<Mint> = (0..64).map(|i| Mint::new(i * i + 7)).collect();
+
+        let mut b = a.clone();
+        ntt(&mut b, false);
+        assert_ne!(a, b);
+
+        ntt(&mut b, true);
+        assert_eq!(a, b);
+
+        ntt(&mut b, false);
+        intt(&mut b);
+        assert_eq!(a, b);
+    }
+
+    fn convolve_naive(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        let mut c = vec![0; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] = (c[i + j] + x * y % modulus) % modulus;
+            }
+        }
+        c
+    }
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, modulus)).collect()
+    }
+
+    #[test]
+    fn convolve_arbitrary_random() {
+        const MOD: u64 = 1_000_000_007;
+
+        for &(n, m) in &[(1, 1), (3, 5), (100, 77), (256, 256)] {
+            let a = random_vec(n, n as u64, MOD);
+            let b = random_vec(m, m as u64 + 1, MOD);
+            assert_eq!(convolve_naive(&a, &b, MOD), convolve_arbitrary(&a, &b, MOD));
+        }
+    }
+
+    #[test]
+    fn convolve_arbitrary_long() {
+        const MOD: u64 = 1_000_000_007;
+
+        let a = vec![MOD - 1; (1 << 17) - 8];
+        let b = random_vec(8, 42, MOD);
+        assert_eq!(convolve_naive(&a, &b, MOD), convolve_arbitrary(&a, &b, MOD));
+    }
+
+    #[test]
+    fn convolve_arbitrary_unreduced() {
+        const MOD: u64 = 1_000_000_007;
+
+        // the inputs exceed the modulus, and their exact products exceed the three primes
+        let a = random_vec(1000, 7, std::u64::MAX);
+        let b = random_vec(1000, 8, std::u64::MAX);
+        let a_reduced: Vec<u64> = a.iter().map(|&x| x % MOD).collect();
+        let b_reduced: Vec<u64> = b.iter().map(|&x| x % MOD).collect();
+        assert_eq!(
+            convolve_naive(&a_reduced, &b_reduced, MOD),
+            convolve_arbitrary(&a, &b, MOD)
+        );
+    }
+
+    #[test]
+    fn convolve_arbitrary_large_modulus() {
+        fn naive(a: &[u64], b: &[u64], m: u64) -> Vec<u64> {
+            let m = m as u128;
+            let mut c = vec![0; a.len() + b.len() - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    c[i + j] = (c[i + j] + x as u128 % m * (y as u128 % m)) % m;
+                }
+            }
+            c.into_iter().map(|x| x as u64).collect()
+        }
+
+        for &m in &[(1 << 61) - 1, std::u64::MAX - 58, std::u64::MAX, 2, 1] {
+            let a = random_vec(300, 3, std::u64::MAX);
+            let b = random_vec(200, 4, std::u64::MAX);
+            assert_eq!(naive(&a, &b, m), convolve_arbitrary(&a, &b, m));
+        }
+        assert!(convolve_arbitrary(&[], &[1], 5).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn convolve_arbitrary_too_long() {
+        let a = vec![1; 1 << 22];
+        convolve_arbitrary(&a, &a, std::u64::MAX);
+    }
+
+    #[test]
+    fn convolve_other_prime() {
+        #[derive(Debug, Copy, Clone)]
+        struct Mod17 {}
+
+        impl ModTrait for Mod17 {
+            const MOD: ModValue = 17;
+        }
+
+        type Mint17 = ModInt<Mod17>;
+
+        let a: Vec<Mint17> = (1..=3).map(Mint17::new).collect();
+        let b: Vec<Mint17> = (4..=6).map(Mint17::new).collect();
+        let c: Vec<Mint17> = [4, 13, 28, 27, 18]
+            .iter()
+            .map(|&x| Mint17::new(x))
+            .collect();
+        assert_eq!(c, convolve(&a, &b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ntt_not_power_of_two() {
+        let mut a = vec![Mint::new(1); 3];
+        ntt(&mut a, false);
+    }
+}
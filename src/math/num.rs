@@ -0,0 +1,84 @@
+//! Numeric traits.
+
+use super::modint::{ModInt, ModTrait};
+
+/// Types with the additive identity.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::num::Zero;
+///
+/// assert_eq!(0, i64::zero());
+/// assert!(0.0.is_zero());
+/// ```
+pub trait Zero: Sized {
+    fn zero() -> Self;
+
+    fn is_zero(&self) -> bool;
+}
+
+/// Types with the multiplicative identity.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{modint::ModInt998244353 as Mint, num::One};
+///
+/// assert_eq!(1, u32::one());
+/// assert_eq!(Mint::new(1), Mint::one());
+/// ```
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+macro_rules! num_impl {
+    ($($t: ty, $zero: expr, $one: expr)+) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                #[allow(clippy::float_cmp)]
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )+
+    };
+}
+
+num_impl! {
+    i32, 0, 1
+    i64, 0, 1
+    i128, 0, 1
+    isize, 0, 1
+    u32, 0, 1
+    u64, 0, 1
+    u128, 0, 1
+    usize, 0, 1
+    f64, 0.0, 1.0
+}
+
+impl<Mod: ModTrait> Zero for ModInt<Mod> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value() == 0
+    }
+}
+
+impl<Mod: ModTrait> One for ModInt<Mod> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
@@ -0,0 +1,100 @@
+//! Partition numbers.
+
+/// Computes the partition numbers `p(0), ..., p(limit)` modulo `modulus`,
+/// using Euler's pentagonal number theorem
+/// `p(n) = sum_{k != 0} (-1)^(k + 1) p(n - k (3k - 1) / 2)`.
+/// Consumes O(limit^1.5) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::partition::partition_table;
+///
+/// assert_eq!(vec![1, 1, 2, 3, 5, 7], partition_table(5, 1_000_000_007));
+/// ```
+pub fn partition_table(limit: u64, modulus: u64) -> Vec<u64> {
+    let n = limit as usize;
+    let mut p = vec![0; n + 1];
+    p[0] = 1 % modulus;
+
+    for i in 1..=n {
+        let mut acc = 0;
+        for k in 1.. {
+            // the generalized pentagonal numbers k (3k - 1) / 2 and k (3k + 1) / 2
+            let a = k * (3 * k - 1) / 2;
+            if a > i {
+                break;
+            }
+            let mut term = p[i - a];
+            let b = k * (3 * k + 1) / 2;
+            if b <= i {
+                term = (term + p[i - b]) % modulus;
+            }
+
+            acc = if k % 2 == 1 {
+                (acc + term) % modulus
+            } else {
+                (acc + modulus - term) % modulus
+            };
+        }
+        p[i] = acc;
+    }
+
+    p
+}
+
+/// Computes the partition number `p(n)` modulo `modulus`.
+/// Consumes O(n^1.5) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::partition::partition_count;
+///
+/// assert_eq!(42, partition_count(10, 1_000_000_007));
+/// ```
+pub fn partition_count(n: u64, modulus: u64) -> u64 {
+    partition_table(n, modulus)[n as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small() {
+        assert_eq!(
+            vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42],
+            partition_table(10, 1_000_000_007)
+        );
+        assert_eq!(vec![1], partition_table(0, 1_000_000_007));
+        assert_eq!(vec![0, 0, 0], partition_table(2, 1));
+    }
+
+    #[test]
+    fn naive() {
+        // the coin change DP over all part sizes
+        let n = 60;
+        let mut dp = vec![0u64; n + 1];
+        dp[0] = 1;
+        for part in 1..=n {
+            for s in part..=n {
+                dp[s] += dp[s - part];
+            }
+        }
+
+        assert_eq!(
+            dp.iter().map(|&x| x % 1009).collect::<Vec<_>>(),
+            partition_table(n as u64, 1009)
+        );
+    }
+
+    #[test]
+    fn large() {
+        assert_eq!(190_569_292, partition_count(100, 1_000_000_007));
+        assert_eq!(
+            24_061_467_864_032_622_473_692_149_727_991 % 1_000_000_007u128,
+            partition_count(1000, 1_000_000_007) as u128
+        );
+    }
+}
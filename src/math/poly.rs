@@ -0,0 +1,892 @@
+//! Polynomials over ModInt.
+
+use super::modint::{ModInt, ModTrait};
+use super::ntt;
+use super::prime::is_prime;
+use std::ops::{Add, Index, Mul, Neg, Sub};
+
+/// Polynomial over ModInt.
+/// The coefficients are kept in ascending order of degree, without trailing zeros.
+///
+/// Multiplication uses the NTT when the modulus is NTT-friendly for the length,
+/// and the naive O(nm) method otherwise.
+/// The other power series operations require an NTT-friendly modulus.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+///
+/// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(1)]);
+/// let g = &f * &f;
+///
+/// assert_eq!(2, g.degree());
+/// assert_eq!(Mint::new(9), g.evaluate(Mint::new(2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Polynomial<Mod: ModTrait> {
+    coef: Vec<ModInt<Mod>>,
+}
+
+impl<Mod: ModTrait> Polynomial<Mod> {
+    /// Constructs a new polynomial from coefficients in ascending order of degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // 1 + 2x + 3x^2
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// assert_eq!(Mint::new(2), f[1]);
+    /// ```
+    pub fn from_coefficients(mut v: Vec<ModInt<Mod>>) -> Self {
+        while v.last() == Some(&ModInt::new(0)) {
+            v.pop();
+        }
+        Self { coef: v }
+    }
+
+    /// Returns the coefficients in ascending order of degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(0)]);
+    /// assert_eq!(&[Mint::new(1), Mint::new(2)], f.coefficients());
+    /// ```
+    pub fn coefficients(&self) -> &[ModInt<Mod>] {
+        &self.coef
+    }
+
+    /// Returns the coefficient of x^i. Returns 0 if i exceeds the degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2)]);
+    /// assert_eq!(Mint::new(2), f.coef(1));
+    /// assert_eq!(Mint::new(0), f.coef(5));
+    /// ```
+    pub fn coef(&self, i: usize) -> ModInt<Mod> {
+        self.coef.get(i).copied().unwrap_or_else(|| ModInt::new(0))
+    }
+
+    /// Returns the degree. The degree of the zero polynomial is regarded as 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// assert_eq!(2, f.degree());
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.coef.len().saturating_sub(1)
+    }
+
+    /// Evaluates the polynomial at x, using Horner's method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // 1 + 2x + 3x^2
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// assert_eq!(Mint::new(17), f.evaluate(Mint::new(2)));
+    /// ```
+    pub fn evaluate(&self, x: ModInt<Mod>) -> ModInt<Mod> {
+        self.coef
+            .iter()
+            .rev()
+            .fold(ModInt::new(0), |acc, &c| acc * x + c)
+    }
+
+    /// Returns the polynomial modulo x^n.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// assert_eq!(1, f.truncate(2).degree());
+    /// ```
+    pub fn truncate(&self, n: usize) -> Self {
+        Self::from_coefficients(self.coef.iter().take(n).copied().collect())
+    }
+
+    /// Returns g such that f * g = 1 (mod x^n), using Newton's method.
+    /// Consumes O(n log n) time.
+    ///
+    /// # Panics
+    /// Panics if the constant term is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // 1 / (1 - x) = 1 + x + x^2 + ...
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), -Mint::new(1)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(1); 4]);
+    ///
+    /// assert_eq!(g, f.inv(4));
+    /// ```
+    pub fn inv(&self, n: usize) -> Self {
+        assert_ne!(
+            self.coef(0),
+            ModInt::new(0),
+            "Attempted to take the inverse of a series with no constant term"
+        );
+
+        let two = Self::from_coefficients(vec![ModInt::new(2)]);
+        let mut g = Self::from_coefficients(vec![self.coef[0].inv()]);
+        let mut k = 1;
+
+        while k < n {
+            k *= 2;
+            let fg = (&self.truncate(k) * &g).truncate(k);
+            g = (&g * &(&two - &fg)).truncate(k);
+        }
+
+        g.truncate(n)
+    }
+
+    /// Returns the derivative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // (1 + 2x + 3x^2)' = 2 + 6x
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(2), Mint::new(6)]);
+    ///
+    /// assert_eq!(g, f.differentiate());
+    /// ```
+    pub fn differentiate(&self) -> Self {
+        Self::from_coefficients(
+            self.coef
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * ModInt::new(i as u64))
+                .collect(),
+        )
+    }
+
+    /// Returns the integral whose constant term is `constant`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // integral of (2 + 6x) = 1 + 2x + 3x^2, with the constant 1
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(2), Mint::new(6)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    ///
+    /// assert_eq!(g, f.integrate(Mint::new(1)));
+    /// ```
+    pub fn integrate(&self, constant: ModInt<Mod>) -> Self {
+        Self::from_coefficients(
+            std::iter::once(constant)
+                .chain(
+                    self.coef
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &c)| c * ModInt::new(i as u64 + 1).inv()),
+                )
+                .collect(),
+        )
+    }
+
+    /// Returns log(f) (mod x^n).
+    /// Consumes O(n log n) time.
+    ///
+    /// # Panics
+    /// Panics if the constant term is not 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // log(1 / (1 - x)) = x + x^2 / 2 + x^3 / 3 + ...
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1); 4]);
+    /// let g = f.log(4);
+    ///
+    /// assert_eq!(Mint::new(0), g[0]);
+    /// assert_eq!(Mint::new(1), g[1]);
+    /// assert_eq!(Mint::new(1) / Mint::new(2), g[2]);
+    /// assert_eq!(Mint::new(1) / Mint::new(3), g[3]);
+    /// ```
+    pub fn log(&self, n: usize) -> Self {
+        assert_eq!(
+            self.coef(0),
+            ModInt::new(1),
+            "The constant term is required to be 1"
+        );
+
+        if n == 0 {
+            return Self::from_coefficients(vec![]);
+        }
+
+        (&self.differentiate() * &self.inv(n))
+            .truncate(n - 1)
+            .integrate(ModInt::new(0))
+    }
+
+    /// Returns exp(f) (mod x^n), using Newton's method.
+    /// Consumes O(n log n) time.
+    ///
+    /// # Panics
+    /// Panics if the constant term is not 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // exp(2x) = 1 + 2x + 2x^2 + ...
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(0), Mint::new(2)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(2)]);
+    ///
+    /// assert_eq!(g, f.exp(3));
+    /// ```
+    pub fn exp(&self, n: usize) -> Self {
+        assert_eq!(
+            self.coef(0),
+            ModInt::new(0),
+            "The constant term is required to be 0"
+        );
+
+        let one = Self::from_coefficients(vec![ModInt::new(1)]);
+        let mut g = one.clone();
+        let mut k = 1;
+
+        while k < n {
+            k *= 2;
+            let h = &(&one - &g.log(k)) + &self.truncate(k);
+            g = (&g * &h).truncate(k);
+        }
+
+        g.truncate(n)
+    }
+
+    /// Returns g such that g^2 = f (mod x^n), using Newton's method,
+    /// or None if there is no such g.
+    /// The lowest non-zero coefficient of g is chosen to be the smaller square root.
+    /// The modulus is required to be a prime.
+    /// Consumes O(n log n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // sqrt(4 + 4x + x^2) = 2 + x
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(4), Mint::new(4), Mint::new(1)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(2), Mint::new(1)]);
+    ///
+    /// assert_eq!(Some(g), f.sqrt(5));
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let d = match self.coef.iter().take(n).position(|&c| c != ModInt::new(0)) {
+            Some(d) => d,
+            None => return Some(Self::from_coefficients(vec![])),
+        };
+        if d % 2 == 1 {
+            return None;
+        }
+
+        // sqrt(f) = x^(d / 2) sqrt(f / x^d)
+        let m = n - d / 2;
+        let f = Self::from_coefficients(self.coef[d..].to_vec());
+
+        let inv2 = Self::from_coefficients(vec![ModInt::new(2).inv()]);
+        let mut g = Self::from_coefficients(vec![f.coef[0].sqrt()?]);
+        let mut k = 1;
+
+        while k < m {
+            k *= 2;
+            let h = (&f.truncate(k) * &g.inv(k)).truncate(k);
+            g = &(&g + &h) * &inv2;
+        }
+
+        let mut coef = vec![ModInt::new(0); d / 2];
+        coef.extend(g.truncate(m).coef);
+        Some(Self::from_coefficients(coef))
+    }
+
+    fn reverse(&self, len: usize) -> Self {
+        let mut coef = self.coef.clone();
+        coef.resize(len, ModInt::new(0));
+        coef.reverse();
+        Self::from_coefficients(coef)
+    }
+
+    /// Returns the quotient and the remainder of the division by `rhs`.
+    /// Consumes O(n log n) time.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // x^3 + 2 = (x^2 - 1) x + (x + 2)
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(2), Mint::new(0), Mint::new(0), Mint::new(1)]);
+    /// let g = Polynomial::from_coefficients(vec![-Mint::new(1), Mint::new(0), Mint::new(1)]);
+    ///
+    /// let (q, r) = f.div_rem(&g);
+    /// assert_eq!(Polynomial::from_coefficients(vec![Mint::new(0), Mint::new(1)]), q);
+    /// assert_eq!(Polynomial::from_coefficients(vec![Mint::new(2), Mint::new(1)]), r);
+    /// ```
+    #[allow(clippy::many_single_char_names)]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.coef.is_empty(), "Attempted to divide by zero");
+
+        let (n, m) = (self.coef.len(), rhs.coef.len());
+        if n < m {
+            return (Self::from_coefficients(vec![]), self.clone());
+        }
+
+        // reversing turns the division into a multiplication by the inverse series
+        let k = n - m + 1;
+        let q = (&self.reverse(n).truncate(k) * &rhs.reverse(m).inv(k))
+            .truncate(k)
+            .reverse(k);
+        let r = self - &(rhs * &q);
+
+        (q, r)
+    }
+
+    /// Returns the monic greatest common divisor, using the Euclidean algorithm.
+    /// The GCD of two zero polynomials is zero.
+    /// Consumes O(n^2 log n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // gcd(x^2 - 1, x^2 - x) = x - 1
+    /// let f = Polynomial::from_coefficients(vec![-Mint::new(1), Mint::new(0), Mint::new(1)]);
+    /// let g = Polynomial::from_coefficients(vec![Mint::new(0), -Mint::new(1), Mint::new(1)]);
+    ///
+    /// assert_eq!(Polynomial::from_coefficients(vec![-Mint::new(1), Mint::new(1)]), f.gcd(&g));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.coef.is_empty() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+
+        match a.coef.last() {
+            Some(&lead) => {
+                let inv = lead.inv();
+                Self::from_coefficients(a.coef.iter().map(|&c| c * inv).collect())
+            }
+            None => a,
+        }
+    }
+
+    /// Evaluates the polynomial at each point, using a subproduct tree.
+    /// Consumes O(n log^2 n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::math::{modint::ModInt998244353 as Mint, poly::Polynomial};
+    ///
+    /// // 1 + 2x + 3x^2
+    /// let f = Polynomial::from_coefficients(vec![Mint::new(1), Mint::new(2), Mint::new(3)]);
+    /// let points = vec![Mint::new(0), Mint::new(1), Mint::new(2)];
+    ///
+    /// assert_eq!(vec![Mint::new(1), Mint::new(6), Mint::new(17)], f.multipoint_eval(&points));
+    /// ```
+    pub fn multipoint_eval(&self, points: &[ModInt<Mod>]) -> Vec<ModInt<Mod>> {
+        if points.is_empty() {
+            return vec![];
+        }
+
+        let m = points.len();
+        let mut tree = vec![Self::from_coefficients(vec![]); 4 * m];
+        build_subproduct_tree(&mut tree, points, 1, 0, m);
+
+        let mut res = Vec::with_capacity(m);
+        evaluate_subproduct_tree(&tree, points, self.clone(), 1, 0, m, &mut res);
+        res
+    }
+}
+
+const NAIVE_EVAL_THRESHOLD: usize = 32;
+
+// tree[k] is the product of (x - p) for p in points[l..r]
+fn build_subproduct_tree<Mod: ModTrait>(
+    tree: &mut [Polynomial<Mod>],
+    points: &[ModInt<Mod>],
+    k: usize,
+    l: usize,
+    r: usize,
+) {
+    if r - l == 1 {
+        tree[k] = Polynomial::from_coefficients(vec![-points[l], ModInt::new(1)]);
+        return;
+    }
+
+    let mid = (l + r) / 2;
+    build_subproduct_tree(tree, points, 2 * k, l, mid);
+    build_subproduct_tree(tree, points, 2 * k + 1, mid, r);
+    tree[k] = &tree[2 * k] * &tree[2 * k + 1];
+}
+
+fn evaluate_subproduct_tree<Mod: ModTrait>(
+    tree: &[Polynomial<Mod>],
+    points: &[ModInt<Mod>],
+    f: Polynomial<Mod>,
+    k: usize,
+    l: usize,
+    r: usize,
+    res: &mut Vec<ModInt<Mod>>,
+) {
+    let f = f.div_rem(&tree[k]).1;
+    if r - l <= NAIVE_EVAL_THRESHOLD {
+        res.extend(points[l..r].iter().map(|&x| f.evaluate(x)));
+        return;
+    }
+
+    let mid = (l + r) / 2;
+    evaluate_subproduct_tree(tree, points, f.clone(), 2 * k, l, mid, res);
+    evaluate_subproduct_tree(tree, points, f, 2 * k + 1, mid, r, res);
+}
+
+//
+// Ops
+//
+impl<Mod: ModTrait> Index<usize> for Polynomial<Mod> {
+    type Output = ModInt<Mod>;
+
+    fn index(&self, i: usize) -> &ModInt<Mod> {
+        &self.coef[i]
+    }
+}
+
+impl<'a, Mod: ModTrait> Add for &'a Polynomial<Mod> {
+    type Output = Polynomial<Mod>;
+
+    fn add(self, rhs: Self) -> Polynomial<Mod> {
+        let n = self.coef.len().max(rhs.coef.len());
+        Polynomial::from_coefficients((0..n).map(|i| self.coef(i) + rhs.coef(i)).collect())
+    }
+}
+
+impl<'a, Mod: ModTrait> Mul for &'a Polynomial<Mod> {
+    type Output = Polynomial<Mod>;
+
+    fn mul(self, rhs: Self) -> Polynomial<Mod> {
+        Polynomial::from_coefficients(multiply(&self.coef, &rhs.coef))
+    }
+}
+
+// Uses the NTT if the modulus is a prime allowing the length, and the naive method otherwise.
+fn multiply<Mod: ModTrait>(a: &[ModInt<Mod>], b: &[ModInt<Mod>]) -> Vec<ModInt<Mod>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let len = a.len() + b.len() - 1;
+    if (Mod::MOD - 1) % len.next_power_of_two() as u64 == 0 && is_prime(Mod::MOD) {
+        return ntt::convolve(a, b);
+    }
+
+    let mut c = vec![ModInt::new(0); len];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            c[i + j] += x * y;
+        }
+    }
+    c
+}
+
+impl<'a, Mod: ModTrait> Neg for &'a Polynomial<Mod> {
+    type Output = Polynomial<Mod>;
+
+    fn neg(self) -> Polynomial<Mod> {
+        Polynomial::from_coefficients(self.coef.iter().map(|&c| -c).collect())
+    }
+}
+
+impl<'a, Mod: ModTrait> Sub for &'a Polynomial<Mod> {
+    type Output = Polynomial<Mod>;
+
+    fn sub(self, rhs: Self) -> Polynomial<Mod> {
+        let n = self.coef.len().max(rhs.coef.len());
+        Polynomial::from_coefficients((0..n).map(|i| self.coef(i) - rhs.coef(i)).collect())
+    }
+}
+
+impl<Mod: ModTrait> Neg for Polynomial<Mod> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+macro_rules! forward_op_impl {
+    ($($trait: ident, $op: ident)+) => {
+        $(
+            impl<Mod: ModTrait> $trait for Polynomial<Mod> {
+                type Output = Self;
+
+                fn $op(self, rhs: Self) -> Self {
+                    (&self).$op(&rhs)
+                }
+            }
+        )+
+    };
+}
+
+forward_op_impl! {
+    Add, add
+    Mul, mul
+    Sub, sub
+}
+
+//
+// comparison
+//
+impl<Mod: ModTrait> PartialEq for Polynomial<Mod> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coef == other.coef
+    }
+}
+
+impl<Mod: ModTrait> Eq for Polynomial<Mod> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::{Mod998244353, ModInt998244353};
+    use crate::utils::random::XorShift64;
+
+    type Mint = ModInt998244353;
+    type Poly = Polynomial<Mod998244353>;
+
+    fn poly(v: &[u64]) -> Poly {
+        Poly::from_coefficients(v.iter().map(|&x| Mint::new(x)).collect())
+    }
+
+    #[test]
+    fn mul_binomial() {
+        let f = poly(&[1, 1]);
+        let g = (0..3).fold(f.clone(), |acc, _| acc * f.clone());
+
+        assert_eq!(poly(&[1, 4, 6, 4, 1]), g);
+
+        let cube = f.clone() * f.clone() * f;
+        assert_eq!(poly(&[1, 3, 3, 1]), cube);
+        assert_eq!(3, cube.degree());
+    }
+
+    #[test]
+    fn mul_not_ntt_friendly() {
+        use crate::math::modint::{Mod1000000007, ModInt1000000007};
+        type Mint = ModInt1000000007;
+        type Poly = Polynomial<Mod1000000007>;
+
+        let mut rng = XorShift64::new(7);
+        let mut random_poly =
+            |n| Poly::from_coefficients((0..n).map(|_| Mint::new(rng.next())).collect::<Vec<_>>());
+        let f = random_poly(40);
+        let g = random_poly(30);
+        let h = &f * &g;
+        for x in 0..10 {
+            let x = Mint::new(x);
+            assert_eq!(f.evaluate(x) * g.evaluate(x), h.evaluate(x));
+        }
+
+        // x + 1 is small enough for the NTT over 10^9 + 7
+        let f = Poly::from_coefficients(vec![Mint::new(1), Mint::new(1)]);
+        let cube = &(&f * &f) * &f;
+        assert_eq!(Mint::new(3), cube.coef(2));
+        assert_eq!(Mint::new(8), cube.evaluate(Mint::new(1)));
+    }
+
+    #[test]
+    fn mul_composite_modulus() {
+        #[derive(Debug, Copy, Clone)]
+        struct Mod9 {}
+
+        impl ModTrait for Mod9 {
+            const MOD: crate::math::modint::ModValue = 9;
+        }
+
+        type Mint = ModInt<Mod9>;
+        type Poly = Polynomial<Mod9>;
+
+        // 8 is divisible by the transform lengths 2, 4 and 8, but 9 is not a prime
+        let f = Poly::from_coefficients(vec![Mint::new(1), Mint::new(1)]);
+        let square = &f * &f;
+        assert_eq!(
+            &[Mint::new(1), Mint::new(2), Mint::new(1)],
+            square.coefficients()
+        );
+
+        let cube = &square * &f;
+        assert_eq!(
+            &[Mint::new(1), Mint::new(3), Mint::new(3), Mint::new(1)],
+            cube.coefficients()
+        );
+    }
+
+    #[test]
+    fn add_sub_neg() {
+        let f = poly(&[1, 2, 3]);
+        let g = poly(&[3, 2, 1, 5]);
+
+        assert_eq!(poly(&[4, 4, 4, 5]), &f + &g);
+        assert_eq!(poly(&[2, 0, 998_244_351]), &g - &poly(&[1, 2, 3, 5]));
+        assert_eq!(f, &(&f - &g) + &g);
+        assert_eq!(poly(&[]), -f.clone() + f);
+    }
+
+    #[test]
+    fn evaluate() {
+        let f = poly(&[5, 0, 1]);
+        assert_eq!(Mint::new(5), f.evaluate(Mint::new(0)));
+        assert_eq!(Mint::new(14), f.evaluate(Mint::new(3)));
+        assert_eq!(Mint::new(0), poly(&[]).evaluate(Mint::new(3)));
+    }
+
+    #[test]
+    fn inv() {
+        let one = poly(&[1]);
+
+        for &d in &[1, 10, 100] {
+            let f = poly(&(0..=d).map(|i| i * i + 1).collect::<Vec<_>>());
+
+            for &n in &[1, 2, 7, 64, 150] {
+                assert_eq!(one, (&f * &f.inv(n)).truncate(n));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn inv_no_constant_term() {
+        let _ = poly(&[0, 1]).inv(4);
+    }
+
+    #[test]
+    fn differentiate_integrate() {
+        let f = poly(&[7, 3, 1, 4, 1, 5, 9, 2, 6]);
+
+        assert_eq!(f, f.differentiate().integrate(Mint::new(7)));
+        assert_eq!(&f - &poly(&[7]), f.differentiate().integrate(Mint::new(0)));
+        assert_eq!(f, f.integrate(Mint::new(5)).differentiate());
+    }
+
+    #[test]
+    fn differentiate_constant() {
+        assert_eq!(poly(&[]), poly(&[3]).differentiate());
+        assert_eq!(poly(&[]), poly(&[]).differentiate());
+    }
+
+    #[test]
+    fn log_one_plus_x() {
+        let f = poly(&[1, 1]);
+        let g = Poly::from_coefficients(vec![
+            Mint::new(0),
+            Mint::new(1),
+            -Mint::new(1) / Mint::new(2),
+            Mint::new(1) / Mint::new(3),
+            -Mint::new(1) / Mint::new(4),
+            Mint::new(1) / Mint::new(5),
+        ]);
+
+        assert_eq!(g, f.log(6));
+    }
+
+    #[test]
+    fn log_derivative() {
+        let n = 50;
+        let f = poly(
+            &(0..40)
+                .map(|i| if i == 0 { 1 } else { i * 3 + 2 })
+                .collect::<Vec<_>>(),
+        );
+        let g = f.log(n);
+
+        assert_eq!(
+            f.differentiate().truncate(n - 1),
+            (&g.differentiate() * &f).truncate(n - 1)
+        );
+    }
+
+    #[test]
+    fn exp_x() {
+        let f = poly(&[0, 1]);
+        let g = Poly::from_coefficients(
+            [1, 1, 2, 6, 24, 120, 720]
+                .iter()
+                .map(|&x| Mint::new(x).inv())
+                .collect(),
+        );
+
+        assert_eq!(g, f.exp(7));
+    }
+
+    #[test]
+    fn exp_log() {
+        let n = 100;
+        let f = poly(&(0..80).map(|i| i * i + 3 * i).collect::<Vec<_>>());
+        assert_eq!(f, f.exp(n).log(n).truncate(n));
+
+        let f = poly(&(0..80).map(|i| 7 * i + 1).collect::<Vec<_>>());
+        assert_eq!(f, f.log(n).exp(n).truncate(n));
+    }
+
+    #[test]
+    fn truncate() {
+        let f = poly(&[1, 2, 0, 4]);
+        assert_eq!(poly(&[1, 2]), f.truncate(3));
+        assert_eq!(f, f.truncate(10));
+        assert_eq!(poly(&[]), f.truncate(0));
+    }
+
+    #[test]
+    fn sqrt_square() {
+        let f = poly(&[1, 1]);
+        assert_eq!(Some(f.clone()), (&f * &f).sqrt(2));
+        assert_eq!(Some(f.clone()), (&f * &f).sqrt(10));
+
+        let g = poly(&[998_244_352, 5, 7]);
+        let h = (&g * &g).sqrt(3).unwrap();
+        assert!(h == g || h == -g);
+    }
+
+    #[test]
+    fn sqrt_random() {
+        let n = 100;
+        for &c in &[1, 4, 2, 5] {
+            let f = poly(
+                &(0..70)
+                    .map(|i| if i == 0 { c } else { i * i + 7 })
+                    .collect::<Vec<_>>(),
+            );
+            match f.sqrt(n) {
+                Some(g) => assert_eq!(f.truncate(n), (&g * &g).truncate(n)),
+                // 5 is a non-residue modulo 998244353
+                None => assert_eq!(5, c),
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_shifted() {
+        // x^2 (3 + x)^2
+        let f = poly(&[0, 0, 9, 6, 1]);
+        let g = f.sqrt(6).unwrap();
+
+        assert_eq!(Mint::new(0), g.coef(0));
+        assert_eq!(f, (&g * &g).truncate(6));
+
+        assert_eq!(None, poly(&[0, 1]).sqrt(4));
+        assert_eq!(Some(poly(&[])), poly(&[0, 0, 0, 1]).sqrt(3));
+        assert_eq!(Some(poly(&[])), poly(&[]).sqrt(3));
+    }
+
+    #[test]
+    fn sqrt_non_residue() {
+        assert_eq!(None, poly(&[5, 1]).sqrt(4));
+        assert_eq!(None, poly(&[0, 0, 5]).sqrt(4));
+    }
+
+    #[test]
+    fn div_rem() {
+        let f = poly(&(0..50).map(|i| i * 7 + 3).collect::<Vec<_>>());
+        for &d in &[0, 1, 10, 49, 60] {
+            let g = poly(&(0..=d).map(|i| i + 1).collect::<Vec<_>>());
+            let (q, r) = f.div_rem(&g);
+
+            assert_eq!(f, &(&g * &q) + &r);
+            assert!(r.coefficients().len() < g.coefficients().len());
+        }
+
+        let (q, r) = poly(&[]).div_rem(&poly(&[3]));
+        assert_eq!((poly(&[]), poly(&[])), (q, r));
+    }
+
+    #[test]
+    fn gcd() {
+        let minus_one = 998_244_352;
+        let f = poly(&[minus_one, 0, 1]);
+        let g = poly(&[0, minus_one, 1]);
+        assert_eq!(poly(&[minus_one, 1]), f.gcd(&g));
+
+        // normalized to be monic
+        let p = poly(&[3, 1, 4, 1, 5]);
+        let lead_inv = Mint::new(5).inv();
+        let monic =
+            Poly::from_coefficients(p.coefficients().iter().map(|&c| c * lead_inv).collect());
+        assert_eq!(monic, p.gcd(&p));
+        assert_eq!(monic, p.gcd(&poly(&[])));
+        assert_eq!(monic, poly(&[]).gcd(&p));
+        assert_eq!(poly(&[]), poly(&[]).gcd(&poly(&[])));
+
+        // x^2 + 1 and x^2 + 2 are coprime
+        assert_eq!(poly(&[1]), poly(&[1, 0, 1]).gcd(&poly(&[2, 0, 1])));
+        assert_eq!(poly(&[1]), poly(&[7]).gcd(&poly(&[1, 2, 3])));
+    }
+
+    #[test]
+    fn gcd_common_factor() {
+        let mut rng = XorShift64::new(402);
+        let mut random_poly = |len: usize| {
+            poly(
+                &(0..len)
+                    .map(|_| rng.next_range(0, 998_244_353))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        for &(a, b, c) in &[(1, 1, 2), (5, 8, 4), (30, 20, 10), (60, 1, 40)] {
+            let (f, g, h) = (random_poly(a), random_poly(b), random_poly(c));
+            let d = (&f * &h).gcd(&(&g * &h));
+
+            // h divides the GCD, which divides both products
+            assert_eq!(Mint::new(1), *d.coefficients().last().unwrap());
+            assert!(d.div_rem(&h).1.coefficients().is_empty());
+            assert!((&f * &h).div_rem(&d).1.coefficients().is_empty());
+            assert!((&g * &h).div_rem(&d).1.coefficients().is_empty());
+        }
+    }
+
+    #[test]
+    fn multipoint_eval() {
+        for &(n, m) in &[(0, 3), (1, 1), (5, 3), (3, 40), (100, 70), (512, 512)] {
+            let f = poly(&(0..n).map(|i| i * i * 31 + 17).collect::<Vec<_>>());
+            let points: Vec<Mint> = (0..m).map(|i| Mint::new(i * 1_000_003 + 5)).collect();
+
+            let naive: Vec<Mint> = points.iter().map(|&x| f.evaluate(x)).collect();
+            assert_eq!(naive, f.multipoint_eval(&points));
+        }
+        assert!(poly(&[1]).multipoint_eval(&[]).is_empty());
+    }
+}
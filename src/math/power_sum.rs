@@ -0,0 +1,73 @@
+//! Sum of powers.
+
+use super::interpolation::lagrange_eval_consecutive;
+use super::util::pow_mod;
+
+/// Computes `1^k + 2^k + ... + n^k` modulo a prime,
+/// interpolating the polynomial of degree k + 1 from its values at 0, 1, ..., k + 1.
+/// The modulus is required to be a prime greater than k + 2.
+/// Consumes O(k log k) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::power_sum::sum_of_powers;
+///
+/// assert_eq!(55, sum_of_powers(10, 1, 1_000_000_007));
+/// assert_eq!(385, sum_of_powers(10, 2, 1_000_000_007));
+/// ```
+pub fn sum_of_powers(n: u64, k: u32, modulus: u64) -> u64 {
+    let m = modulus;
+    let ys: Vec<u64> = (0..=k as u64 + 1)
+        .scan(0, |acc, i| {
+            if i > 0 {
+                *acc = (*acc + pow_mod(i, k as u64, m)) % m;
+            }
+            Some(*acc)
+        })
+        .collect();
+
+    lagrange_eval_consecutive(&ys, n, m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = 1_000_000_007;
+
+    #[test]
+    fn direct() {
+        for k in 0..8 {
+            for n in 0..30 {
+                let naive = (1..=n)
+                    .map(|i| pow_mod(i, k as u64, MOD))
+                    .fold(0, |a, b| (a + b) % MOD);
+                assert_eq!(naive, sum_of_powers(n, k, MOD));
+            }
+        }
+    }
+
+    #[test]
+    fn formulas() {
+        for &n in &[1, 100, 123_456, 1_000_000_000] {
+            let n128 = n as u128;
+            let m = MOD as u128;
+
+            assert_eq!(n % MOD, sum_of_powers(n, 0, MOD));
+            assert_eq!((n128 * (n128 + 1) / 2 % m) as u64, sum_of_powers(n, 1, MOD));
+            assert_eq!(
+                (n128 * (n128 + 1) * (2 * n128 + 1) / 6 % m) as u64,
+                sum_of_powers(n, 2, MOD)
+            );
+        }
+    }
+
+    #[test]
+    fn large_n() {
+        // 1^3 + ... + n^3 = (n (n + 1) / 2)^2
+        let n: u64 = 1_000_000_000_000_000_000;
+        let half = (n as u128 * (n as u128 + 1) / 2 % MOD as u128) as u64;
+        assert_eq!(pow_mod(half, 2, MOD), sum_of_powers(n, 3, MOD));
+    }
+}
@@ -0,0 +1,244 @@
+//! Prime numbers.
+
+use super::arith::mul_mod;
+use super::util::pow_mod;
+
+/// Tests whether n is a prime, using the Miller-Rabin test
+/// with the bases which are deterministic for all u64.
+/// Consumes O(log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::prime::is_prime;
+///
+/// assert!(is_prime(998_244_353));
+/// assert!(!is_prime(561));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450_775, 9_780_504, 1_795_265_022]
+        .iter()
+        .all(|&a| {
+            let a = a % n;
+            if a == 0 {
+                return true;
+            }
+            let mut x = pow_mod(a, d, n);
+            if x == 1 || x == n - 1 {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(x, x, n);
+                if x == n - 1 {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
+/// Returns the smallest prime greater than n.
+/// Consumes O(log^2 n) expected time.
+///
+/// # Panics
+/// Panics if there is no such prime in u64.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::prime::next_prime;
+///
+/// assert_eq!(11, next_prime(10));
+/// assert_eq!(2, next_prime(0));
+/// ```
+pub fn next_prime(n: u64) -> u64 {
+    (n.checked_add(1).expect("There is no next prime")..=std::u64::MAX)
+        .find(|&p| is_prime(p))
+        .expect("There is no next prime")
+}
+
+/// Returns the largest prime less than n, or None if n is at most 2.
+/// Consumes O(log^2 n) expected time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::prime::prev_prime;
+///
+/// assert_eq!(Some(7), prev_prime(11));
+/// assert_eq!(None, prev_prime(2));
+/// ```
+pub fn prev_prime(n: u64) -> Option<u64> {
+    (2..n).rev().find(|&p| is_prime(p))
+}
+
+/// Returns the exponent of the prime p in n!, using Legendre's formula Σ_{k ≥ 1} ⌊n / p^k⌋.
+/// Consumes O(log n) time.
+///
+/// # Panics
+/// Panics if p is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::prime::v_p_factorial;
+///
+/// // 10! = 2^8 * 3^4 * 5^2 * 7
+/// assert_eq!(8, v_p_factorial(10, 2));
+/// assert_eq!(6, v_p_factorial(25, 5));
+/// ```
+pub fn v_p_factorial(mut n: u64, p: u64) -> u64 {
+    assert!(p >= 2);
+    let mut res = 0;
+    while n > 0 {
+        n /= p;
+        res += n;
+    }
+    res
+}
+
+/// Returns the exponent of p in n, the p-adic valuation.
+/// Consumes O(log n) time.
+///
+/// # Panics
+/// Panics if n is 0 or p is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::prime::v_p;
+///
+/// assert_eq!(3, v_p(24, 2));
+/// assert_eq!(0, v_p(24, 5));
+/// ```
+pub fn v_p(mut n: u64, p: u64) -> u32 {
+    assert!(n > 0 && p >= 2);
+    let mut res = 0;
+    while n % p == 0 {
+        n /= p;
+        res += 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small() {
+        let sieve: Vec<bool> = (0..10_000u64)
+            .map(|n| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+            .collect();
+        for (n, &p) in sieve.iter().enumerate() {
+            assert_eq!(p, is_prime(n as u64), "{}", n);
+        }
+    }
+
+    #[test]
+    fn large() {
+        // Carmichael numbers and strong pseudoprimes
+        for &n in &[
+            561,
+            1105,
+            1729,
+            2_047,
+            3_215_031_751,
+            3_825_123_056_546_413_051,
+        ] {
+            assert!(!is_prime(n));
+        }
+        for &n in &[
+            998_244_353,
+            1_000_000_007,
+            1_000_000_000_000_000_003,
+            std::u64::MAX - 58,
+        ] {
+            assert!(is_prime(n));
+        }
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
+    }
+
+    #[test]
+    fn next_prev() {
+        assert_eq!(11, next_prime(10));
+        assert_eq!(998_244_353, next_prime(998_244_352));
+        assert_eq!(
+            1_000_000_000_000_000_003,
+            next_prime(1_000_000_000_000_000_000)
+        );
+        assert_eq!(std::u64::MAX - 58, next_prime(std::u64::MAX - 82));
+        assert_eq!(3, next_prime(2));
+
+        assert_eq!(Some(7), prev_prime(11));
+        assert_eq!(Some(2), prev_prime(3));
+        assert_eq!(None, prev_prime(2));
+        assert_eq!(None, prev_prime(0));
+        assert_eq!(
+            Some(999_999_999_999_999_989),
+            prev_prime(1_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_next_prime() {
+        next_prime(std::u64::MAX - 58);
+    }
+
+    #[test]
+    fn legendre() {
+        assert_eq!(8, v_p_factorial(10, 2));
+        assert_eq!(6, v_p_factorial(25, 5));
+        assert_eq!(0, v_p_factorial(0, 3));
+        assert_eq!(0, v_p_factorial(6, 7));
+
+        // the sum of v_p(k) for k in [1, n]
+        for &p in &[2, 3, 5, 7] {
+            let mut acc = 0;
+            for n in 1..300 {
+                acc += v_p(n, p) as u64;
+                assert_eq!(acc, v_p_factorial(n, p));
+            }
+        }
+
+        // v_p(n!) = (n - s_p(n)) / (p - 1), where s_p is the digit sum in base p
+        for &p in &[2, 3, 10_007, 998_244_353] {
+            for &n in &[
+                1,
+                100,
+                123_456_789,
+                1_000_000_000_000_000_000,
+                std::u64::MAX,
+            ] {
+                let mut digit_sum = 0;
+                let mut m = n;
+                while m > 0 {
+                    digit_sum += m % p;
+                    m /= p;
+                }
+                assert_eq!((n - digit_sum) / (p - 1), v_p_factorial(n, p));
+            }
+        }
+    }
+
+    #[test]
+    fn valuation() {
+        assert_eq!(0, v_p(1, 2));
+        assert_eq!(63, v_p(1 << 63, 2));
+        assert_eq!(2, v_p(1_000_000_007 * 1_000_000_007, 1_000_000_007));
+        assert_eq!(3, v_p(1000, 10));
+    }
+}
@@ -0,0 +1,140 @@
+//! Sieve of Eratosthenes.
+
+/// Primes and smallest prime factors up to a limit, using a linear sieve.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::sieve::Sieve;
+///
+/// let sieve = Sieve::new(30);
+/// assert_eq!(&[2, 3, 5, 7, 11, 13, 17, 19, 23, 29], sieve.primes());
+/// assert!(sieve.is_prime(29));
+/// assert_eq!(3, sieve.smallest_prime_factor(21));
+/// assert_eq!(vec![(2, 2), (7, 1)], sieve.factorize(28));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sieve {
+    is_prime: Vec<bool>,
+    primes: Vec<u64>,
+    // spf[n] is the smallest prime factor of n, for n >= 2
+    spf: Vec<u64>,
+}
+
+impl Sieve {
+    /// Runs the sieve on [0, limit].
+    /// Consumes O(limit) time.
+    pub fn new(limit: u64) -> Self {
+        let n = limit as usize;
+        let mut is_prime = vec![false; n + 1];
+        let mut primes = vec![];
+        let mut spf = vec![0; n + 1];
+
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u64;
+                is_prime[i] = true;
+                primes.push(i as u64);
+            }
+            for &p in &primes {
+                let j = i * p as usize;
+                if p > spf[i] || j > n {
+                    break;
+                }
+                spf[j] = p;
+            }
+        }
+
+        Self {
+            is_prime,
+            primes,
+            spf,
+        }
+    }
+
+    /// Returns the limit.
+    pub fn limit(&self) -> u64 {
+        self.is_prime.len() as u64 - 1
+    }
+
+    /// Returns true if n is a prime.
+    ///
+    /// # Panics
+    /// Panics if n exceeds the limit.
+    pub fn is_prime(&self, n: u64) -> bool {
+        assert!(n <= self.limit(), "n exceeds the limit");
+        self.is_prime[n as usize]
+    }
+
+    /// Returns the primes up to the limit in ascending order.
+    pub fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+
+    /// Returns the smallest prime factor of n.
+    ///
+    /// # Panics
+    /// Panics if n is less than 2 or exceeds the limit.
+    pub fn smallest_prime_factor(&self, n: u64) -> u64 {
+        assert!(2 <= n && n <= self.limit(), "n is out of range");
+        self.spf[n as usize]
+    }
+
+    /// Factorizes n into pairs of a prime and its exponent, in ascending order of the primes.
+    /// Consumes O(log n) time.
+    ///
+    /// # Panics
+    /// Panics if n is 0 or exceeds the limit.
+    pub fn factorize(&self, mut n: u64) -> Vec<(u64, u32)> {
+        assert!(1 <= n && n <= self.limit(), "n is out of range");
+        let mut factors: Vec<(u64, u32)> = vec![];
+        while n > 1 {
+            let p = self.spf[n as usize];
+            match factors.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+            n /= p;
+        }
+        factors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::factor::factorize;
+    use crate::math::prime::is_prime;
+
+    #[test]
+    fn prime_count() {
+        let sieve = Sieve::new(1_000_000);
+        assert_eq!(78498, sieve.primes().len());
+        assert_eq!(999_983, *sieve.primes().last().unwrap());
+        assert_eq!(1_000_000, sieve.limit());
+    }
+
+    #[test]
+    fn small() {
+        let sieve = Sieve::new(2000);
+        for n in 0..=2000 {
+            assert_eq!(is_prime(n), sieve.is_prime(n));
+        }
+        for n in 1..=2000 {
+            assert_eq!(factorize(n), sieve.factorize(n));
+        }
+        for n in 2..=2000 {
+            let spf = (2..).find(|p| n % p == 0).unwrap();
+            assert_eq!(spf, sieve.smallest_prime_factor(n));
+        }
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], sieve.factorize(360));
+        assert!(sieve.factorize(1).is_empty());
+    }
+
+    #[test]
+    fn tiny_limits() {
+        assert!(Sieve::new(0).primes().is_empty());
+        assert!(!Sieve::new(1).is_prime(1));
+        assert_eq!(&[2], Sieve::new(2).primes());
+    }
+}
@@ -0,0 +1,199 @@
+//! Stirling numbers.
+
+use super::arith::mul_mod;
+
+/// Computes the unsigned Stirling numbers of the first kind `[i, k]` for `0 <= k <= i <= n`
+/// modulo `modulus`, the number of permutations of i elements with exactly k cycles,
+/// using the recurrence `[i, k] = (i - 1) [i - 1, k] + [i - 1, k - 1]`.
+/// Returns the table indexed as `table[i][k]`, whose row i has length i + 1.
+/// Consumes O(n^2) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::stirling::stirling_first_table;
+///
+/// let table = stirling_first_table(4, 1_000_000_007);
+/// assert_eq!(vec![0, 6, 11, 6, 1], table[4]);
+/// ```
+pub fn stirling_first_table(n: usize, modulus: u64) -> Vec<Vec<u64>> {
+    let mut table = vec![vec![1 % modulus]];
+    for i in 1..=n {
+        let prev = &table[i - 1];
+        let row = (0..=i)
+            .map(|k| {
+                let stay = if k < i {
+                    mul_mod((i - 1) as u64 % modulus, prev[k], modulus)
+                } else {
+                    0
+                };
+                let new_cycle = if k > 0 { prev[k - 1] } else { 0 };
+                ((stay as u128 + new_cycle as u128) % modulus as u128) as u64
+            })
+            .collect();
+        table.push(row);
+    }
+    table
+}
+
+/// Computes the Stirling numbers of the second kind `{i, k}` for `0 <= k <= i <= n`
+/// modulo `modulus`, the number of partitions of i elements into exactly k nonempty sets,
+/// using the recurrence `{i, k} = k {i - 1, k} + {i - 1, k - 1}`.
+/// Returns the table indexed as `table[i][k]`, whose row i has length i + 1.
+/// Consumes O(n^2) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::stirling::stirling_second_table;
+///
+/// let table = stirling_second_table(4, 1_000_000_007);
+/// assert_eq!(vec![0, 1, 7, 6, 1], table[4]);
+/// ```
+pub fn stirling_second_table(n: usize, modulus: u64) -> Vec<Vec<u64>> {
+    let mut table = vec![vec![1 % modulus]];
+    for i in 1..=n {
+        let prev = &table[i - 1];
+        let row = (0..=i)
+            .map(|k| {
+                let join = if k < i {
+                    mul_mod(k as u64 % modulus, prev[k], modulus)
+                } else {
+                    0
+                };
+                let new_set = if k > 0 { prev[k - 1] } else { 0 };
+                ((join as u128 + new_set as u128) % modulus as u128) as u64
+            })
+            .collect();
+        table.push(row);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = 1_000_000_007;
+
+    #[test]
+    fn small() {
+        let table = stirling_first_table(5, MOD);
+        assert_eq!(vec![1], table[0]);
+        assert_eq!(vec![0, 1], table[1]);
+        assert_eq!(vec![0, 1, 1], table[2]);
+        assert_eq!(vec![0, 2, 3, 1], table[3]);
+        assert_eq!(vec![0, 6, 11, 6, 1], table[4]);
+        assert_eq!(vec![0, 24, 50, 35, 10, 1], table[5]);
+    }
+
+    #[test]
+    fn row_sums() {
+        // every permutation has some number of cycles
+        let table = stirling_first_table(200, MOD);
+        let mut factorial = 1;
+        for (i, row) in table.iter().enumerate() {
+            if i > 0 {
+                factorial = factorial * i as u64 % MOD;
+            }
+            assert_eq!(factorial, row.iter().fold(0, |acc, &x| (acc + x) % MOD));
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        // [n, 1] = (n - 1)! and [n, n - 1] = C(n, 2)
+        let table = stirling_first_table(20, std::u64::MAX);
+        for n in 2..=20u64 {
+            let row = &table[n as usize];
+            assert_eq!((1..n).product::<u64>(), row[1]);
+            assert_eq!(n * (n - 1) / 2, row[n as usize - 1]);
+        }
+    }
+
+    #[test]
+    fn small_modulus() {
+        let table = stirling_first_table(6, 7);
+        let exact = stirling_first_table(6, MOD);
+        for (row, exact_row) in table.iter().zip(&exact) {
+            let reduced: Vec<u64> = exact_row.iter().map(|&x| x % 7).collect();
+            assert_eq!(&reduced, row);
+        }
+        assert_eq!(vec![vec![0]], stirling_first_table(0, 1));
+    }
+
+    #[test]
+    fn second_small() {
+        let table = stirling_second_table(5, MOD);
+        assert_eq!(vec![1], table[0]);
+        assert_eq!(vec![0, 1], table[1]);
+        assert_eq!(vec![0, 1, 1], table[2]);
+        assert_eq!(vec![0, 1, 3, 1], table[3]);
+        assert_eq!(vec![0, 1, 7, 6, 1], table[4]);
+        assert_eq!(vec![0, 1, 15, 25, 10, 1], table[5]);
+        assert_eq!(7, table[4][2]);
+        assert_eq!(11, stirling_first_table(4, MOD)[4][2]);
+    }
+
+    #[test]
+    fn second_row_sums() {
+        // the Bell numbers
+        let bell = [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147, 115_975];
+        let table = stirling_second_table(10, MOD);
+        for (row, &b) in table.iter().zip(&bell) {
+            assert_eq!(b, row.iter().sum::<u64>());
+        }
+    }
+
+    #[test]
+    fn second_inverse() {
+        // the signed first kind and the second kind are inverse matrices
+        let n = 30;
+        let first = stirling_first_table(n, MOD);
+        let second = stirling_second_table(n, MOD);
+        for (i, first_row) in first.iter().enumerate() {
+            for j in 0..=i {
+                let sum = (j..=i).fold(0, |acc, k| {
+                    let x = mul_mod(first_row[k], second[k][j], MOD);
+                    if (i - k) % 2 == 0 {
+                        (acc + x) % MOD
+                    } else {
+                        (acc + MOD - x) % MOD
+                    }
+                });
+                assert_eq!(if i == j { 1 } else { 0 }, sum, "{} {}", i, j);
+            }
+        }
+        assert_eq!(vec![vec![0]], stirling_second_table(0, 1));
+    }
+
+    #[test]
+    fn large_modulus() {
+        // the same recurrences in u128, with the coefficient i - 1 or k
+        fn reference(n: usize, m: u64, first: bool) -> Vec<Vec<u64>> {
+            let m = m as u128;
+            let mut table = vec![vec![1 % m]];
+            for i in 1..=n {
+                let prev = &table[i - 1];
+                let row = (0..=i)
+                    .map(|k| {
+                        let c = if first { i as u128 - 1 } else { k as u128 };
+                        let keep = if k < i { c % m * prev[k] % m } else { 0 };
+                        let add = if k > 0 { prev[k - 1] } else { 0 };
+                        (keep + add) % m
+                    })
+                    .collect();
+                table.push(row);
+            }
+            table
+                .into_iter()
+                .map(|row| row.into_iter().map(|x| x as u64).collect())
+                .collect()
+        }
+
+        for &m in &[std::u64::MAX, std::u64::MAX - 58, (1 << 63) + 1] {
+            assert_eq!(reference(40, m, true), stirling_first_table(40, m));
+            assert_eq!(reference(40, m, false), stirling_second_table(40, m));
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Euler's totient function.
+
+use super::factor::factorize;
+
+/// Computes φ(n) for all n in [0, limit], using a sieve. φ(0) is 0.
+/// Consumes O(n log log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::totient::totient_sieve;
+///
+/// assert_eq!(vec![0, 1, 1, 2, 2, 4, 2, 6], totient_sieve(7));
+/// ```
+pub fn totient_sieve(limit: usize) -> Vec<u64> {
+    let mut phi: Vec<u64> = (0..=limit as u64).collect();
+
+    for p in 2..=limit {
+        // p is a prime iff it is untouched
+        if phi[p] == p as u64 {
+            for m in (p..=limit).step_by(p) {
+                phi[m] -= phi[m] / p as u64;
+            }
+        }
+    }
+
+    phi
+}
+
+/// Computes φ(n), using trial division.
+/// Consumes O(√n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::totient::totient;
+///
+/// assert_eq!(4, totient(12));
+/// ```
+pub fn totient(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |acc, (p, _)| acc / p * (p - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::gcd::gcd;
+
+    #[test]
+    fn values() {
+        assert_eq!(1, totient(1));
+        assert_eq!(4, totient(12));
+        assert_eq!(998_244_352, totient(998_244_353));
+        assert_eq!(3u64.pow(9) * 2, totient(3u64.pow(10)));
+        assert_eq!(2u64.pow(39), totient(2u64.pow(40)));
+        assert_eq!(0, totient(0));
+    }
+
+    #[test]
+    fn sieve() {
+        let phi = totient_sieve(1000);
+        for (n, &x) in phi.iter().enumerate() {
+            assert_eq!(totient(n as u64), x);
+        }
+
+        // the number of k in [1, n] coprime to n
+        for n in 1..100 {
+            assert_eq!(
+                (1..=n).filter(|&k| gcd(n, k) == 1).count() as u64,
+                phi[n as usize]
+            );
+        }
+    }
+
+    #[test]
+    fn sum_over_divisors() {
+        let phi = totient_sieve(1000);
+        for n in 1..=1000 {
+            let sum: u64 = (1..=n).filter(|d| n % d == 0).map(|d| phi[d]).sum();
+            assert_eq!(n as u64, sum);
+        }
+    }
+}
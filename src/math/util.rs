@@ -0,0 +1,124 @@
+//! Integer utilities.
+
+use super::arith::mul_mod;
+
+pub use super::gcd::gcd;
+pub use super::iroot::isqrt;
+
+/// Computes base^exp mod m, using binary exponentiation with 128-bit products.
+/// 0^0 is 1 mod m.
+/// Consumes O(log exp) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::util::pow_mod;
+///
+/// assert_eq!(1024, pow_mod(2, 10, 1_000_000_007));
+/// assert_eq!(1, pow_mod(0, 0, 7));
+/// ```
+pub fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut acc = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul_mod(acc, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    acc
+}
+
+/// Computes ⌊a / b⌋, rounding toward negative infinity unlike `/`.
+///
+/// # Panics
+/// Panics if b is 0, or if the quotient overflows.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::util::floor_div;
+///
+/// assert_eq!(-4, floor_div(-7, 2));
+/// assert_eq!(-4, floor_div(7, -2));
+/// assert_eq!(3, floor_div(-7, -2));
+/// ```
+pub const fn floor_div(a: i64, b: i64) -> i64 {
+    a / b - ((a % b != 0) & ((a < 0) != (b < 0))) as i64
+}
+
+/// Computes ⌈a / b⌉, rounding toward positive infinity.
+///
+/// # Panics
+/// Panics if b is 0, or if the quotient overflows.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::math::util::ceil_div;
+///
+/// assert_eq!(-3, ceil_div(-7, 2));
+/// assert_eq!(4, ceil_div(7, 2));
+/// assert_eq!(4, ceil_div(-7, -2));
+/// ```
+pub const fn ceil_div(a: i64, b: i64) -> i64 {
+    a / b + ((a % b != 0) & ((a < 0) == (b < 0))) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow() {
+        const P: u64 = 1_000_000_007;
+        let m = std::u64::MAX - 58;
+        assert_eq!(0, pow_mod(5, 3, 1));
+        assert_eq!(1, pow_mod(0, 0, 7));
+        assert_eq!(0, pow_mod(0, 5, 7));
+        // Fermat's little theorem
+        assert_eq!(1, pow_mod(123_456_789, m - 1, m));
+        assert_eq!(1, pow_mod(2, P - 1, P));
+        // reducing the exponent modulo p - 1
+        assert_eq!(
+            pow_mod(2, 1_000_000_000_000_000_000 % (P - 1), P),
+            pow_mod(2, 1_000_000_000_000_000_000, P)
+        );
+        assert_eq!(
+            pow_mod(3, 1_000_000, 998_244_353),
+            pow_mod(3 + 998_244_353, 1_000_000, 998_244_353)
+        );
+        assert_eq!(m - 1, pow_mod(m - 1, 1_000_000_000_000_000_001, m));
+    }
+
+    #[test]
+    fn div() {
+        const Q: i64 = floor_div(-7, 2);
+        assert_eq!(-4, Q);
+        assert_eq!(-3, ceil_div(-7, 2));
+
+        for a in -30..=30 {
+            for b in (-7..=7).filter(|&b| b != 0) {
+                let (fa, fb) = (a as f64, b as f64);
+                assert_eq!((fa / fb).floor() as i64, floor_div(a, b), "{} {}", a, b);
+                assert_eq!((fa / fb).ceil() as i64, ceil_div(a, b), "{} {}", a, b);
+            }
+        }
+
+        let (min, max) = (std::i64::MIN, std::i64::MAX);
+        assert_eq!(min, floor_div(min, 1));
+        assert_eq!(-2, floor_div(min, max));
+        assert_eq!(-1, ceil_div(min, max));
+        assert_eq!(-1, floor_div(max, min));
+        assert_eq!(0, ceil_div(max, min));
+    }
+
+    #[test]
+    fn reexports() {
+        assert_eq!(1_000_000_000, isqrt(1_000_000_000_000_000_000));
+        assert_eq!(999_999_999, isqrt(999_999_999_999_999_999));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(6, gcd(12, 18));
+    }
+}
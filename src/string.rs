@@ -0,0 +1,9 @@
+pub mod aho_corasick;
+pub mod edit_distance;
+pub mod hash;
+pub mod kmp;
+pub mod lyndon;
+pub mod manacher;
+pub mod sam;
+pub mod suffix_array;
+pub mod z_function;
@@ -0,0 +1,190 @@
+//! Aho-Corasick automaton.
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// Aho-Corasick automaton for multiple pattern matching.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::aho_corasick::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(&[b"he", b"she", b"his", b"hers"]);
+///
+/// assert_eq!(vec![(1, 4), (0, 4), (3, 6)], ac.search(b"ushers"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    next: Vec<BTreeMap<u8, usize>>,
+    fail: Vec<usize>,
+    // the nearest node on the failure chain that ends some pattern
+    dict: Vec<Option<usize>>,
+    accept: Vec<Vec<usize>>,
+}
+
+impl AhoCorasick {
+    /// Constructs a new automaton.
+    /// Consumes O(m log σ) time where m is the total length of the patterns.
+    pub fn new(patterns: &[&[u8]]) -> Self {
+        let mut next = vec![BTreeMap::new()];
+        let mut accept = vec![vec![]];
+
+        for (i, pattern) in patterns.iter().enumerate() {
+            let mut v = 0;
+            for &c in pattern.iter() {
+                v = match next[v].get(&c) {
+                    Some(&u) => u,
+                    None => {
+                        let u = next.len();
+                        next[v].insert(c, u);
+                        next.push(BTreeMap::new());
+                        accept.push(vec![]);
+                        u
+                    }
+                };
+            }
+            accept[v].push(i);
+        }
+
+        let n = next.len();
+        let mut fail = vec![0; n];
+        let mut dict = vec![None; n];
+
+        let mut queue: VecDeque<usize> = next[0].values().copied().collect();
+        while let Some(v) = queue.pop_front() {
+            for (&c, &u) in next[v].iter() {
+                let mut f = fail[v];
+                fail[u] = loop {
+                    if let Some(&w) = next[f].get(&c) {
+                        break w;
+                    }
+                    if f == 0 {
+                        break 0;
+                    }
+                    f = fail[f];
+                };
+                dict[u] = if accept[fail[u]].is_empty() {
+                    dict[fail[u]]
+                } else {
+                    Some(fail[u])
+                };
+                queue.push_back(u);
+            }
+        }
+
+        Self {
+            next,
+            fail,
+            dict,
+            accept,
+        }
+    }
+
+    /// Finds all occurrences of the patterns in the text,
+    /// including overlapping ones.
+    /// Returns pairs of the pattern index and the end position (exclusive),
+    /// ordered by the end position.
+    /// Consumes O(n log σ + k) time where k is the number of occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::string::aho_corasick::AhoCorasick;
+    ///
+    /// let ac = AhoCorasick::new(&[b"aa"]);
+    ///
+    /// assert_eq!(vec![(0, 2), (0, 3)], ac.search(b"aaa"));
+    /// ```
+    pub fn search(&self, text: &[u8]) -> Vec<(usize, usize)> {
+        let mut res = vec![];
+        let mut v = 0;
+
+        for (i, c) in text.iter().enumerate() {
+            v = loop {
+                if let Some(&u) = self.next[v].get(c) {
+                    break u;
+                }
+                if v == 0 {
+                    break 0;
+                }
+                v = self.fail[v];
+            };
+
+            let mut u = Some(v);
+            while let Some(w) = u {
+                res.extend(self.accept[w].iter().map(|&p| (p, i + 1)));
+                u = self.dict[w];
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn search_naive(patterns: &[&[u8]], text: &[u8]) -> Vec<(usize, usize)> {
+        let mut res = vec![];
+        for end in 0..=text.len() {
+            for (i, p) in patterns.iter().enumerate() {
+                if !p.is_empty() && text[..end].ends_with(p) {
+                    res.push((i, end));
+                }
+            }
+        }
+        res
+    }
+
+    fn sorted(mut v: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+        v.sort_by_key(|&(i, end)| (end, i));
+        v
+    }
+
+    #[test]
+    fn overlapping() {
+        let patterns: &[&[u8]] = &[b"aba", b"bab", b"b"];
+        let text = b"ababab";
+
+        let res = AhoCorasick::new(patterns).search(text);
+        assert_eq!(search_naive(patterns, text), sorted(res));
+    }
+
+    #[test]
+    fn prefixes() {
+        let patterns: &[&[u8]] = &[b"a", b"ab", b"abc", b"bc", b"c"];
+        let text = b"xabcabx";
+
+        let res = AhoCorasick::new(patterns).search(text);
+        assert_eq!(search_naive(patterns, text), sorted(res));
+    }
+
+    #[test]
+    fn no_false_positives() {
+        let patterns: &[&[u8]] = &[b"abcd", b"bce", b"xyz"];
+        assert_eq!(
+            vec![(1, 7)],
+            AhoCorasick::new(patterns).search(b"abcabcebcd")
+        );
+        assert!(AhoCorasick::new(patterns).search(b"").is_empty());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(7);
+        let mut rand = |m: u64| rng.next_range(0, m);
+
+        for _ in 0..50 {
+            let patterns: Vec<Vec<u8>> = (0..rand(6) + 1)
+                .map(|_| (0..rand(4) + 1).map(|_| b'a' + rand(2) as u8).collect())
+                .collect();
+            let patterns: Vec<&[u8]> = patterns.iter().map(Vec::as_slice).collect();
+            let text: Vec<u8> = (0..rand(30)).map(|_| b'a' + rand(2) as u8).collect();
+
+            let res = AhoCorasick::new(&patterns).search(&text);
+            assert_eq!(search_naive(&patterns, &text), sorted(res));
+        }
+    }
+}
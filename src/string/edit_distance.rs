@@ -0,0 +1,119 @@
+//! Edit distance and longest common subsequence.
+
+/// Computes the Levenshtein distance,
+/// the minimum number of insertions, deletions and substitutions.
+/// Consumes O(nm) time and O(min(n, m)) space.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::edit_distance::levenshtein;
+///
+/// assert_eq!(3, levenshtein(b"kitten", b"sitting"));
+/// ```
+pub fn levenshtein(a: &[u8], b: &[u8]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut dp: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut diag = dp[0];
+        dp[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let sub = diag + if x == y { 0 } else { 1 };
+            diag = dp[j + 1];
+            dp[j + 1] = sub.min(dp[j] + 1).min(dp[j + 1] + 1);
+        }
+    }
+
+    dp[b.len()]
+}
+
+/// Computes the length of the longest common subsequence.
+/// Consumes O(nm) time and O(min(n, m)) space.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::edit_distance::longest_common_subsequence;
+///
+/// assert_eq!(4, longest_common_subsequence(b"ABCBDAB", b"BDCAB"));
+/// ```
+pub fn longest_common_subsequence(a: &[u8], b: &[u8]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut dp = vec![0; b.len() + 1];
+    for x in a.iter() {
+        let mut diag = 0;
+        for (j, y) in b.iter().enumerate() {
+            let next = if x == y {
+                diag + 1
+            } else {
+                dp[j].max(dp[j + 1])
+            };
+            diag = dp[j + 1];
+            dp[j + 1] = next;
+        }
+    }
+
+    dp[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    // the distance allowing insertions and deletions only
+    fn indel_distance(a: &[u8], b: &[u8]) -> usize {
+        let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..=a.len() {
+            for j in 0..=b.len() {
+                dp[i][j] = if i == 0 || j == 0 {
+                    i + j
+                } else if a[i - 1] == b[j - 1] {
+                    dp[i - 1][j - 1]
+                } else {
+                    dp[i - 1][j].min(dp[i][j - 1]) + 1
+                };
+            }
+        }
+        dp[a.len()][b.len()]
+    }
+
+    #[test]
+    fn levenshtein_examples() {
+        assert_eq!(3, levenshtein(b"kitten", b"sitting"));
+        assert_eq!(3, levenshtein(b"sitting", b"kitten"));
+        assert_eq!(3, levenshtein(b"", b"abc"));
+        assert_eq!(3, levenshtein(b"abc", b""));
+        assert_eq!(0, levenshtein(b"same", b"same"));
+        assert_eq!(2, levenshtein(b"flaw", b"lawn"));
+    }
+
+    #[test]
+    fn lcs_examples() {
+        assert_eq!(4, longest_common_subsequence(b"ABCBDAB", b"BDCAB"));
+        assert_eq!(0, longest_common_subsequence(b"", b"abc"));
+        assert_eq!(0, longest_common_subsequence(b"abc", b"xyz"));
+        assert_eq!(3, longest_common_subsequence(b"abc", b"abc"));
+    }
+
+    #[test]
+    fn indel_identity() {
+        let mut rng = XorShift64::new(1);
+        let mut random_string =
+            |n: usize| -> Vec<u8> { (0..n).map(|_| b'a' + rng.next_range(0, 3) as u8).collect() };
+
+        for n in 0..10 {
+            for m in 0..10 {
+                let a = random_string(n);
+                let b = random_string(m);
+                let lcs = longest_common_subsequence(&a, &b);
+
+                assert_eq!(indel_distance(&a, &b), n + m - 2 * lcs);
+                assert!(levenshtein(&a, &b) <= n + m - 2 * lcs);
+                assert!(levenshtein(&a, &b) >= n.max(m) - lcs);
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Rolling hash.
+
+use crate::math::arith::mul_mod;
+
+/// Polynomial rolling hash of a byte string.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::hash::RollingHash;
+///
+/// let rh = RollingHash::new(b"abcabc", 131, 1_000_000_007);
+///
+/// assert_eq!(rh.query(0, 3), rh.query(3, 6));
+/// assert_ne!(rh.query(0, 3), rh.query(1, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    hash: Vec<u64>,
+    pow: Vec<u64>,
+    modulus: u64,
+}
+
+impl RollingHash {
+    /// Constructs a new rolling hash of `s`.
+    /// Consumes O(n) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::string::hash::RollingHash;
+    ///
+    /// let rh = RollingHash::new(b"abracadabra", 131, 1_000_000_007);
+    /// ```
+    pub fn new(s: &[u8], base: u64, modulus: u64) -> Self {
+        let hash = std::iter::once(0)
+            .chain(s.iter().scan(0, |h, &c| {
+                *h = ((mul_mod(*h, base, modulus) as u128 + c as u128) % modulus as u128) as u64;
+                Some(*h)
+            }))
+            .collect();
+
+        let pow = std::iter::successors(Some(1 % modulus), |&p| Some(mul_mod(p, base, modulus)))
+            .take(s.len() + 1)
+            .collect();
+
+        Self { hash, pow, modulus }
+    }
+
+    /// Returns the hash of `s[l..r]` in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::string::hash::RollingHash;
+    ///
+    /// let rh = RollingHash::new(b"abracadabra", 131, 1_000_000_007);
+    /// assert_eq!(rh.query(0, 4), rh.query(7, 11));
+    /// ```
+    pub fn query(&self, l: usize, r: usize) -> u64 {
+        assert!(l <= r);
+
+        let m = self.modulus as u128;
+        let sub = mul_mod(self.hash[l], self.pow[r - l], self.modulus) as u128;
+        ((self.hash[r] as u128 + m - sub) % m) as u64
+    }
+}
+
+/// Rolling hash with two independent pairs of base and modulus,
+/// to lower the probability of collision.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::hash::DoubleHash;
+///
+/// let dh = DoubleHash::new(b"abcabc", (131, 1_000_000_007), (137, 998_244_353));
+///
+/// assert_eq!(dh.query(0, 3), dh.query(3, 6));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DoubleHash(RollingHash, RollingHash);
+
+impl DoubleHash {
+    /// Constructs a new double hash of `s` from two `(base, modulus)` pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::string::hash::DoubleHash;
+    ///
+    /// let dh = DoubleHash::new(b"abracadabra", (131, 1_000_000_007), (137, 998_244_353));
+    /// ```
+    pub fn new(s: &[u8], (base1, modulus1): (u64, u64), (base2, modulus2): (u64, u64)) -> Self {
+        Self(
+            RollingHash::new(s, base1, modulus1),
+            RollingHash::new(s, base2, modulus2),
+        )
+    }
+
+    /// Returns the pair of hashes of `s[l..r]` in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::string::hash::DoubleHash;
+    ///
+    /// let dh = DoubleHash::new(b"abracadabra", (131, 1_000_000_007), (137, 998_244_353));
+    /// assert_eq!(dh.query(0, 4), dh.query(7, 11));
+    /// ```
+    pub fn query(&self, l: usize, r: usize) -> (u64, u64) {
+        (self.0.query(l, r), self.1.query(l, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD: u64 = (1 << 61) - 1;
+
+    #[test]
+    fn equal_substrings() {
+        let s = b"mississippi";
+        let rh = RollingHash::new(s, 1_000_003, MOD);
+
+        for l1 in 0..s.len() {
+            for l2 in 0..s.len() {
+                for len in 0..=s.len() - l1.max(l2) {
+                    let same = s[l1..l1 + len] == s[l2..l2 + len];
+                    assert_eq!(same, rh.query(l1, l1 + len) == rh.query(l2, l2 + len));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shifted() {
+        let s: Vec<u8> = (0..200).map(|i| (i * i % 26) as u8 + b'a').collect();
+        let n = s.len() - 1;
+
+        let rh = RollingHash::new(&s, 1_000_003, MOD);
+        assert_ne!(rh.query(0, n), rh.query(1, n + 1));
+
+        let dh = DoubleHash::new(&s, (131, 1_000_000_007), (137, 998_244_353));
+        assert_ne!(dh.query(0, n), dh.query(1, n + 1));
+    }
+
+    #[test]
+    fn large_modulus() {
+        // the largest prime below 2^64
+        const M: u64 = std::u64::MAX - 58;
+        let s = b"\xff\xfe\xfdabc\xff";
+        let rh = RollingHash::new(s, M - 1, M);
+
+        for l in 0..=s.len() {
+            for r in l..=s.len() {
+                let naive = s[l..r].iter().fold(0, |h, &c| {
+                    ((h as u128 * (M - 1) as u128 + c as u128) % M as u128) as u64
+                });
+                assert_eq!(naive, rh.query(l, r));
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let rh = RollingHash::new(b"abc", 131, 1_000_000_007);
+        assert_eq!(0, rh.query(1, 1));
+    }
+}
@@ -0,0 +1,120 @@
+//! Knuth-Morris-Pratt algorithm.
+
+/// Returns the failure function of `pattern`.
+/// `f[i]` is the length of the longest proper border of `pattern[..=i]`.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::kmp::failure_function;
+///
+/// assert_eq!(vec![0, 0, 1, 2, 0], failure_function(b"ababc"));
+/// ```
+pub fn failure_function(pattern: &[u8]) -> Vec<usize> {
+    let mut f = vec![0; pattern.len()];
+
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[i] != pattern[k] {
+            k = f[k - 1];
+        }
+        if pattern[i] == pattern[k] {
+            k += 1;
+        }
+        f[i] = k;
+    }
+
+    f
+}
+
+/// Returns all the starting positions of `pattern` in `text`, in ascending order.
+/// An empty pattern matches at every position in `0..text.len()`.
+/// Consumes O(|text| + |pattern|) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::kmp::kmp_search;
+///
+/// assert_eq!(vec![0, 2], kmp_search(b"ababa", b"aba"));
+/// ```
+pub fn kmp_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..text.len()).collect();
+    }
+
+    let f = failure_function(pattern);
+    let mut res = vec![];
+
+    let mut k = 0;
+    for (i, &c) in text.iter().enumerate() {
+        while k > 0 && c != pattern[k] {
+            k = f[k - 1];
+        }
+        if c == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            res.push(i + 1 - m);
+            k = f[k - 1];
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::z_function::find_occurrences;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn failure() {
+        assert_eq!(vec![0, 1, 2, 3], failure_function(b"aaaa"));
+        assert_eq!(vec![0, 0, 1, 0, 1, 2, 3], failure_function(b"abacaba"));
+        assert_eq!(Vec::<usize>::new(), failure_function(b""));
+    }
+
+    #[test]
+    fn overlapping() {
+        assert_eq!(vec![0, 1, 2], kmp_search(b"aaaaa", b"aaa"));
+    }
+
+    #[test]
+    fn no_occurrence() {
+        assert_eq!(Vec::<usize>::new(), kmp_search(b"abcabc", b"abd"));
+        assert_eq!(Vec::<usize>::new(), kmp_search(b"ab", b"abc"));
+    }
+
+    #[test]
+    fn whole_text() {
+        assert_eq!(vec![0], kmp_search(b"abcab", b"abcab"));
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(vec![0, 1, 2], kmp_search(b"abc", b""));
+    }
+
+    #[test]
+    fn random() {
+        assert_eq!(vec![0, 5], kmp_search(b"ababcabab", b"abab"));
+
+        let mut rng = XorShift64::new(9);
+        for _ in 0..300 {
+            let n = rng.next_range(0, 30) as usize;
+            let m = rng.next_range(1, 5) as usize;
+            let text: Vec<u8> = (0..n).map(|_| b'a' + rng.next_range(0, 2) as u8).collect();
+            let pattern: Vec<u8> = (0..m).map(|_| b'a' + rng.next_range(0, 2) as u8).collect();
+
+            let naive: Vec<usize> = (0..n)
+                .filter(|&i| text[i..].starts_with(&pattern))
+                .collect();
+            assert_eq!(naive, kmp_search(&text, &pattern));
+            assert_eq!(naive, find_occurrences(&text, &pattern));
+        }
+    }
+}
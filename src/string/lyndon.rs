@@ -0,0 +1,96 @@
+//! Lyndon factorization.
+
+/// Computes the Lyndon factorization, using Duval's algorithm.
+/// Returns the start indices of the factors, which are non-increasing Lyndon words.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::lyndon::lyndon_factorization;
+///
+/// // abac | ab | a
+/// assert_eq!(vec![0, 4, 6], lyndon_factorization(b"abacaba"));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn lyndon_factorization(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut res = vec![];
+
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        let mut k = i;
+        while j < n && s[k] <= s[j] {
+            k = if s[k] < s[j] { i } else { k + 1 };
+            j += 1;
+        }
+        while i <= k {
+            res.push(i);
+            i += j - k;
+        }
+    }
+
+    res
+}
+
+/// Returns true if s is a Lyndon word,
+/// i.e. strictly less than all of its proper rotations.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::lyndon::is_lyndon;
+///
+/// assert!(is_lyndon(b"aab"));
+/// assert!(!is_lyndon(b"aba"));
+/// assert!(!is_lyndon(b"abab"));
+/// ```
+pub fn is_lyndon(s: &[u8]) -> bool {
+    lyndon_factorization(s) == [0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn is_lyndon_naive(s: &[u8]) -> bool {
+        !s.is_empty()
+            && (1..s.len()).all(|i| {
+                let rotated: Vec<u8> = s[i..].iter().chain(&s[..i]).copied().collect();
+                s < &rotated[..]
+            })
+    }
+
+    #[test]
+    fn factorization() {
+        assert_eq!(vec![0, 4, 6], lyndon_factorization(b"abacaba"));
+        assert_eq!(vec![0], lyndon_factorization(b"aab"));
+        assert_eq!(vec![0, 1, 2], lyndon_factorization(b"aaa"));
+        assert_eq!(vec![0, 1, 2], lyndon_factorization(b"cba"));
+        assert!(lyndon_factorization(b"").is_empty());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        for n in 1..12 {
+            for _ in 0..50 {
+                let s: Vec<u8> = (0..n).map(|_| b'a' + rng.next_range(0, 3) as u8).collect();
+
+                assert_eq!(is_lyndon_naive(&s), is_lyndon(&s));
+
+                let starts = lyndon_factorization(&s);
+                let factors: Vec<&[u8]> = starts
+                    .iter()
+                    .zip(starts.iter().skip(1).chain(std::iter::once(&s.len())))
+                    .map(|(&l, &r)| &s[l..r])
+                    .collect();
+                assert!(factors.iter().all(|f| is_lyndon_naive(f)));
+                assert!(factors.windows(2).all(|w| w[0] >= w[1]));
+                assert_eq!(s, factors.concat());
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Manacher's algorithm.
+
+/// Returns the palindrome radii over the interleaved string.
+///
+/// The interleaved string puts a separator before, between and after
+/// the characters of `s`, e.g. `#a#b#a#` for `aba`, so that it has length 2n + 1.
+/// `r[i]` is the largest radius of a palindrome in it centered at i,
+/// which equals the length of the longest palindrome of `s` with the same center.
+/// Even indices are the centers between characters.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::manacher::manacher;
+///
+/// assert_eq!(vec![0, 1, 0, 3, 0, 1, 0], manacher(b"aba"));
+/// assert_eq!(vec![0, 1, 2, 1, 0], manacher(b"aa"));
+/// ```
+pub fn manacher(s: &[u8]) -> Vec<usize> {
+    let t: Vec<Option<u8>> = std::iter::once(None)
+        .chain(s.iter().flat_map(|&c| vec![Some(c), None]))
+        .collect();
+    let n = t.len();
+
+    let mut rad = vec![0; n];
+    let (mut center, mut right) = (0, 0);
+
+    for i in 0..n {
+        let mut k = if i < right {
+            rad[2 * center - i].min(right - i)
+        } else {
+            0
+        };
+        while i > k && i + k + 1 < n && t[i - k - 1] == t[i + k + 1] {
+            k += 1;
+        }
+        rad[i] = k;
+
+        if i + k > right {
+            center = i;
+            right = i + k;
+        }
+    }
+
+    rad
+}
+
+/// Returns the start and the length of the longest palindromic substring.
+/// The leftmost one is chosen if there are several.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::manacher::longest_palindromic_substring;
+///
+/// assert_eq!((1, 4), longest_palindromic_substring(b"xabbay"));
+/// ```
+pub fn longest_palindromic_substring(s: &[u8]) -> (usize, usize) {
+    let rad = manacher(s);
+
+    let (i, len) = rad.iter().enumerate().fold(
+        (0, 0),
+        |(i, len), (j, &r)| if r > len { (j, r) } else { (i, len) },
+    );
+
+    ((i - len) / 2, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radii() {
+        assert_eq!(
+            vec![0, 1, 0, 3, 0, 1, 0, 7, 0, 1, 0, 3, 0, 1, 0],
+            manacher(b"abacaba")
+        );
+        assert_eq!(vec![0, 1, 2, 3, 2, 1, 0], manacher(b"aaa"));
+        assert_eq!(vec![0], manacher(b""));
+        assert_eq!(vec![0, 1, 0], manacher(b"a"));
+    }
+
+    #[test]
+    fn radii_brute_force() {
+        let s = b"abaabbabbaababa";
+        let n = s.len();
+        let rad = manacher(s);
+
+        let is_palindrome = |t: &[u8]| t.iter().eq(t.iter().rev());
+        for (i, &r) in rad.iter().enumerate() {
+            let expected = (0..=n)
+                .filter(|&len| len <= i && i + len <= 2 * n && (i + len) % 2 == 0)
+                .filter(|&len| is_palindrome(&s[(i - len) / 2..(i + len) / 2]))
+                .max()
+                .unwrap();
+            assert_eq!(expected, r);
+        }
+    }
+
+    #[test]
+    fn longest() {
+        assert_eq!((0, 7), longest_palindromic_substring(b"abacaba"));
+        assert_eq!((2, 4), longest_palindromic_substring(b"abcddcx"));
+        assert_eq!((0, 1), longest_palindromic_substring(b"abc"));
+        assert_eq!((0, 0), longest_palindromic_substring(b""));
+    }
+}
@@ -0,0 +1,154 @@
+//! Suffix automaton.
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone)]
+struct State {
+    len: usize,
+    link: Option<usize>,
+    next: BTreeMap<u8, usize>,
+}
+
+/// Suffix automaton, built online.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::sam::SuffixAutomaton;
+///
+/// let mut sam = SuffixAutomaton::new();
+/// b"abab".iter().for_each(|&c| sam.extend(c));
+///
+/// assert!(sam.contains(b"bab"));
+/// assert!(!sam.contains(b"bb"));
+/// assert_eq!(7, sam.count_distinct_substrings());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    last: usize,
+}
+
+impl SuffixAutomaton {
+    /// Constructs an automaton of the empty string.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            states: vec![State {
+                len: 0,
+                link: None,
+                next: BTreeMap::new(),
+            }],
+            last: 0,
+        }
+    }
+
+    /// Appends a character to the string.
+    /// Consumes amortized O(log σ) time.
+    pub fn extend(&mut self, c: u8) {
+        let cur = self.states.len();
+        self.states.push(State {
+            len: self.states[self.last].len + 1,
+            link: None,
+            next: BTreeMap::new(),
+        });
+
+        let mut p = Some(self.last);
+        while let Some(v) = p {
+            if self.states[v].next.contains_key(&c) {
+                break;
+            }
+            self.states[v].next.insert(c, cur);
+            p = self.states[v].link;
+        }
+
+        self.states[cur].link = Some(match p {
+            None => 0,
+            Some(p) => {
+                let q = self.states[p].next[&c];
+                if self.states[p].len + 1 == self.states[q].len {
+                    q
+                } else {
+                    let clone = self.states.len();
+                    self.states.push(State {
+                        len: self.states[p].len + 1,
+                        ..self.states[q].clone()
+                    });
+
+                    let mut p = Some(p);
+                    while let Some(v) = p {
+                        if self.states[v].next.get(&c) != Some(&q) {
+                            break;
+                        }
+                        self.states[v].next.insert(c, clone);
+                        p = self.states[v].link;
+                    }
+
+                    self.states[q].link = Some(clone);
+                    clone
+                }
+            }
+        });
+
+        self.last = cur;
+    }
+
+    /// Returns true if the pattern is a substring of the string.
+    /// Consumes O(m log σ) time.
+    pub fn contains(&self, pattern: &[u8]) -> bool {
+        pattern
+            .iter()
+            .try_fold(0, |v, c| self.states[v].next.get(c).copied())
+            .is_some()
+    }
+
+    /// Counts the distinct non-empty substrings of the string.
+    pub fn count_distinct_substrings(&self) -> u64 {
+        self.states
+            .iter()
+            .filter_map(|s| s.link.map(|l| (s.len - self.states[l].len) as u64))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::string::suffix_array;
+    use crate::utils::random::XorShift64;
+
+    fn build(s: &[u8]) -> SuffixAutomaton {
+        let mut sam = SuffixAutomaton::new();
+        s.iter().for_each(|&c| sam.extend(c));
+        sam
+    }
+
+    #[test]
+    fn contains() {
+        let s = b"abab";
+        let sam = build(s);
+
+        for l in 0..=s.len() {
+            for r in l..=s.len() {
+                assert!(sam.contains(&s[l..r]));
+            }
+        }
+        for pattern in &[&b"aa"[..], b"bb", b"bba", b"abb", b"baa", b"ababa", b"c"] {
+            assert!(!sam.contains(pattern));
+        }
+    }
+
+    #[test]
+    fn count_distinct_substrings() {
+        let mut rng = XorShift64::new(3);
+        for n in 0..100 {
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.next_range(0, 3) as u8).collect();
+
+            let sa = suffix_array::build(&s);
+            let lcp: usize = suffix_array::build_lcp(&s, &sa).iter().sum();
+            let expected = (n * (n + 1) / 2 - lcp) as u64;
+
+            assert_eq!(expected, build(&s).count_distinct_substrings());
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Suffix array and LCP array.
+
+const NONE: usize = std::usize::MAX;
+
+/// Builds the suffix array of `s`, using SA-IS.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::suffix_array::build;
+///
+/// assert_eq!(vec![5, 3, 1, 0, 4, 2], build(b"banana"));
+/// ```
+pub fn build(s: &[u8]) -> Vec<usize> {
+    let s: Vec<usize> = s.iter().map(|&c| c as usize).collect();
+    sa_is(&s, 255)
+}
+
+/// Builds the LCP array, using Kasai's algorithm.
+/// `lcp[i]` is the length of the longest common prefix of
+/// the suffixes starting at `sa[i]` and `sa[i + 1]`.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::suffix_array::{build, build_lcp};
+///
+/// let s = b"banana";
+/// let sa = build(s);
+///
+/// assert_eq!(vec![1, 3, 0, 0, 2], build_lcp(s, &sa));
+/// ```
+pub fn build_lcp(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    assert_eq!(n, sa.len());
+
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+
+    let mut lcp = vec![0; n.saturating_sub(1)];
+    let mut h: usize = 0;
+    for (i, &r) in rank.iter().enumerate() {
+        h = h.saturating_sub(1);
+        if r == 0 {
+            continue;
+        }
+
+        let j = sa[r - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[r - 1] = h;
+    }
+
+    lcp
+}
+
+/// LCP array with a sparse table for the longest common prefix of any two suffixes.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::suffix_array::{build, build_lcp, LCPArray};
+///
+/// let s = b"banana";
+/// let sa = build(s);
+/// let lcp = LCPArray::new(&sa, &build_lcp(s, &sa));
+///
+/// // "ana" and "anana"
+/// assert_eq!(3, lcp.query(1, 2));
+/// // "a" and "anana"
+/// assert_eq!(1, lcp.query(0, 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LCPArray {
+    sa: Vec<usize>,
+    // table[k][i] is the minimum of lcp[i..i + 2^k]
+    table: Vec<Vec<usize>>,
+}
+
+impl LCPArray {
+    /// Constructs the sparse table from the suffix array and the LCP array.
+    /// Consumes O(n log n) time.
+    pub fn new(sa: &[usize], lcp: &[usize]) -> Self {
+        assert_eq!(sa.len().saturating_sub(1), lcp.len());
+
+        let mut table = vec![lcp.to_vec()];
+        let mut k = 1;
+        while 2 * k <= lcp.len() {
+            let prev = table.last().unwrap();
+            let next = (0..=lcp.len() - 2 * k)
+                .map(|i| prev[i].min(prev[i + k]))
+                .collect();
+            table.push(next);
+            k *= 2;
+        }
+
+        Self {
+            sa: sa.to_vec(),
+            table,
+        }
+    }
+
+    /// Returns the length of the longest common prefix of
+    /// the suffixes starting at `sa[i]` and `sa[j]`.
+    /// Consumes O(1) time.
+    #[allow(clippy::many_single_char_names)]
+    pub fn query(&self, i: usize, j: usize) -> usize {
+        let n = self.sa.len();
+        assert!(i < n && j < n, "Index out of range");
+
+        if i == j {
+            return n - self.sa[i];
+        }
+
+        let (l, r) = (i.min(j), i.max(j));
+        let k = (63 - ((r - l) as u64).leading_zeros()) as usize;
+        self.table[k][l].min(self.table[k][r - (1 << k)])
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if s[i] == s[i + 1] {
+            ls[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for (&c, &is_s) in s.iter().zip(&ls) {
+        if is_s {
+            sum_l[c + 1] += 1;
+        } else {
+            sum_s[c] += 1;
+        }
+    }
+    for i in 0..=upper {
+        sum_s[i] += sum_l[i];
+        if i < upper {
+            sum_l[i + 1] += sum_s[i];
+        }
+    }
+
+    let induce = |lms: &[usize], sa: &mut [usize]| {
+        sa.iter_mut().for_each(|x| *x = NONE);
+
+        let mut buf = sum_s.clone();
+        for &d in lms.iter().filter(|&&d| d != n) {
+            sa[buf[s[d]]] = d;
+            buf[s[d]] += 1;
+        }
+
+        buf.copy_from_slice(&sum_l);
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != NONE && v >= 1 && !ls[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+
+        buf.copy_from_slice(&sum_l);
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != NONE && v >= 1 && ls[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    };
+
+    let lms: Vec<usize> = (1..n).filter(|&i| !ls[i - 1] && ls[i]).collect();
+    let m = lms.len();
+    let mut lms_map = vec![NONE; n + 1];
+    for (i, &p) in lms.iter().enumerate() {
+        lms_map[p] = i;
+    }
+
+    let mut sa = vec![NONE; n];
+    induce(&lms, &mut sa);
+
+    if m > 0 {
+        let mut sorted_lms: Vec<usize> =
+            sa.iter().copied().filter(|&v| lms_map[v] != NONE).collect();
+
+        let mut rec_s = vec![0; m];
+        let mut rec_upper = 0;
+        for i in 1..m {
+            let (mut l, mut r) = (sorted_lms[i - 1], sorted_lms[i]);
+            let end_l = lms.get(lms_map[l] + 1).copied().unwrap_or(n);
+            let end_r = lms.get(lms_map[r] + 1).copied().unwrap_or(n);
+
+            let same = end_l - l == end_r - r && {
+                while l < end_l && s[l] == s[r] {
+                    l += 1;
+                    r += 1;
+                }
+                l != n && r != n && s[l] == s[r]
+            };
+
+            if !same {
+                rec_upper += 1;
+            }
+            rec_s[lms_map[sorted_lms[i]]] = rec_upper;
+        }
+
+        let rec_sa = sa_is(&rec_s, rec_upper);
+        for (x, &i) in sorted_lms.iter_mut().zip(&rec_sa) {
+            *x = lms[i];
+        }
+        induce(&sorted_lms, &mut sa);
+    }
+
+    sa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn build_naive(s: &[u8]) -> Vec<usize> {
+        let mut sa: Vec<usize> = (0..s.len()).collect();
+        sa.sort_by_key(|&i| &s[i..]);
+        sa
+    }
+
+    fn lcp_naive(s: &[u8], sa: &[usize]) -> Vec<usize> {
+        sa.windows(2)
+            .map(|w| {
+                s[w[0]..]
+                    .iter()
+                    .zip(&s[w[1]..])
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn banana() {
+        let s = b"banana";
+        let sa = build(s);
+
+        assert_eq!(vec![5, 3, 1, 0, 4, 2], sa);
+        assert_eq!(vec![1, 3, 0, 0, 2], build_lcp(s, &sa));
+    }
+
+    #[test]
+    fn small() {
+        assert_eq!(Vec::<usize>::new(), build(b""));
+        assert_eq!(vec![0], build(b"a"));
+        assert_eq!(vec![1, 0], build(b"ba"));
+        assert_eq!(vec![2, 1, 0], build(b"aaa"));
+        assert_eq!(Vec::<usize>::new(), build_lcp(b"a", &[0]));
+    }
+
+    #[test]
+    fn sorted() {
+        let mut rng = XorShift64::new(1);
+        for n in 1..200 {
+            for &alphabet in &[1, 2, 3, 26] {
+                let s: Vec<u8> = (0..n)
+                    .map(|_| b'a' + rng.next_range(0, alphabet) as u8)
+                    .collect();
+
+                let sa = build(&s);
+                assert!(sa.windows(2).all(|w| s[w[0]..] < s[w[1]..]));
+                assert_eq!(build_naive(&s), sa);
+                assert_eq!(lcp_naive(&s, &sa), build_lcp(&s, &sa));
+            }
+        }
+    }
+
+    #[test]
+    fn lcp_array() {
+        let common = |a: &[u8], b: &[u8]| a.iter().zip(b).take_while(|(x, y)| x == y).count();
+
+        for &s in &[
+            &b"banana"[..],
+            b"a",
+            b"abracadabra",
+            b"aaaaaaaa",
+            b"mississippi",
+        ] {
+            let sa = build(s);
+            let lcp = LCPArray::new(&sa, &build_lcp(s, &sa));
+
+            for i in 0..s.len() {
+                for j in 0..s.len() {
+                    assert_eq!(common(&s[sa[i]..], &s[sa[j]..]), lcp.query(i, j));
+                }
+            }
+        }
+    }
+}
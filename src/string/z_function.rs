@@ -0,0 +1,88 @@
+//! Z-algorithm.
+
+/// Returns the Z-array of `s`.
+/// `z[i]` is the length of the longest common prefix of `s` and `s[i..]`.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::z_function::z_function;
+///
+/// assert_eq!(vec![6, 1, 0, 0, 2, 1], z_function(b"aabxaa"));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn z_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let mut z = vec![0; n];
+    z[0] = n;
+
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = (r - i).min(z[i - l]);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+
+    z
+}
+
+/// Returns all the starting positions of `pattern` in `text`, in ascending order.
+/// Consumes O(|text| + |pattern|) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::string::z_function::find_occurrences;
+///
+/// assert_eq!(vec![0, 2, 4], find_occurrences(b"abababa", b"aba"));
+/// ```
+pub fn find_occurrences(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+
+    let s: Vec<Option<u8>> = pattern
+        .iter()
+        .map(|&c| Some(c))
+        .chain(std::iter::once(None))
+        .chain(text.iter().map(|&c| Some(c)))
+        .collect();
+
+    z_function(&s)[m + 1..]
+        .iter()
+        .enumerate()
+        .filter(|&(_, &len)| len >= m)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn z_array() {
+        assert_eq!(vec![6, 1, 0, 0, 2, 1], z_function(b"aabxaa"));
+        assert_eq!(vec![5, 4, 3, 2, 1], z_function(b"aaaaa"));
+        assert_eq!(vec![7, 0, 1, 0, 3, 0, 1], z_function(b"abacaba"));
+        assert_eq!(Vec::<usize>::new(), z_function(b""));
+    }
+
+    #[test]
+    fn occurrences() {
+        assert_eq!(vec![0, 1, 2], find_occurrences(b"aaaa", b"aa"));
+        assert_eq!(vec![1, 4], find_occurrences(b"xabcabc", b"abc"));
+        assert_eq!(Vec::<usize>::new(), find_occurrences(b"abc", b"abcd"));
+        assert_eq!(vec![0], find_occurrences(b"abc", b"abc"));
+    }
+}
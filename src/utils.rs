@@ -1 +1,16 @@
+pub mod bisect;
+pub mod bitmask;
+pub mod bits;
+pub mod compress;
+pub mod histogram;
+pub mod inversions;
+pub mod lis;
+pub mod mo;
+pub mod output;
+pub mod perm;
+pub mod random;
+pub mod range_set;
 pub mod scan;
+pub mod search;
+pub mod sos;
+pub mod two_sum;
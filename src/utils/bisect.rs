@@ -0,0 +1,93 @@
+//! Binary search on sorted slices.
+
+use std::cmp::Ordering;
+
+/// Finds insertion points in a sorted slice, like Python's `bisect` module.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bisect::BisectExt;
+///
+/// let a = [1, 2, 2, 2, 5];
+///
+/// assert_eq!(1, a.bisect_left(&2));
+/// assert_eq!(4, a.bisect_right(&2));
+/// assert_eq!(4, a.bisect_left(&3));
+/// ```
+pub trait BisectExt<T> {
+    /// Returns the first index where x can be inserted keeping the order,
+    /// i.e. the number of the elements less than x.
+    fn bisect_left(&self, x: &T) -> usize;
+
+    /// Returns the last index where x can be inserted keeping the order,
+    /// i.e. the number of the elements not greater than x.
+    fn bisect_right(&self, x: &T) -> usize;
+}
+
+impl<T: Ord> BisectExt<T> for [T] {
+    fn bisect_left(&self, x: &T) -> usize {
+        self.binary_search_by(|y| match y.cmp(x) {
+            Ordering::Less => Ordering::Less,
+            _ => Ordering::Greater,
+        })
+        .unwrap_err()
+    }
+
+    fn bisect_right(&self, x: &T) -> usize {
+        self.binary_search_by(|y| match y.cmp(x) {
+            Ordering::Greater => Ordering::Greater,
+            _ => Ordering::Less,
+        })
+        .unwrap_err()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absent() {
+        let a = [1, 3, 5];
+
+        assert_eq!((0, 0), (a.bisect_left(&0), a.bisect_right(&0)));
+        assert_eq!((1, 1), (a.bisect_left(&2), a.bisect_right(&2)));
+        assert_eq!((3, 3), (a.bisect_left(&6), a.bisect_right(&6)));
+    }
+
+    #[test]
+    fn boundaries() {
+        let a = [1, 3, 5];
+
+        assert_eq!((0, 1), (a.bisect_left(&1), a.bisect_right(&1)));
+        assert_eq!((2, 3), (a.bisect_left(&5), a.bisect_right(&5)));
+    }
+
+    #[test]
+    fn duplicates() {
+        let a = [2, 2, 2, 4, 4, 7];
+
+        assert_eq!((0, 3), (a.bisect_left(&2), a.bisect_right(&2)));
+        assert_eq!((3, 5), (a.bisect_left(&4), a.bisect_right(&4)));
+        assert_eq!((0, 6), ([7; 6].bisect_left(&7), [7; 6].bisect_right(&7)));
+    }
+
+    #[test]
+    fn empty() {
+        let a: Vec<i32> = vec![];
+
+        assert_eq!(0, a.bisect_left(&1));
+        assert_eq!(0, a.bisect_right(&1));
+    }
+
+    #[test]
+    fn naive() {
+        let a: Vec<i32> = vec![0, 0, 1, 3, 3, 3, 4, 8, 8, 9];
+
+        for x in -1..=10 {
+            assert_eq!(a.iter().filter(|&&y| y < x).count(), a.bisect_left(&x));
+            assert_eq!(a.iter().filter(|&&y| y <= x).count(), a.bisect_right(&x));
+        }
+    }
+}
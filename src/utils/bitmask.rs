@@ -0,0 +1,92 @@
+//! Subset and superset enumeration.
+
+/// Enumerates the submasks of mask in decreasing order, including mask itself and 0.
+/// Same as `bits::enumerate_submasks`.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bitmask::subsets;
+///
+/// assert_eq!(vec![0b1010, 0b1000, 0b0010, 0], subsets(0b1010).collect::<Vec<_>>());
+/// ```
+pub fn subsets(mask: u64) -> impl Iterator<Item = u64> {
+    super::bits::enumerate_submasks(mask)
+}
+
+/// Enumerates the supersets of mask within universe in increasing order,
+/// including mask itself and universe.
+///
+/// # Panics
+/// Panics if mask is not a subset of universe.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bitmask::supersets;
+///
+/// assert_eq!(vec![0b0010, 0b0011, 0b1010, 0b1011], supersets(0b0010, 0b1011).collect::<Vec<_>>());
+/// ```
+pub fn supersets(mask: u64, universe: u64) -> impl Iterator<Item = u64> {
+    assert_eq!(
+        mask,
+        mask & universe,
+        "The mask is not a subset of the universe"
+    );
+
+    std::iter::successors(Some(mask), move |&sup| {
+        if sup == universe {
+            None
+        } else {
+            // increments the bits of universe \ mask as a counter
+            Some(((sup | !universe).wrapping_add(1) | mask) & universe)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsets_of_mask() {
+        let subs: Vec<u64> = subsets(0b1010).collect();
+
+        assert_eq!(4, subs.len());
+        assert!(subs.iter().all(|&s| s & 0b1010 == s));
+        assert_eq!(vec![0], subsets(0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn full_set() {
+        for n in 0..12 {
+            let full = (1 << n) - 1;
+            let mut subs: Vec<u64> = subsets(full).collect();
+            assert_eq!(1 << n, subs.len());
+
+            subs.sort();
+            assert_eq!((0..1 << n).collect::<Vec<_>>(), subs);
+        }
+    }
+
+    #[test]
+    fn supersets_of_mask() {
+        let universe = 0b1101_0110;
+        for mask in subsets(universe) {
+            let sups: Vec<u64> = supersets(mask, universe).collect();
+            let naive: Vec<u64> = (0..=universe)
+                .filter(|&s| s & mask == mask && s & universe == s)
+                .collect();
+            assert_eq!(naive, sups);
+        }
+
+        assert_eq!(2, supersets(std::u64::MAX - 1, std::u64::MAX).count());
+        assert_eq!(1, supersets(0, 0).count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn supersets_outside_universe() {
+        let _ = supersets(0b100, 0b011);
+    }
+}
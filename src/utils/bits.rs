@@ -0,0 +1,136 @@
+//! Bit manipulation.
+
+/// Returns the lowest set bit of x, or 0 if x is 0.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bits::lowest_set_bit;
+///
+/// assert_eq!(0b100, lowest_set_bit(0b10100));
+/// ```
+pub fn lowest_set_bit(x: u64) -> u64 {
+    x & x.wrapping_neg()
+}
+
+/// Returns the highest set bit of x, or 0 if x is 0.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bits::highest_set_bit;
+///
+/// assert_eq!(0b10000, highest_set_bit(0b10100));
+/// ```
+pub fn highest_set_bit(x: u64) -> u64 {
+    if x == 0 {
+        0
+    } else {
+        1 << (63 - x.leading_zeros())
+    }
+}
+
+/// Returns the smallest number greater than x with the same number of set bits,
+/// using Gosper's hack.
+///
+/// # Panics
+/// Panics if x is 0, or if there is no such number in u64.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bits::next_set_with_same_popcount;
+///
+/// assert_eq!(0b1_0011, next_set_with_same_popcount(0b1110));
+/// ```
+pub fn next_set_with_same_popcount(x: u64) -> u64 {
+    assert_ne!(0, x);
+
+    let c = lowest_set_bit(x);
+    let r = x.checked_add(c).expect("There is no next number");
+    (((r ^ x) >> 2) / c) | r
+}
+
+/// Enumerates the submasks of mask in decreasing order, including mask itself and 0.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::bits::enumerate_submasks;
+///
+/// let subs: Vec<u64> = enumerate_submasks(0b101).collect();
+/// assert_eq!(vec![0b101, 0b100, 0b001, 0b000], subs);
+/// ```
+pub fn enumerate_submasks(mask: u64) -> impl Iterator<Item = u64> {
+    std::iter::successors(Some(mask), move |&sub| {
+        if sub == 0 {
+            None
+        } else {
+            Some((sub - 1) & mask)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_bits() {
+        assert_eq!(0, lowest_set_bit(0));
+        assert_eq!(0, highest_set_bit(0));
+        assert_eq!(1, lowest_set_bit(std::u64::MAX));
+        assert_eq!(1 << 63, highest_set_bit(std::u64::MAX));
+        assert_eq!(1 << 63, lowest_set_bit(1 << 63));
+        assert_eq!(1, highest_set_bit(1));
+    }
+
+    #[test]
+    fn same_popcount() {
+        let mut x = 0b111;
+        let mut count = 1;
+        while x < 1 << 10 {
+            let y = next_set_with_same_popcount(x);
+            assert!(y > x);
+            assert_eq!(3, y.count_ones());
+            assert!((x + 1..y).all(|z| z.count_ones() != 3));
+            x = y;
+            count += 1;
+        }
+        // the numbers below 2^10 with 3 set bits, and one more
+        assert_eq!(120 + 1, count);
+
+        assert_eq!(
+            0xBFFF_FFFF_FFFF_FFFF,
+            next_set_with_same_popcount(std::u64::MAX >> 1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn same_popcount_overflow() {
+        next_set_with_same_popcount(std::u64::MAX);
+    }
+
+    #[test]
+    fn submasks() {
+        for &mask in &[0, 1, 0b1011_0110, 0xF0F0] {
+            let subs: Vec<u64> = enumerate_submasks(mask).collect();
+
+            assert_eq!(1 << mask.count_ones(), subs.len());
+            assert!(subs.iter().all(|&s| s & mask == s));
+            assert!(subs.windows(2).all(|w| w[0] > w[1]));
+        }
+
+        assert_eq!(
+            Some(std::u64::MAX),
+            enumerate_submasks(std::u64::MAX).next()
+        );
+        assert_eq!(
+            vec![std::u64::MAX, std::u64::MAX - 1, std::u64::MAX - 2],
+            enumerate_submasks(std::u64::MAX)
+                .take(3)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Coordinate compression.
+
+/// Compresses the values into ranks among the distinct values.
+/// Returns the ranks in the same order as `data`, and the sorted distinct values.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::compress::compress;
+///
+/// let (ranks, values) = compress(&[100, -5, 100, 42]);
+///
+/// assert_eq!(vec![2, 0, 2, 1], ranks);
+/// assert_eq!(vec![-5, 42, 100], values);
+/// ```
+pub fn compress<T: Ord + Clone>(data: &[T]) -> (Vec<usize>, Vec<T>) {
+    let compressor = Compressor::new(data);
+    let ranks = data.iter().map(|x| compressor.rank(x)).collect();
+    (ranks, compressor.values)
+}
+
+/// Compresses several arrays together into ranks among all their distinct values,
+/// so that equal values share a rank across the arrays.
+/// Returns the ranks of each array in the same order, and the sorted distinct values.
+/// Consumes O(n log n) time for n values in total.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::compress::multi_compress;
+///
+/// let (ranks, values) = multi_compress(&[&[1, 5, 3], &[3, 7, 1]]);
+///
+/// assert_eq!(vec![vec![0, 2, 1], vec![1, 3, 0]], ranks);
+/// assert_eq!(vec![1, 3, 5, 7], values);
+/// ```
+pub fn multi_compress<T: Ord + Clone>(arrs: &[&[T]]) -> (Vec<Vec<usize>>, Vec<T>) {
+    let all: Vec<T> = arrs.iter().flat_map(|a| a.iter().cloned()).collect();
+    let compressor = Compressor::new(&all);
+    let ranks = arrs
+        .iter()
+        .map(|a| a.iter().map(|x| compressor.rank(x)).collect())
+        .collect();
+    (ranks, compressor.values)
+}
+
+/// Compresses the data together with the query points,
+/// so that the queries can be located among the ranks of the data.
+/// Returns the ranks of the data, the ranks of the queries, and the sorted distinct values.
+/// Consumes O((n + q) log(n + q)) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::compress::compress_with_queries;
+///
+/// let (ranks, query_ranks, values) = compress_with_queries(&[10, 30], &[20, 30]);
+///
+/// assert_eq!(vec![0, 2], ranks);
+/// assert_eq!(vec![1, 2], query_ranks);
+/// assert_eq!(vec![10, 20, 30], values);
+/// ```
+pub fn compress_with_queries<T: Ord + Clone>(
+    data: &[T],
+    queries: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    let (mut ranks, values) = multi_compress(&[data, queries]);
+    let query_ranks = ranks.pop().unwrap();
+    let data_ranks = ranks.pop().unwrap();
+    (data_ranks, query_ranks, values)
+}
+
+/// Maps values to their ranks among the distinct values, and vice versa.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::compress::Compressor;
+///
+/// let cmp = Compressor::new(&[30, 10, 20, 10]);
+///
+/// assert_eq!(3, cmp.len());
+/// assert_eq!(1, cmp.rank(&20));
+/// assert_eq!(&30, cmp.value(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compressor<T> {
+    values: Vec<T>,
+}
+
+impl<T: Ord + Clone> Compressor<T> {
+    /// Constructs a new compressor.
+    /// Consumes O(n log n) time.
+    pub fn new(data: &[T]) -> Self {
+        let mut values = data.to_vec();
+        values.sort();
+        values.dedup();
+        Self { values }
+    }
+
+    /// Returns the rank of x.
+    /// Consumes O(log n) time.
+    ///
+    /// # Panics
+    /// Panics if x is not one of the values.
+    pub fn rank(&self, x: &T) -> usize {
+        self.values
+            .binary_search(x)
+            .expect("The value is not registered")
+    }
+
+    /// Returns the value of the rank.
+    pub fn value(&self, rank: usize) -> &T {
+        &self.values[rank]
+    }
+
+    /// Returns the number of distinct values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, 1_000) as i64 * 1_000_000_000_000)
+            .collect()
+    }
+
+    #[test]
+    fn order_preserving() {
+        let a = random_vec(1000, 1);
+        let (ranks, values) = compress(&a);
+
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+        assert!(ranks.iter().all(|&r| r < values.len()));
+        for i in 0..a.len() {
+            for j in 0..a.len() {
+                assert_eq!(a[i].cmp(&a[j]), ranks[i].cmp(&ranks[j]));
+            }
+        }
+    }
+
+    #[test]
+    fn small() {
+        let (ranks, values) = compress(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(vec![2, 0, 3, 0, 4, 6, 1, 5], ranks);
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 9], values);
+
+        let (ranks, values) = compress::<i32>(&[]);
+        assert!(ranks.is_empty() && values.is_empty());
+    }
+
+    #[test]
+    fn with_queries() {
+        let (ranks, query_ranks, values) =
+            compress_with_queries(&[3, 1, 4, 1, 5, 9, 2, 6], &[0, 5, 7, 10]);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7, 9, 10], values);
+        assert_eq!(vec![3, 1, 4, 1, 5, 8, 2, 6], ranks);
+        assert_eq!(vec![0, 5, 7, 9], query_ranks);
+
+        let (ranks, query_ranks, values) = compress_with_queries(&["b", "a"], &[]);
+        assert_eq!(vec![1, 0], ranks);
+        assert!(query_ranks.is_empty());
+        assert_eq!(vec!["a", "b"], values);
+    }
+
+    #[test]
+    fn multi() {
+        let a = random_vec(300, 3);
+        let b = random_vec(200, 4);
+        let (ranks, values) = multi_compress(&[&a, &b, &[]]);
+
+        assert_eq!(3, ranks.len());
+        assert!(ranks[2].is_empty());
+        // the same as compressing the concatenation
+        let all: Vec<i64> = a.iter().chain(&b).copied().collect();
+        let (all_ranks, all_values) = compress(&all);
+        assert_eq!(all_values, values);
+        assert_eq!(&all_ranks[..300], &ranks[0][..]);
+        assert_eq!(&all_ranks[300..], &ranks[1][..]);
+
+        let (ranks, values) = multi_compress::<i64>(&[]);
+        assert!(ranks.is_empty() && values.is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = random_vec(1000, 2);
+        let cmp = Compressor::new(&a);
+
+        for x in a.iter() {
+            assert_eq!(x, cmp.value(cmp.rank(x)));
+        }
+        for r in 0..cmp.len() {
+            assert_eq!(r, cmp.rank(cmp.value(r)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rank_absent() {
+        Compressor::new(&[1, 3]).rank(&2);
+    }
+}
@@ -0,0 +1,113 @@
+//! Largest rectangles in histograms.
+
+/// Returns the largest area of an axis-aligned rectangle under a histogram,
+/// using a monotone stack. The heights are required to be non-negative.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::histogram::largest_rectangle;
+///
+/// assert_eq!(10, largest_rectangle(&[2, 1, 5, 6, 2, 3]));
+/// ```
+pub fn largest_rectangle(heights: &[i64]) -> i64 {
+    // (start, height) with increasing heights; each bar extends left to its start
+    let mut stack: Vec<(usize, i64)> = vec![];
+    let mut best = 0;
+
+    for (i, &h) in heights.iter().chain(&[0]).enumerate() {
+        let mut start = i;
+        while let Some(&(s, top)) = stack.last() {
+            if top < h {
+                break;
+            }
+            best = best.max(top * (i - s) as i64);
+            start = s;
+            stack.pop();
+        }
+        stack.push((start, h));
+    }
+
+    best
+}
+
+/// Returns the largest area of a rectangle of cells `b'1'` in a grid,
+/// taking the heights of consecutive `b'1'` cells up to each row as a histogram.
+/// Consumes O(hw) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::histogram::maximal_rectangle_in_grid;
+///
+/// let grid: [&[u8]; 4] = [b"10100", b"10111", b"11111", b"10010"];
+/// assert_eq!(6, maximal_rectangle_in_grid(&grid));
+/// ```
+pub fn maximal_rectangle_in_grid(grid: &[&[u8]]) -> i64 {
+    let w = grid.first().map_or(0, |row| row.len());
+    let mut heights = vec![0; w];
+    let mut best = 0;
+
+    for row in grid {
+        assert_eq!(w, row.len(), "The grid is required to be rectangular");
+        for (h, &c) in heights.iter_mut().zip(row.iter()) {
+            *h = if c == b'1' { *h + 1 } else { 0 };
+        }
+        best = best.max(largest_rectangle(&heights));
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn naive(heights: &[i64]) -> i64 {
+        (0..heights.len())
+            .flat_map(|l| (l + 1..=heights.len()).map(move |r| (l, r)))
+            .map(|(l, r)| heights[l..r].iter().min().unwrap() * (r - l) as i64)
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn histogram() {
+        assert_eq!(10, largest_rectangle(&[2, 1, 5, 6, 2, 3]));
+        assert_eq!(4, largest_rectangle(&[2, 4]));
+        assert_eq!(7 * 5, largest_rectangle(&[5; 7]));
+        assert_eq!(42, largest_rectangle(&[42]));
+        assert_eq!(0, largest_rectangle(&[]));
+        assert_eq!(0, largest_rectangle(&[0, 0]));
+        assert_eq!(9, largest_rectangle(&[1, 2, 3, 4, 5]));
+        assert_eq!(9, largest_rectangle(&[5, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn histogram_random() {
+        let mut rng = XorShift64::new(405);
+        for _ in 0..300 {
+            let n = rng.next_range(0, 30) as usize;
+            let heights: Vec<i64> = (0..n).map(|_| rng.next_range(0, 10) as i64).collect();
+            assert_eq!(naive(&heights), largest_rectangle(&heights));
+        }
+    }
+
+    #[test]
+    fn grid() {
+        let grid: [&[u8]; 4] = [b"10100", b"10111", b"11111", b"10010"];
+        assert_eq!(6, maximal_rectangle_in_grid(&grid));
+
+        let grid: [&[u8]; 1] = [b"0"];
+        assert_eq!(0, maximal_rectangle_in_grid(&grid));
+        let grid: [&[u8]; 1] = [b"1"];
+        assert_eq!(1, maximal_rectangle_in_grid(&grid));
+        assert_eq!(0, maximal_rectangle_in_grid(&[]));
+
+        let full = vec![b'1'; 7];
+        let grid = vec![&full[..]; 3];
+        assert_eq!(21, maximal_rectangle_in_grid(&grid));
+    }
+}
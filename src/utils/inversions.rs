@@ -0,0 +1,118 @@
+//! Inversion number.
+
+use super::compress::compress;
+use crate::data_structures::fenwick::Fenwick;
+
+/// Counts the pairs i < j with `data[i] > data[j]`,
+/// using coordinate compression and a Fenwick Tree.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::inversions::inversion_count;
+///
+/// assert_eq!(5, inversion_count(&[3, 1, 2, 0]));
+/// ```
+pub fn inversion_count<T: Ord + Clone>(data: &[T]) -> usize {
+    let (ranks, values) = compress(data);
+    let mut fw = Fenwick::from_slice(&vec![0; values.len()]);
+
+    ranks
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let not_greater = fw.sum(r + 1) as usize;
+            fw.add(r + 1, 1);
+            i - not_greater
+        })
+        .sum()
+}
+
+/// Counts the pairs i < j with `data[i] > data[j]`, using merge sort.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::inversions::inversion_count_merge_sort;
+///
+/// assert_eq!(5, inversion_count_merge_sort(&[3, 1, 2, 0]));
+/// ```
+pub fn inversion_count_merge_sort<T: Ord + Clone>(data: &[T]) -> usize {
+    let mut a = data.to_vec();
+    let mut buf = Vec::with_capacity(a.len());
+    merge_sort(&mut a, &mut buf)
+}
+
+#[allow(clippy::many_single_char_names)]
+fn merge_sort<T: Ord + Clone>(a: &mut [T], buf: &mut Vec<T>) -> usize {
+    let n = a.len();
+    if n <= 1 {
+        return 0;
+    }
+
+    let (l, r) = a.split_at_mut(n / 2);
+    let mut count = merge_sort(l, buf) + merge_sort(r, buf);
+
+    buf.clear();
+    let (mut i, mut j) = (0, 0);
+    while i < l.len() || j < r.len() {
+        if j == r.len() || (i < l.len() && l[i] <= r[j]) {
+            buf.push(l[i].clone());
+            i += 1;
+        } else {
+            count += l.len() - i;
+            buf.push(r[j].clone());
+            j += 1;
+        }
+    }
+    a.clone_from_slice(buf);
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, modulus)).collect()
+    }
+
+    fn inversion_count_naive<T: Ord>(data: &[T]) -> usize {
+        (0..data.len())
+            .map(|j| (0..j).filter(|&i| data[i] > data[j]).count())
+            .sum()
+    }
+
+    #[test]
+    fn sorted() {
+        let a: Vec<u32> = (0..100).collect();
+        assert_eq!(0, inversion_count(&a));
+        assert_eq!(0, inversion_count_merge_sort(&a));
+        assert_eq!(0, inversion_count(&[7; 10]));
+        assert_eq!(0, inversion_count::<u32>(&[]));
+    }
+
+    #[test]
+    fn reversed() {
+        let n = 100;
+        let a: Vec<u32> = (0..n).rev().collect();
+        assert_eq!((n * (n - 1) / 2) as usize, inversion_count(&a));
+        assert_eq!((n * (n - 1) / 2) as usize, inversion_count_merge_sort(&a));
+    }
+
+    #[test]
+    fn random() {
+        for &(n, m) in &[(1, 1), (10, 3), (100, 10), (300, 1_000_000)] {
+            let a = random_vec(n, n as u64, m);
+            let expected = inversion_count_naive(&a);
+
+            assert_eq!(expected, inversion_count(&a));
+            assert_eq!(expected, inversion_count_merge_sort(&a));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Longest increasing subsequence.
+
+use std::cmp::Ordering;
+
+/// Returns the length of the longest strictly increasing subsequence.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::lis::lis_length;
+///
+/// assert_eq!(4, lis_length(&[10, 9, 2, 5, 3, 7, 101, 18]));
+/// ```
+pub fn lis_length<T: Ord>(data: &[T]) -> usize {
+    let mut tails: Vec<&T> = vec![];
+    for x in data {
+        let i = lower_bound(&tails, |t| *t < x);
+        if i == tails.len() {
+            tails.push(x);
+        } else {
+            tails[i] = x;
+        }
+    }
+    tails.len()
+}
+
+/// Returns the length of the longest non-decreasing subsequence.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::lis::lis_non_decreasing;
+///
+/// assert_eq!(5, lis_non_decreasing(&[1, 3, 3, 2, 3, 4]));
+/// ```
+pub fn lis_non_decreasing<T: Ord>(data: &[T]) -> usize {
+    let mut tails: Vec<&T> = vec![];
+    for x in data {
+        let i = lower_bound(&tails, |t| *t <= x);
+        if i == tails.len() {
+            tails.push(x);
+        } else {
+            tails[i] = x;
+        }
+    }
+    tails.len()
+}
+
+/// Returns a longest strictly increasing subsequence.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::lis::lis;
+///
+/// assert_eq!(vec![2, 3, 7, 18], lis(&[10, 9, 2, 5, 3, 7, 101, 18]));
+/// ```
+pub fn lis<T: Ord + Clone>(data: &[T]) -> Vec<T> {
+    // indices of the last elements of the subsequences of each length
+    let mut tails: Vec<usize> = vec![];
+    let mut parent = vec![None; data.len()];
+
+    for (j, x) in data.iter().enumerate() {
+        let i = lower_bound(&tails, |&t| data[t] < *x);
+        parent[j] = if i == 0 { None } else { Some(tails[i - 1]) };
+        if i == tails.len() {
+            tails.push(j);
+        } else {
+            tails[i] = j;
+        }
+    }
+
+    let mut res: Vec<T> = std::iter::successors(tails.last().copied(), |&j| parent[j])
+        .map(|j| data[j].clone())
+        .collect();
+    res.reverse();
+    res
+}
+
+// the first index where pred turns false
+fn lower_bound<T>(a: &[T], pred: impl Fn(&T) -> bool) -> usize {
+    a.binary_search_by(|x| {
+        if pred(x) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    })
+    .unwrap_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<u64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len).map(|_| rng.next_range(0, modulus)).collect()
+    }
+
+    fn lis_naive(data: &[u64], strict: bool) -> usize {
+        let mut dp = vec![1; data.len()];
+        for j in 0..data.len() {
+            for i in 0..j {
+                if data[i] < data[j] || (!strict && data[i] == data[j]) {
+                    dp[j] = dp[j].max(dp[i] + 1);
+                }
+            }
+        }
+        dp.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn classic() {
+        let a = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+        assert_eq!(6, lis_length(&a));
+        assert_eq!(6, lis(&a).len());
+        assert_eq!(0, lis_length::<u32>(&[]));
+        assert!(lis::<u32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn all_equal() {
+        let a = [5; 10];
+        assert_eq!(1, lis_length(&a));
+        assert_eq!(vec![5], lis(&a));
+        assert_eq!(10, lis_non_decreasing(&a));
+    }
+
+    #[test]
+    fn random() {
+        for n in 0..100 {
+            let a = random_vec(n, n as u64, 10);
+            assert_eq!(lis_naive(&a, true), lis_length(&a));
+            assert_eq!(lis_naive(&a, false), lis_non_decreasing(&a));
+
+            let s = lis(&a);
+            assert_eq!(lis_length(&a), s.len());
+            assert!(s.windows(2).all(|w| w[0] < w[1]));
+
+            // s is a subsequence of a
+            let mut it = a.iter();
+            assert!(s.iter().all(|x| it.any(|y| x == y)));
+        }
+    }
+}
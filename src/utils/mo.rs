@@ -0,0 +1,171 @@
+//! Mo's algorithm.
+
+/// Mo's algorithm for offline range queries on half-open intervals.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::mo::Mo;
+///
+/// let a = [1, 2, 1, 3, 2];
+///
+/// let mut mo = Mo::new(a.len());
+/// mo.add_query(0, 3);
+/// mo.add_query(1, 5);
+///
+/// // the number of distinct values
+/// let res = mo.run(
+///     (vec![0; 4], 0),
+///     |(cnt, k), i| {
+///         cnt[a[i]] += 1;
+///         if cnt[a[i]] == 1 {
+///             *k += 1;
+///         }
+///     },
+///     |(cnt, k), i| {
+///         cnt[a[i]] -= 1;
+///         if cnt[a[i]] == 0 {
+///             *k -= 1;
+///         }
+///     },
+///     |(_, k)| *k,
+/// );
+/// assert_eq!(vec![2, 3], res);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mo {
+    n: usize,
+    queries: Vec<(usize, usize)>,
+}
+
+impl Mo {
+    /// Constructs a new instance for a sequence of length n.
+    pub fn new(n: usize) -> Self {
+        Self { n, queries: vec![] }
+    }
+
+    /// Adds a query on [l, r). Returns the id of the query.
+    ///
+    /// # Panics
+    /// Panics unless `l <= r <= n`.
+    pub fn add_query(&mut self, l: usize, r: usize) -> usize {
+        assert!(l <= r && r <= self.n);
+        self.queries.push((l, r));
+        self.queries.len() - 1
+    }
+
+    /// Processes the queries starting from the empty interval with `state`.
+    /// `add` and `remove` update the state by an element at the index,
+    /// and `get` computes the answer of the current interval.
+    /// Returns the answers ordered by the query ids.
+    /// Consumes O((n + q) √n) calls of `add` and `remove`.
+    pub fn run<S, T, Add, Remove, Get>(
+        &self,
+        mut state: S,
+        mut add: Add,
+        mut remove: Remove,
+        mut get: Get,
+    ) -> Vec<T>
+    where
+        Add: FnMut(&mut S, usize),
+        Remove: FnMut(&mut S, usize),
+        Get: FnMut(&S) -> T,
+    {
+        let q = self.queries.len();
+        let width = std::cmp::max(1, (self.n as f64 / (q.max(1) as f64).sqrt()) as usize);
+
+        let mut order: Vec<usize> = (0..q).collect();
+        order.sort_by_key(|&i| {
+            let (l, r) = self.queries[i];
+            let block = l / width;
+            (block, if block % 2 == 0 { r } else { self.n - r })
+        });
+
+        let mut res: Vec<Option<T>> = (0..q).map(|_| None).collect();
+        let (mut cur_l, mut cur_r) = (0, 0);
+        for i in order {
+            let (l, r) = self.queries[i];
+            while cur_l > l {
+                cur_l -= 1;
+                add(&mut state, cur_l);
+            }
+            while cur_r < r {
+                add(&mut state, cur_r);
+                cur_r += 1;
+            }
+            while cur_l < l {
+                remove(&mut state, cur_l);
+                cur_l += 1;
+            }
+            while cur_r > r {
+                cur_r -= 1;
+                remove(&mut state, cur_r);
+            }
+            res[i] = Some(get(&state));
+        }
+
+        res.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<usize> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, modulus) as usize)
+            .collect()
+    }
+
+    #[test]
+    fn distinct() {
+        let n = 200;
+        let a = random_vec(n, 1, 30);
+        let ends = random_vec(400, 2, n as u64 + 1);
+
+        let mut mo = Mo::new(n);
+        let mut queries = vec![];
+        for w in ends.chunks(2) {
+            let (l, r) = (w[0].min(w[1]), w[0].max(w[1]));
+            assert_eq!(queries.len(), mo.add_query(l, r));
+            queries.push((l, r));
+        }
+
+        let res = mo.run(
+            (vec![0; 30], 0),
+            |(cnt, k), i| {
+                cnt[a[i]] += 1;
+                if cnt[a[i]] == 1 {
+                    *k += 1;
+                }
+            },
+            |(cnt, k), i| {
+                cnt[a[i]] -= 1;
+                if cnt[a[i]] == 0 {
+                    *k -= 1;
+                }
+            },
+            |(_, k)| *k,
+        );
+
+        let naive: Vec<usize> = queries
+            .iter()
+            .map(|&(l, r)| {
+                let mut b = a[l..r].to_vec();
+                b.sort();
+                b.dedup();
+                b.len()
+            })
+            .collect();
+        assert_eq!(naive, res);
+    }
+
+    #[test]
+    fn no_queries() {
+        let res: Vec<i32> = Mo::new(10).run((), |_, _| {}, |_, _| {}, |_| 0);
+        assert!(res.is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+use cargo_snippet::snippet;
+
+#[snippet(doc_hidden, "output")]
+use std::fmt::Display;
+
+#[snippet(doc_hidden, "output")]
+use std::io::{BufWriter, Stdout, Write};
+
+/// Wraps a writer in `BufWriter`. Prints values.
+/// The output is flushed when dropped.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::output::BufPrinter;
+///
+/// let mut buf = Vec::new();
+/// {
+///     let mut out = BufPrinter::with_writer(&mut buf);
+///     out.println(3);
+///     out.print_space_separated(&[1, 2, 3]);
+///     out.yes_no(false);
+/// }
+/// assert_eq!(b"3\n1 2 3\nNo\n", buf.as_slice());
+/// ```
+#[snippet(doc_hidden, "output")]
+pub struct BufPrinter<W: Write> {
+    out: BufWriter<W>,
+}
+
+#[snippet(doc_hidden, "output")]
+impl BufPrinter<Stdout> {
+    /// Builds a printer to the standard output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::output::BufPrinter;
+    ///
+    /// let mut out = BufPrinter::new();
+    /// out.println("Hello");
+    /// ```
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stdout())
+    }
+}
+
+#[snippet(doc_hidden, "output")]
+impl<W: Write> BufPrinter<W> {
+    /// Builds a printer out of a writer.
+    pub fn with_writer(writer: W) -> Self {
+        Self {
+            out: BufWriter::new(writer),
+        }
+    }
+
+    /// Prints x and a newline.
+    pub fn println(&mut self, x: impl Display) {
+        writeln!(self.out, "{}", x).unwrap();
+    }
+
+    /// Prints the values separated by spaces, and a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::output::BufPrinter;
+    ///
+    /// let mut buf = Vec::new();
+    /// BufPrinter::with_writer(&mut buf).print_space_separated(&["a", "b"]);
+    ///
+    /// assert_eq!(b"a b\n", buf.as_slice());
+    /// ```
+    pub fn print_space_separated<T: Display>(&mut self, v: &[T]) {
+        for (i, x) in v.iter().enumerate() {
+            if i > 0 {
+                write!(self.out, " ").unwrap();
+            }
+            write!(self.out, "{}", x).unwrap();
+        }
+        writeln!(self.out).unwrap();
+    }
+
+    /// Prints "Yes" if b is true, and "No" otherwise.
+    pub fn yes_no(&mut self, b: bool) {
+        self.println(if b { "Yes" } else { "No" });
+    }
+
+    /// Flushes the buffer.
+    pub fn flush(&mut self) {
+        self.out.flush().unwrap();
+    }
+}
+
+#[snippet(doc_hidden, "output")]
+impl<W: Write> Drop for BufPrinter<W> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capture<F: FnOnce(&mut BufPrinter<&mut Vec<u8>>)>(f: F) -> String {
+        let mut buf = Vec::new();
+        f(&mut BufPrinter::with_writer(&mut buf));
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn println() {
+        let s = capture(|out| {
+            out.println(42);
+            out.println("abc");
+            out.println(-1.5);
+        });
+        assert_eq!(s, "42\nabc\n-1.5\n");
+    }
+
+    #[test]
+    fn space_separated() {
+        assert_eq!(
+            capture(|out| out.print_space_separated(&[1, 2, 3])),
+            "1 2 3\n"
+        );
+        assert_eq!(capture(|out| out.print_space_separated(&['x'])), "x\n");
+        assert_eq!(capture(|out| out.print_space_separated::<i32>(&[])), "\n");
+    }
+
+    #[test]
+    fn yes_no() {
+        let s = capture(|out| {
+            out.yes_no(true);
+            out.yes_no(false);
+        });
+        assert_eq!(s, "Yes\nNo\n");
+    }
+
+    #[test]
+    fn flush() {
+        let mut buf = Vec::new();
+        let mut out = BufPrinter::with_writer(&mut buf);
+        out.println(1);
+        out.flush();
+        drop(out);
+        assert_eq!(buf, b"1\n");
+    }
+}
@@ -0,0 +1,111 @@
+//! Permutation enumeration.
+
+/// Rearranges `a` into the next permutation in lexicographical order,
+/// like C++ `std::next_permutation`.
+/// Returns false and rearranges into the first permutation if `a` is the last one.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::perm::next_permutation;
+///
+/// let mut a = [1, 3, 2];
+///
+/// assert!(next_permutation(&mut a));
+/// assert_eq!([2, 1, 3], a);
+/// ```
+pub fn next_permutation<T: Ord>(a: &mut [T]) -> bool {
+    let i = match (1..a.len()).rev().find(|&i| a[i - 1] < a[i]) {
+        Some(i) => i,
+        None => {
+            a.reverse();
+            return false;
+        }
+    };
+
+    let j = (i..a.len()).rev().find(|&j| a[i - 1] < a[j]).unwrap();
+    a.swap(i - 1, j);
+    a[i..].reverse();
+    true
+}
+
+/// Rearranges `a` into the previous permutation in lexicographical order,
+/// like C++ `std::prev_permutation`.
+/// Returns false and rearranges into the last permutation if `a` is the first one.
+/// Consumes O(n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::perm::prev_permutation;
+///
+/// let mut a = [2, 1, 3];
+///
+/// assert!(prev_permutation(&mut a));
+/// assert_eq!([1, 3, 2], a);
+/// ```
+pub fn prev_permutation<T: Ord>(a: &mut [T]) -> bool {
+    let i = match (1..a.len()).rev().find(|&i| a[i - 1] > a[i]) {
+        Some(i) => i,
+        None => {
+            a.reverse();
+            return false;
+        }
+    };
+
+    let j = (i..a.len()).rev().find(|&j| a[i - 1] > a[j]).unwrap();
+    a.swap(i - 1, j);
+    a[i..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumerate() {
+        let mut a = [1, 2, 3];
+        let mut perms = vec![a];
+        while next_permutation(&mut a) {
+            perms.push(a);
+        }
+
+        assert_eq!(6, perms.len());
+        assert!(perms.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!([1, 2, 3], a);
+
+        let mut rev = vec![[3, 2, 1]];
+        let mut b = [3, 2, 1];
+        while prev_permutation(&mut b) {
+            rev.push(b);
+        }
+        rev.reverse();
+        assert_eq!(perms, rev);
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut a = [1, 1, 2, 2];
+        let mut count = 1;
+        while next_permutation(&mut a) {
+            count += 1;
+        }
+        assert_eq!(6, count);
+    }
+
+    #[test]
+    fn last() {
+        let mut a = [3, 2, 1];
+        assert!(!next_permutation(&mut a));
+        assert_eq!([1, 2, 3], a);
+
+        assert!(!prev_permutation(&mut a));
+        assert_eq!([3, 2, 1], a);
+
+        let mut empty: [i32; 0] = [];
+        assert!(!next_permutation(&mut empty));
+        assert!(!next_permutation(&mut [1]));
+    }
+}
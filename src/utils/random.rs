@@ -0,0 +1,121 @@
+//! Pseudo-random number generator.
+
+/// Xorshift64 generator. Deterministic for a seed, and not cryptographically secure.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::random::XorShift64;
+///
+/// let mut rng = XorShift64::new(42);
+/// let x = rng.next_range(10, 20);
+/// assert!(10 <= x && x < 20);
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// rng.shuffle(&mut v);
+/// v.sort();
+/// assert_eq!(vec![1, 2, 3, 4, 5], v);
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Constructs a new generator. The seed 0 is replaced by a fixed nonzero value,
+    /// since the state 0 is a fixed point.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    /// Returns the next value.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a value in [lo, hi).
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    pub fn next_range(&mut self, lo: u64, hi: u64) -> u64 {
+        assert!(lo < hi, "The range is empty");
+        lo + self.next() % (hi - lo)
+    }
+
+    /// Shuffles the slice, using the Fisher-Yates shuffle.
+    /// Consumes O(n) time.
+    pub fn shuffle<T>(&mut self, s: &mut [T]) {
+        for i in (1..s.len()).rev() {
+            let j = self.next_range(0, i as u64 + 1) as usize;
+            s.swap(i, j);
+        }
+    }
+
+    /// Returns a uniformly random permutation of 0..n.
+    /// Consumes O(n) time.
+    pub fn random_permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut p: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut p);
+        p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let mut a = XorShift64::new(123);
+        let mut b = XorShift64::new(123);
+        let mut c = XorShift64::new(124);
+
+        let va: Vec<u64> = (0..100).map(|_| a.next()).collect();
+        let vb: Vec<u64> = (0..100).map(|_| b.next()).collect();
+        let vc: Vec<u64> = (0..100).map(|_| c.next()).collect();
+        assert_eq!(va, vb);
+        assert_ne!(va, vc);
+
+        let mut zero = XorShift64::new(0);
+        assert!((0..100).all(|_| zero.next() != 0));
+    }
+
+    #[test]
+    fn range() {
+        let mut rng = XorShift64::new(1);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let x = rng.next_range(3, 10);
+            assert!(3 <= x && x < 10);
+            seen[(x - 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&b| b));
+
+        assert_eq!(5, rng.next_range(5, 6));
+    }
+
+    #[test]
+    fn permutation() {
+        let mut rng = XorShift64::new(7);
+        for n in 0..50 {
+            let mut p = rng.random_permutation(n);
+            p.sort();
+            assert_eq!((0..n).collect::<Vec<_>>(), p);
+        }
+
+        let mut v: Vec<char> = "shuffle".chars().collect();
+        rng.shuffle(&mut v);
+        v.sort();
+        assert_eq!(vec!['e', 'f', 'f', 'h', 'l', 's', 'u'], v);
+    }
+}
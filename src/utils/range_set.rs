@@ -0,0 +1,177 @@
+//! Set of disjoint intervals.
+
+use std::collections::BTreeMap;
+
+/// Set of integers kept as disjoint half-open intervals.
+/// Overlapping or adjacent intervals are merged.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::range_set::IntervalSet;
+///
+/// let mut set = IntervalSet::new();
+/// set.insert(0, 5);
+/// set.insert(5, 8);
+/// set.remove(2, 4);
+///
+/// assert!(set.contains(1));
+/// assert!(!set.contains(3));
+/// assert_eq!(6, set.total_length());
+/// assert_eq!(2, set.count_intervals());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntervalSet {
+    intervals: BTreeMap<i64, i64>,
+    total: i64,
+}
+
+impl IntervalSet {
+    /// Constructs a new empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the integers in [lo, hi).
+    /// Consumes O(log n) amortized time.
+    pub fn insert(&mut self, mut lo: i64, mut hi: i64) {
+        if lo >= hi {
+            return;
+        }
+
+        while let Some((&s, &e)) = self.intervals.range(..=hi).next_back() {
+            if e < lo {
+                break;
+            }
+            lo = lo.min(s);
+            hi = hi.max(e);
+            self.intervals.remove(&s);
+            self.total -= e - s;
+        }
+
+        self.intervals.insert(lo, hi);
+        self.total += hi - lo;
+    }
+
+    /// Removes the integers in [lo, hi).
+    /// Consumes O(log n) amortized time.
+    pub fn remove(&mut self, lo: i64, hi: i64) {
+        if lo >= hi {
+            return;
+        }
+
+        while let Some((&s, &e)) = self.intervals.range(..hi).next_back() {
+            if e <= lo {
+                break;
+            }
+            self.intervals.remove(&s);
+            self.total -= e - s;
+
+            if s < lo {
+                self.intervals.insert(s, lo);
+                self.total += lo - s;
+            }
+            if hi < e {
+                self.intervals.insert(hi, e);
+                self.total += e - hi;
+            }
+        }
+    }
+
+    /// Returns true if x is contained.
+    /// Consumes O(log n) time.
+    pub fn contains(&self, x: i64) -> bool {
+        self.intervals
+            .range(..=x)
+            .next_back()
+            .map_or(false, |(_, &e)| x < e)
+    }
+
+    /// Returns the number of contained integers.
+    pub fn total_length(&self) -> i64 {
+        self.total
+    }
+
+    /// Returns the number of the disjoint intervals.
+    pub fn count_intervals(&self) -> usize {
+        self.intervals.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    #[test]
+    fn merge() {
+        let mut set = IntervalSet::new();
+        set.insert(0, 3);
+        set.insert(5, 7);
+        assert_eq!(2, set.count_intervals());
+
+        // adjacent
+        set.insert(3, 5);
+        assert_eq!(1, set.count_intervals());
+        assert_eq!(7, set.total_length());
+
+        // covering
+        set.insert(10, 12);
+        set.insert(-2, 20);
+        assert_eq!(1, set.count_intervals());
+        assert_eq!(22, set.total_length());
+
+        // empty
+        set.insert(30, 30);
+        assert_eq!(1, set.count_intervals());
+    }
+
+    #[test]
+    fn split() {
+        let mut set = IntervalSet::new();
+        set.insert(0, 10);
+        set.remove(3, 5);
+        assert_eq!(2, set.count_intervals());
+        assert_eq!(8, set.total_length());
+        assert!(set.contains(2) && !set.contains(3) && !set.contains(4) && set.contains(5));
+
+        set.remove(-5, 1);
+        set.remove(9, 15);
+        assert_eq!(6, set.total_length());
+        assert!(!set.contains(0) && !set.contains(9));
+
+        set.remove(0, 100);
+        assert_eq!(0, set.count_intervals());
+        assert_eq!(0, set.total_length());
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift64::new(1);
+        let mut rand = |m: u64| rng.next_range(0, m) as i64;
+
+        let mut set = IntervalSet::new();
+        let mut naive = vec![false; 64];
+        for _ in 0..1000 {
+            let lo = rand(64);
+            let hi = lo + rand(16).min(64 - lo);
+            let insert = rand(2) == 0;
+            if insert {
+                set.insert(lo, hi);
+            } else {
+                set.remove(lo, hi);
+            }
+            (lo..hi).for_each(|i| naive[i as usize] = insert);
+
+            let runs = (0..64)
+                .filter(|&i| naive[i] && (i == 0 || !naive[i - 1]))
+                .count();
+            assert_eq!(runs, set.count_intervals());
+            assert_eq!(
+                naive.iter().filter(|&&b| b).count() as i64,
+                set.total_length()
+            );
+            assert!((0..64).all(|i| naive[i as usize] == set.contains(i)));
+        }
+    }
+}
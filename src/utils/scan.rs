@@ -8,7 +8,11 @@ use std::str::FromStr;
 
 /// Wraps a reader. Reads tokens.
 #[snippet(doc_hidden, "scan")]
-pub struct Scanner<R: Read>(R);
+pub struct Scanner<R: Read> {
+    reader: R,
+    // true if the last token ended with '\r', which may be followed by '\n'
+    after_cr: bool,
+}
 
 #[snippet(doc_hidden, "scan")]
 impl<R: Read> Scanner<R> {
@@ -22,7 +26,10 @@ impl<R: Read> Scanner<R> {
     /// let mut sc = Scanner::new(stdin.lock());
     /// ```
     pub fn new(reader: R) -> Self {
-        Self(reader)
+        Self {
+            reader,
+            after_cr: false,
+        }
     }
 
     /// Reads a token.
@@ -42,19 +49,57 @@ impl<R: Read> Scanner<R> {
     /// assert_eq!((n, m), (4, 3));
     /// ```
     pub fn read<T: FromStr>(&mut self) -> T {
-        let token = self
-            .0
+        self.try_read().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reads a token.
+    /// Returns an error containing the token if it fails to parse it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "4 x";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// assert_eq!(4, sc.try_read::<u32>().unwrap());
+    /// assert!(sc.try_read::<u32>().is_err());
+    /// ```
+    pub fn try_read<T: FromStr>(&mut self) -> Result<T, Box<dyn std::error::Error>> {
+        let token = self.token();
+        if token.is_empty() {
+            return Err("Can't parse it: reached EOF.".into());
+        }
+        Self::parse_token(&token)
+    }
+
+    fn parse_token<T: FromStr>(token: &[u8]) -> Result<T, Box<dyn std::error::Error>> {
+        let s = std::str::from_utf8(token)
+            .map_err(|_| format!("The token {:?} is not valid UTF-8.", token))?;
+        s.parse()
+            .map_err(|_| format!("Can't parse the token {:?}.", s).into())
+    }
+
+    // Returns an empty token at EOF.
+    fn token(&mut self) -> Vec<u8> {
+        let bytes = self
+            .reader
             .by_ref()
             .bytes()
             .map(|b| b.unwrap())
-            .skip_while(|&b| (b as char).is_whitespace())
-            .take_while(|&b| !(b as char).is_whitespace())
-            .collect::<Vec<u8>>();
+            .skip_while(|&b| (b as char).is_whitespace());
 
-        unsafe { std::str::from_utf8_unchecked(token.as_slice()) }
-            .parse()
-            .ok()
-            .expect("Can't parse it.")
+        let mut token = vec![];
+        self.after_cr = false;
+        for b in bytes {
+            if (b as char).is_whitespace() {
+                self.after_cr = b == b'\r';
+                break;
+            }
+            token.push(b);
+        }
+        token
     }
 
     /// Reads n tokens. Returns in Vec.
@@ -80,6 +125,94 @@ impl<R: Read> Scanner<R> {
         (0..n).map(|_| self.read()).collect()
     }
 
+    /// Reads a grid of rows by cols tokens, row by row. Returns in Vec\<Vec\>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// 1 2 3
+    /// 4 5 6
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let grid: Vec<Vec<i32>> = sc.read_grid(2, 3);
+    /// assert_eq!(grid, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn read_grid<T: FromStr>(&mut self, rows: usize, cols: usize) -> Vec<Vec<T>> {
+        (0..rows).map(|_| self.vec(cols)).collect()
+    }
+
+    /// Same as [`read_grid`], kept for compatibility.
+    ///
+    /// [`read_grid`]: #method.read_grid
+    pub fn read_vec2d<T: FromStr>(&mut self, rows: usize, cols: usize) -> Vec<Vec<T>> {
+        self.read_grid(rows, cols)
+    }
+
+    /// Same as [`read_grid`], for matrices.
+    ///
+    /// [`read_grid`]: #method.read_grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "1 0\n0 1\n";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let a: Vec<Vec<i64>> = sc.read_matrix(2, 2);
+    /// assert_eq!(a, vec![vec![1, 0], vec![0, 1]]);
+    /// ```
+    pub fn read_matrix<T: FromStr>(&mut self, rows: usize, cols: usize) -> Vec<Vec<T>> {
+        self.read_grid(rows, cols)
+    }
+
+    /// Reads n pairs of tokens, such as an edge list. Returns in Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// 1 2
+    /// 2 3
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let edges: Vec<(usize, usize)> = sc.read_pairs(2);
+    /// assert_eq!(edges, vec![(1, 2), (2, 3)]);
+    /// ```
+    pub fn read_pairs<A: FromStr, B: FromStr>(&mut self, n: usize) -> Vec<(A, B)> {
+        (0..n).map(|_| (self.read(), self.read())).collect()
+    }
+
+    /// Reads n triples of tokens, such as a weighted edge list. Returns in Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// 1 2 5
+    /// 2 3 -1
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let edges: Vec<(usize, usize, i64)> = sc.read_triples(2);
+    /// assert_eq!(edges, vec![(1, 2, 5), (2, 3, -1)]);
+    /// ```
+    pub fn read_triples<A: FromStr, B: FromStr, C: FromStr>(&mut self, n: usize) -> Vec<(A, B, C)> {
+        (0..n)
+            .map(|_| (self.read(), self.read(), self.read()))
+            .collect()
+    }
+
     /// Reads a string. Returns in Vec\<char\>.
     ///
     /// # Examples
@@ -96,6 +229,125 @@ impl<R: Read> Scanner<R> {
     pub fn chars(&mut self) -> Vec<char> {
         self.read::<String>().chars().collect()
     }
+
+    /// Reads the rest of the current line, consuming the `\n` but excluding it and a preceding `\r`.
+    /// Since reading a token consumes the whitespace just after it,
+    /// this reads the next line if a token ending a line has just been read.
+    /// Returns an empty string at EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// 2
+    /// hello world
+    /// a b
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let n: usize = sc.read();
+    /// assert_eq!(n, 2);
+    /// assert_eq!(sc.read_line(), "hello world");
+    /// assert_eq!(sc.read_line(), "a b");
+    /// ```
+    pub fn read_line(&mut self) -> String {
+        // the '\n' of a "\r\n" that ended the last token
+        let skip_lf = std::mem::replace(&mut self.after_cr, false);
+
+        let mut line = vec![];
+        let bytes = self.reader.by_ref().bytes().map(|b| b.unwrap());
+        for (i, b) in bytes.enumerate() {
+            if b == b'\n' {
+                if i == 0 && skip_lf {
+                    continue;
+                }
+                break;
+            }
+            line.push(b);
+        }
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        String::from_utf8(line).expect("The line is not valid UTF-8")
+    }
+
+    /// Reads rows strings, such as a grid of characters. Returns in Vec\<Vec\<char\>\>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "\
+    /// .#
+    /// #.
+    /// ";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let grid = sc.char_grid(2);
+    /// assert_eq!(grid, vec![vec!['.', '#'], vec!['#', '.']]);
+    /// ```
+    pub fn char_grid(&mut self, rows: usize) -> Vec<Vec<char>> {
+        (0..rows).map(|_| self.chars()).collect()
+    }
+
+    /// Reads all the remaining tokens. Returns in Vec.
+    ///
+    /// # Panics
+    /// Panics if it fails to parse a token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tklib::utils::scan::Scanner;
+    ///
+    /// let input = "1 2\n3\n";
+    /// let mut sc = Scanner::new(input.as_bytes());
+    ///
+    /// let v: Vec<i32> = sc.read_all();
+    /// assert_eq!(v, vec![1, 2, 3]);
+    /// ```
+    pub fn read_all<T: FromStr>(&mut self) -> Vec<T> {
+        std::iter::from_fn(|| {
+            let token = self.token();
+            if token.is_empty() {
+                None
+            } else {
+                Some(Self::parse_token(&token).unwrap_or_else(|e| panic!("{}", e)))
+            }
+        })
+        .collect()
+    }
+}
+
+/// Iterates over the tokens until EOF.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::scan::Scanner;
+///
+/// let input = "abc de\n";
+/// let mut sc = Scanner::new(input.as_bytes());
+///
+/// assert_eq!(Some("abc".to_string()), sc.next());
+/// assert_eq!(Some("de".to_string()), sc.next());
+/// assert_eq!(None, sc.next());
+/// ```
+#[snippet(doc_hidden, "scan")]
+impl<R: Read> Iterator for Scanner<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let token = self.token();
+        if token.is_empty() {
+            None
+        } else {
+            Some(String::from_utf8(token).expect("The token is not valid UTF-8"))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -159,6 +411,103 @@ mod tests {
         assert_eq!(v, vec![1, 2, 3, 4, 5]);
     }
 
+    #[test]
+    fn read_grid() {
+        let input = "\
+        3 4
+        1 -2 3 4
+        -5 6 -7 8
+        9 10 11 -12
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let (h, w): (usize, usize) = (sc.read(), sc.read());
+        let grid: Vec<Vec<i64>> = sc.read_grid(h, w);
+        assert_eq!(
+            grid,
+            vec![vec![1, -2, 3, 4], vec![-5, 6, -7, 8], vec![9, 10, 11, -12]]
+        );
+
+        let empty: Vec<Vec<i64>> = sc.read_grid(0, 5);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn read_vec2d() {
+        let input = "1 2\n3 4\n";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let grid: Vec<Vec<u8>> = sc.read_vec2d(2, 2);
+        assert_eq!(grid, vec![vec![1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn read_char_grid() {
+        let input = "\
+        2 5
+        #..#.
+        .##..
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let (h, w): (usize, usize) = (sc.read(), sc.read());
+        let grid = sc.char_grid(h);
+        assert_eq!(
+            grid,
+            vec![vec!['#', '.', '.', '#', '.'], vec!['.', '#', '#', '.', '.']]
+        );
+        assert!(grid.iter().all(|row| row.len() == w));
+    }
+
+    #[test]
+    fn read_weighted_edges() {
+        let input = "\
+        3
+        0 10
+        2 -5
+        1 1000000000000
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let m: usize = sc.read();
+        let edges: Vec<(usize, i64)> = sc.read_pairs(m);
+        assert_eq!(edges, vec![(0, 10), (2, -5), (1, 1_000_000_000_000)]);
+    }
+
+    #[test]
+    fn read_matrix() {
+        let input = "\
+        3
+        1 2 3
+        4 5 6
+        7 8 9
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let n: usize = sc.read();
+        let a: Vec<Vec<u32>> = sc.read_matrix(n, n);
+        assert_eq!(a, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    }
+
+    #[test]
+    fn read_triples() {
+        let input = "\
+        4 4
+        1 2 10
+        2 3 -5
+        3 4 1000000000000
+        4 1 0
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let (_n, m): (usize, usize) = (sc.read(), sc.read());
+        let edges: Vec<(u32, u32, i64)> = sc.read_triples(m);
+        assert_eq!(
+            edges,
+            vec![(1, 2, 10), (2, 3, -5), (3, 4, 1_000_000_000_000), (4, 1, 0)]
+        );
+    }
+
     #[test]
     fn read_chars() {
         let input = ".#..#";
@@ -167,4 +516,97 @@ mod tests {
         let v = sc.chars();
         assert_eq!(v, vec!['.', '#', '.', '.', '#']);
     }
+
+    #[test]
+    fn iterate() {
+        let input = "\
+        3 abc
+        -4
+        ";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let n: i32 = sc.read();
+        assert_eq!(n, 3);
+
+        let rest: Vec<String> = sc.by_ref().collect();
+        assert_eq!(rest, vec!["abc", "-4"]);
+        assert_eq!(sc.next(), None);
+        assert_eq!(sc.next(), None);
+    }
+
+    #[test]
+    fn read_all() {
+        let input = "1 2 3";
+        let mut sc = Scanner::new(input.as_bytes());
+        assert_eq!(sc.read_all::<i32>(), vec![1, 2, 3]);
+        assert!(sc.read_all::<i32>().is_empty());
+
+        let input = "";
+        let mut sc = Scanner::new(input.as_bytes());
+        assert!(sc.read_all::<String>().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't parse the token \"x\".")]
+    fn read_all_invalid() {
+        let input = "1 x 3";
+        let mut sc = Scanner::new(input.as_bytes());
+        sc.read_all::<i32>();
+    }
+
+    #[test]
+    fn read_line() {
+        let input = "3\nthe quick  brown fox\r\n4 rest of line\nlast";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let n: usize = sc.read();
+        assert_eq!(n, 3);
+        assert_eq!(sc.read_line(), "the quick  brown fox");
+
+        let m: usize = sc.read();
+        assert_eq!(m, 4);
+        assert_eq!(sc.read_line(), "rest of line");
+        assert_eq!(sc.read_line(), "last");
+        assert_eq!(sc.read_line(), "");
+    }
+
+    #[test]
+    fn read_line_crlf() {
+        let input = "3\r\nhello world\r\n4 5\r\n\r\nlast\r\n";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let n: usize = sc.read();
+        assert_eq!(n, 3);
+        assert_eq!(sc.read_line(), "hello world");
+
+        let (a, b): (u32, u32) = (sc.read(), sc.read());
+        assert_eq!((a, b), (4, 5));
+        assert_eq!(sc.read_line(), "");
+        assert_eq!(sc.read_line(), "last");
+        assert_eq!(sc.read_line(), "");
+
+        // a token ending with "\r" on the last line
+        let mut sc = Scanner::new(&b"7\r"[..]);
+        assert_eq!(7, sc.read::<i32>());
+        assert_eq!(sc.read_line(), "");
+    }
+
+    #[test]
+    fn try_read() {
+        let input = "abc 12 -3";
+        let mut sc = Scanner::new(input.as_bytes());
+
+        let err = sc.try_read::<u32>().unwrap_err();
+        assert!(err.to_string().contains("abc"));
+        assert_eq!(12, sc.try_read::<u32>().unwrap());
+        assert!(sc.try_read::<u32>().unwrap_err().to_string().contains("-3"));
+        assert!(sc.try_read::<u32>().is_err());
+
+        let input: &[u8] = &[b'1', b' ', 0xff, 0xfe, b' ', b'2'];
+        let mut sc = Scanner::new(input);
+        assert_eq!(1, sc.try_read::<u32>().unwrap());
+        let err = sc.try_read::<String>().unwrap_err();
+        assert!(err.to_string().contains("[255, 254]"));
+        assert_eq!(2, sc.read::<u32>());
+    }
 }
@@ -0,0 +1,90 @@
+//! Ternary search.
+
+/// Finds the minimizer of a unimodal function on [lo, hi].
+/// Each iteration shrinks the interval to 2/3.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::search::ternary_search_f64;
+///
+/// let x = ternary_search_f64(-10.0, 10.0, |x| (x - 1.5) * (x - 1.5), 100);
+/// assert!((x - 1.5).abs() < 1e-9);
+/// ```
+pub fn ternary_search_f64<F: Fn(f64) -> f64>(
+    mut lo: f64,
+    mut hi: f64,
+    f: F,
+    iterations: usize,
+) -> f64 {
+    for _ in 0..iterations {
+        let m1 = lo + (hi - lo) / 3.0;
+        let m2 = hi - (hi - lo) / 3.0;
+        if f(m1) <= f(m2) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Finds the smallest minimizer of a function on [lo, hi]
+/// which is strictly decreasing and then non-decreasing.
+/// Consumes O(log(hi - lo)) evaluations.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::search::ternary_search_i64;
+///
+/// assert_eq!(5, ternary_search_i64(-100, 100, |x| (x - 5) * (x - 5)));
+/// ```
+pub fn ternary_search_i64<F: Fn(i64) -> i64>(mut lo: i64, mut hi: i64, f: F) -> i64 {
+    assert!(lo <= hi);
+
+    // the first x with f(x) <= f(x + 1)
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        if f(m) <= f(m + 1) {
+            hi = m;
+        } else {
+            lo = m + 1;
+        }
+    }
+    lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn real() {
+        let x = ternary_search_f64(-100.0, 100.0, |x| (x - 3.7).abs(), 200);
+        assert!((x - 3.7).abs() < 1e-9);
+
+        let x = ternary_search_f64(0.0, 6.0, f64::cos, 200);
+        assert!((x - std::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn integer() {
+        assert_eq!(5, ternary_search_i64(-1000, 1000, |x| (x - 5) * (x - 5)));
+        assert_eq!(-1000, ternary_search_i64(-1000, 1000, |x| x));
+        assert_eq!(1000, ternary_search_i64(-1000, 1000, |x| -x));
+        assert_eq!(7, ternary_search_i64(7, 7, |x| x));
+
+        // plateau at the bottom
+        assert_eq!(2, ternary_search_i64(0, 100, |x| (x - 3).abs().max(1)));
+    }
+
+    #[test]
+    fn not_unimodal() {
+        let x = ternary_search_f64(0.0, 100.0, f64::sin, 100);
+        assert!((0.0..=100.0).contains(&x));
+
+        let x = ternary_search_i64(0, 100, |x| x % 7);
+        assert!((0..=100).contains(&x));
+    }
+}
@@ -0,0 +1,102 @@
+//! Sum over subsets.
+
+/// Performs the zeta transform over subsets in place.
+/// After the call, `f[mask]` is the sum of the original `f[sub]` over all submasks `sub` of `mask`.
+/// Consumes O(n 2^n) time.
+///
+/// # Panics
+/// Panics if the length of `f` is not 2^n.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::sos::subset_sum;
+///
+/// let mut f = vec![1, 2, 3, 4];
+/// subset_sum(&mut f, 2);
+///
+/// assert_eq!(vec![1, 3, 4, 10], f);
+/// ```
+pub fn subset_sum(f: &mut Vec<i64>, n: usize) {
+    assert_eq!(1 << n, f.len());
+
+    for k in 0..n {
+        for mask in 0..f.len() {
+            if mask >> k & 1 == 1 {
+                f[mask] += f[mask ^ 1 << k];
+            }
+        }
+    }
+}
+
+/// Performs the Möbius transform over subsets in place, the inverse of `subset_sum`.
+/// Consumes O(n 2^n) time.
+///
+/// # Panics
+/// Panics if the length of `f` is not 2^n.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::sos::subset_sum_inv;
+///
+/// let mut f = vec![1, 3, 4, 10];
+/// subset_sum_inv(&mut f, 2);
+///
+/// assert_eq!(vec![1, 2, 3, 4], f);
+/// ```
+pub fn subset_sum_inv(f: &mut Vec<i64>, n: usize) {
+    assert_eq!(1 << n, f.len());
+
+    for k in 0..n {
+        for mask in 0..f.len() {
+            if mask >> k & 1 == 1 {
+                f[mask] -= f[mask ^ 1 << k];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, 2001) as i64 - 1000)
+            .collect()
+    }
+
+    #[test]
+    fn naive() {
+        let n = 6;
+        let f = random_vec(1 << n, 1);
+
+        let mut g = f.clone();
+        subset_sum(&mut g, n);
+
+        for (mask, &x) in g.iter().enumerate() {
+            let expected: i64 = (0..1 << n).filter(|&s| s & mask == s).map(|s| f[s]).sum();
+            assert_eq!(expected, x);
+        }
+        assert_eq!(f.iter().sum::<i64>(), g[(1 << n) - 1]);
+    }
+
+    #[test]
+    fn inverse() {
+        for n in 0..10 {
+            let f = random_vec(1 << n, n as u64);
+
+            let mut g = f.clone();
+            subset_sum(&mut g, n);
+            subset_sum_inv(&mut g, n);
+            assert_eq!(f, g);
+
+            subset_sum_inv(&mut g, n);
+            subset_sum(&mut g, n);
+            assert_eq!(f, g);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Pair sums.
+
+fn sorted(data: &[i64]) -> Vec<i64> {
+    let mut v = data.to_vec();
+    v.sort();
+    v
+}
+
+/// Counts the pairs i < j with `data[i] + data[j] == target`, using two pointers.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::two_sum::count_pairs_equal;
+///
+/// assert_eq!(3, count_pairs_equal(&[1, 5, 3, 3, 7], 8));
+/// ```
+pub fn count_pairs_equal(data: &[i64], target: i64) -> usize {
+    let v = sorted(data);
+    if v.is_empty() {
+        return 0;
+    }
+
+    let (mut i, mut j) = (0, v.len() - 1);
+    let mut count = 0;
+    while i < j {
+        let sum = v[i] + v[j];
+        if sum < target {
+            i += 1;
+        } else if sum > target {
+            j -= 1;
+        } else if v[i] == v[j] {
+            // all of v[i..=j] are equal
+            let k = j - i + 1;
+            count += k * (k - 1) / 2;
+            break;
+        } else {
+            let left = v[i..].iter().take_while(|&&x| x == v[i]).count();
+            let right = v[..=j].iter().rev().take_while(|&&x| x == v[j]).count();
+            count += left * right;
+            i += left;
+            j -= right;
+        }
+    }
+
+    count
+}
+
+/// Counts the pairs i < j with `data[i] + data[j] < target`, using two pointers.
+/// Consumes O(n log n) time.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::two_sum::count_pairs_less;
+///
+/// assert_eq!(4, count_pairs_less(&[1, 5, 3, 3, 7], 8));
+/// ```
+pub fn count_pairs_less(data: &[i64], target: i64) -> usize {
+    let v = sorted(data);
+    if v.is_empty() {
+        return 0;
+    }
+
+    let (mut i, mut j) = (0, v.len() - 1);
+    let mut count = 0;
+    while i < j {
+        if v[i] + v[j] < target {
+            count += j - i;
+            i += 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    count
+}
+
+/// Returns the sum of a pair i < j closest to target, the smaller one on a tie.
+/// Consumes O(n log n) time.
+///
+/// # Panics
+/// Panics if there are fewer than 2 values.
+///
+/// # Examples
+///
+/// ```
+/// use tklib::utils::two_sum::closest_pair_sum;
+///
+/// assert_eq!(10, closest_pair_sum(&[1, 5, 3, 3, 7], 11));
+/// ```
+pub fn closest_pair_sum(data: &[i64], target: i64) -> i64 {
+    assert!(data.len() >= 2, "At least 2 values are required");
+
+    let v = sorted(data);
+    let (mut i, mut j) = (0, v.len() - 1);
+    let mut best = v[0] + v[1];
+    while i < j {
+        let sum = v[i] + v[j];
+        let (d, best_d) = ((sum - target).abs(), (best - target).abs());
+        if d < best_d || (d == best_d && sum < best) {
+            best = sum;
+        }
+        if sum < target {
+            i += 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::random::XorShift64;
+
+    fn random_vec(len: usize, seed: u64, modulus: u64) -> Vec<i64> {
+        let mut rng = XorShift64::new(seed);
+        (0..len)
+            .map(|_| rng.next_range(0, 2 * modulus + 1) as i64 - modulus as i64)
+            .collect()
+    }
+
+    fn pair_sums(v: &[i64]) -> Vec<i64> {
+        (0..v.len())
+            .flat_map(|i| (i + 1..v.len()).map(move |j| v[i] + v[j]))
+            .collect()
+    }
+
+    #[test]
+    fn naive() {
+        for len in 0..30 {
+            for &modulus in &[0, 3, 50] {
+                let v = random_vec(len, len as u64 * 7 + modulus, modulus);
+                let sums = pair_sums(&v);
+
+                for target in -2 * modulus as i64 - 2..=2 * modulus as i64 + 2 {
+                    assert_eq!(
+                        sums.iter().filter(|&&s| s == target).count(),
+                        count_pairs_equal(&v, target)
+                    );
+                    assert_eq!(
+                        sums.iter().filter(|&&s| s < target).count(),
+                        count_pairs_less(&v, target)
+                    );
+                    if len >= 2 {
+                        let expected = *sums
+                            .iter()
+                            .min_by_key(|&&s| ((s - target).abs(), s))
+                            .unwrap();
+                        assert_eq!(expected, closest_pair_sum(&v, target));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_equal() {
+        let v = vec![4; 10];
+        assert_eq!(45, count_pairs_equal(&v, 8));
+        assert_eq!(0, count_pairs_equal(&v, 7));
+        assert_eq!(45, count_pairs_less(&v, 9));
+        assert_eq!(8, closest_pair_sum(&v, -100));
+    }
+}